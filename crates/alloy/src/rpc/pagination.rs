@@ -28,6 +28,11 @@ pub struct PaginationParams<Filters> {
     /// The cursor format depends on the endpoint:
     /// - `dex_getOrders`: Order ID (u128 encoded as string)
     /// - `dex_getOrderbooks`: Book Key (B256 encoded as hex string)
+    /// - `amm_getLiquidityPools`: Pool ID (B256 encoded as hex string)
+    /// - `policy_getAddresses`: Account address (hex string)
+    /// - `eth_getTransactions`: Transaction hash (hex string)
+    /// - `token_getTokens`, `token_getTokensByAddress`: Token address (hex string)
+    /// - `token_getRoleHistory`: Log position (`<block number>:<log index>`)
     ///
     /// Defaults to first entry based on the sort and filter configuration.
     /// Use the `nextCursor` in response to get the next set of results.
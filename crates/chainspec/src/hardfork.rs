@@ -48,6 +48,8 @@ hardfork!(
         Allegretto,
         /// Allegro-Moderato hardfork.
         AllegroModerato,
+        /// Vivace hardfork.
+        Vivace,
     }
 );
 
@@ -67,6 +69,11 @@ impl TempoHardfork {
     pub fn is_allegro_moderato(self) -> bool {
         self >= Self::AllegroModerato
     }
+
+    /// Returns `true` if this hardfork is Vivace or later.
+    pub fn is_vivace(self) -> bool {
+        self >= Self::Vivace
+    }
 }
 
 /// Trait for querying Tempo-specific hardfork activations.
@@ -98,9 +105,17 @@ pub trait TempoHardforks: EthereumHardforks {
             .active_at_timestamp(timestamp)
     }
 
+    /// Convenience method to check if Vivace hardfork is active at a given timestamp
+    fn is_vivace_active_at_timestamp(&self, timestamp: u64) -> bool {
+        self.tempo_fork_activation(TempoHardfork::Vivace)
+            .active_at_timestamp(timestamp)
+    }
+
     /// Retrieves the latest Tempo hardfork active at a given timestamp.
     fn tempo_hardfork_at(&self, timestamp: u64) -> TempoHardfork {
-        if self.is_allegro_moderato_active_at_timestamp(timestamp) {
+        if self.is_vivace_active_at_timestamp(timestamp) {
+            TempoHardfork::Vivace
+        } else if self.is_allegro_moderato_active_at_timestamp(timestamp) {
             TempoHardfork::AllegroModerato
         } else if self.is_allegretto_active_at_timestamp(timestamp) {
             TempoHardfork::Allegretto
@@ -119,6 +134,7 @@ impl From<TempoHardfork> for SpecId {
             TempoHardfork::Moderato => Self::OSAKA,
             TempoHardfork::Allegretto => Self::OSAKA,
             TempoHardfork::AllegroModerato => Self::OSAKA,
+            TempoHardfork::Vivace => Self::OSAKA,
         }
     }
 }
@@ -130,7 +146,9 @@ impl From<SpecId> for TempoHardfork {
     /// `From<TempoHardfork> for SpecId`, because multiple Tempo
     /// hardforks may share the same underlying EVM spec.
     fn from(spec: SpecId) -> Self {
-        if spec.is_enabled_in(SpecId::from(Self::AllegroModerato)) {
+        if spec.is_enabled_in(SpecId::from(Self::Vivace)) {
+            Self::Vivace
+        } else if spec.is_enabled_in(SpecId::from(Self::AllegroModerato)) {
             Self::AllegroModerato
         } else if spec.is_enabled_in(SpecId::from(Self::Allegretto)) {
             Self::Allegretto
@@ -180,6 +198,7 @@ mod tests {
         assert!(TempoHardfork::Moderato.is_moderato());
         assert!(TempoHardfork::Allegretto.is_moderato());
         assert!(TempoHardfork::AllegroModerato.is_moderato());
+        assert!(TempoHardfork::Vivace.is_moderato());
     }
 
     #[test]
@@ -189,6 +208,7 @@ mod tests {
 
         assert!(TempoHardfork::Allegretto.is_allegretto());
         assert!(TempoHardfork::AllegroModerato.is_allegretto());
+        assert!(TempoHardfork::Vivace.is_allegretto());
 
         assert!(TempoHardfork::Allegretto.is_moderato());
     }
@@ -200,8 +220,23 @@ mod tests {
         assert!(!TempoHardfork::Allegretto.is_allegro_moderato());
 
         assert!(TempoHardfork::AllegroModerato.is_allegro_moderato());
+        assert!(TempoHardfork::Vivace.is_allegro_moderato());
 
         assert!(TempoHardfork::AllegroModerato.is_allegretto());
         assert!(TempoHardfork::AllegroModerato.is_moderato());
     }
+
+    #[test]
+    fn test_is_vivace() {
+        assert!(!TempoHardfork::Adagio.is_vivace());
+        assert!(!TempoHardfork::Moderato.is_vivace());
+        assert!(!TempoHardfork::Allegretto.is_vivace());
+        assert!(!TempoHardfork::AllegroModerato.is_vivace());
+
+        assert!(TempoHardfork::Vivace.is_vivace());
+
+        assert!(TempoHardfork::Vivace.is_allegro_moderato());
+        assert!(TempoHardfork::Vivace.is_allegretto());
+        assert!(TempoHardfork::Vivace.is_moderato());
+    }
 }
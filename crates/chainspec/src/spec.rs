@@ -38,6 +38,10 @@ pub struct TempoGenesisInfo {
     #[serde(skip_serializing_if = "Option::is_none")]
     allegro_moderato_time: Option<u64>,
 
+    /// Timestamp of Vivace hardfork activation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vivace_time: Option<u64>,
+
     /// The epoch length used by consensus.
     #[serde(skip_serializing_if = "Option::is_none")]
     epoch_length: Option<u64>,
@@ -137,6 +141,7 @@ impl TempoChainSpec {
             moderato_time,
             allegretto_time,
             allegro_moderato_time,
+            vivace_time,
             ..
         } = TempoGenesisInfo::extract_from(&genesis);
 
@@ -148,6 +153,7 @@ impl TempoChainSpec {
             (TempoHardfork::Moderato, moderato_time),
             (TempoHardfork::Allegretto, allegretto_time),
             (TempoHardfork::AllegroModerato, allegro_moderato_time),
+            (TempoHardfork::Vivace, vivace_time),
         ]
         .into_iter()
         .filter_map(|(fork, time)| time.map(|time| (fork, ForkCondition::Timestamp(time))));
@@ -368,6 +374,7 @@ mod tests {
                 "moderatoTime": 2000,
                 "allegrettoTime": 3000,
                 "allegroModeratoTime": 4000,
+                "vivaceTime": 5000,
             },
             "alloc": {}
         });
@@ -484,6 +491,31 @@ mod tests {
             chainspec.is_allegro_moderato_active_at_timestamp(5000),
             "AllegroModerato should be active after its activation timestamp"
         );
+
+        // Test Vivace activation
+        let activation = chainspec.fork(TempoHardfork::Vivace);
+        assert_eq!(
+            activation,
+            ForkCondition::Timestamp(5000),
+            "Vivace should be activated at the parsed timestamp from extra_fields"
+        );
+
+        assert!(
+            !chainspec.is_vivace_active_at_timestamp(0),
+            "Vivace should not be active before its activation timestamp"
+        );
+        assert!(
+            !chainspec.is_vivace_active_at_timestamp(4000),
+            "Vivace should not be active at AllegroModerato's activation timestamp"
+        );
+        assert!(
+            chainspec.is_vivace_active_at_timestamp(5000),
+            "Vivace should be active at its activation timestamp"
+        );
+        assert!(
+            chainspec.is_vivace_active_at_timestamp(6000),
+            "Vivace should be active after its activation timestamp"
+        );
     }
 
     #[test]
@@ -574,7 +606,8 @@ mod tests {
                 "adagioTime": 1000,
                 "moderatoTime": 2000,
                 "allegrettoTime": 3000,
-                "allegroModeratoTime": 4000
+                "allegroModeratoTime": 4000,
+                "vivaceTime": 5000
             },
             "alloc": {}
         });
@@ -640,11 +673,25 @@ mod tests {
             "Should return AllegroModerato at its activation time"
         );
 
-        // After AllegroModerato
+        // Between AllegroModerato and Vivace
         assert_eq!(
-            chainspec.tempo_hardfork_at(5000),
+            chainspec.tempo_hardfork_at(4500),
             TempoHardfork::AllegroModerato,
-            "Should return AllegroModerato after its activation time"
+            "Should return AllegroModerato between AllegroModerato and Vivace activation"
+        );
+
+        // At Vivace time
+        assert_eq!(
+            chainspec.tempo_hardfork_at(5000),
+            TempoHardfork::Vivace,
+            "Should return Vivace at its activation time"
+        );
+
+        // After Vivace
+        assert_eq!(
+            chainspec.tempo_hardfork_at(6000),
+            TempoHardfork::Vivace,
+            "Should return Vivace after its activation time"
         );
     }
 }
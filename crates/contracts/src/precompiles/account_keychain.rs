@@ -9,6 +9,8 @@ crate::sol! {
     /// - Different signature types (secp256k1, P256, WebAuthn)
     /// - Expiry times for key rotation
     /// - Per-token spending limits for security
+    /// - Optional call scopes restricting the contracts and functions a key can call
+    /// - M-of-N signer sets authorizing transactions through multisig signatures
     ///
     /// Only the main account key can authorize/revoke keys, while secondary keys
     /// can be used for regular transactions within their spending limits.
@@ -27,6 +29,33 @@ crate::sol! {
             uint256 amount;
         }
 
+        /// Token spending limit that resets every `period` seconds (0 for a lifetime limit)
+        struct PeriodicTokenLimit {
+            address token;
+            uint256 amount;
+            uint64 period;
+        }
+
+        /// Spending limit of a key for a token
+        struct SpendingLimit {
+            uint256 limit;
+            uint256 remaining;
+            uint64 period;
+            uint64 periodEnd;
+        }
+
+        /// Call an access key is allowed to make
+        struct CallScope {
+            address target;
+            bytes4 selector;
+        }
+
+        /// Signer set of a multisig account
+        struct Multisig {
+            address[] signers;
+            uint8 threshold;
+        }
+
         /// Key information structure
         struct KeyInfo {
             SignatureType signatureType;
@@ -53,6 +82,12 @@ crate::sol! {
         /// Legacy event for backwards compatibility
         event SpendingLimitUpdated(address indexed account, bytes32 indexed publicKey, address indexed token, uint256 newLimit);
 
+        /// Emitted when a call is added to or removed from the call scope of a key
+        event CallScopeUpdated(address indexed account, address indexed publicKey, address indexed target, bytes4 selector, bool allowed);
+
+        /// Emitted when the signer set of an account is updated
+        event MultisigUpdated(address indexed account, address[] signers, uint8 threshold);
+
         /// Authorize a new key for the caller's account
         /// @param keyId The key identifier (address derived from public key)
         /// @param signatureType 0: secp256k1, 1: P256, 2: WebAuthn
@@ -67,6 +102,20 @@ crate::sol! {
             TokenLimit[] calldata limits
         ) external;
 
+        /// Authorize a new key for the caller's account, with limits that can reset periodically
+        /// @param keyId The key identifier (address derived from public key)
+        /// @param signatureType 0: secp256k1, 1: P256, 2: WebAuthn
+        /// @param expiry Block timestamp when the key expires (u64::MAX for never expires)
+        /// @param enforceLimits Whether to enforce spending limits for this key
+        /// @param limits Initial spending limits for tokens (only used if enforceLimits is true)
+        function authorizeKeyWithPeriodicLimits(
+            address keyId,
+            SignatureType signatureType,
+            uint64 expiry,
+            bool enforceLimits,
+            PeriodicTokenLimit[] calldata limits
+        ) external;
+
         /// Revoke an authorized key
         /// @param publicKey The public key to revoke
         function revokeKey(address keyId) external;
@@ -81,6 +130,50 @@ crate::sol! {
             uint256 newLimit
         ) external;
 
+        /// Restrict a key to a set of calls, adding them to its call scope
+        /// @param keyId The key identifier
+        /// @param calls The calls the key is allowed to make
+        function setAllowedCalls(address keyId, CallScope[] calldata calls) external;
+
+        /// Remove calls from the call scope of a key, which stays restricted to its remaining calls
+        /// @param keyId The key identifier
+        /// @param calls The calls the key is no longer allowed to make
+        function removeAllowedCalls(address keyId, CallScope[] calldata calls) external;
+
+        /// Check whether a key is allowed to call a function on a contract
+        /// @param account The account address
+        /// @param keyId The key identifier
+        /// @param target The contract being called
+        /// @param selector The function selector being called
+        /// @return True if the key has no call scope or the call is within it
+        function isCallAllowed(
+            address account,
+            address keyId,
+            address target,
+            bytes4 selector
+        ) external view returns (bool);
+
+        /// Create an account controlled by a signer set, without a root key
+        /// @param signers The signers of the account
+        /// @param threshold The number of signers required to sign a transaction
+        /// @param salt Salt used to derive the account address
+        /// @return account The address of the new account
+        function createMultisig(
+            address[] calldata signers,
+            uint8 threshold,
+            bytes32 salt
+        ) external returns (address account);
+
+        /// Set the signer set of the caller's account, or remove it with no signers and a zero threshold
+        /// @param signers The signers of the account
+        /// @param threshold The number of signers required to sign a transaction
+        function setMultisig(address[] calldata signers, uint8 threshold) external;
+
+        /// Get the signer set of an account
+        /// @param account The account address
+        /// @return The signers and threshold, empty if the account has no signer set
+        function getMultisig(address account) external view returns (Multisig memory);
+
         /// Get key information
         /// @param account The account address
         /// @param publicKey The public key
@@ -98,6 +191,17 @@ crate::sol! {
             address token
         ) external view returns (uint256);
 
+        /// Get the spending limit of a key for a token, including its current period
+        /// @param account The account address
+        /// @param keyId The key identifier
+        /// @param token The token address
+        /// @return The limit, remaining amount, period length and end of the current period
+        function getSpendingLimit(
+            address account,
+            address keyId,
+            address token
+        ) external view returns (SpendingLimit memory);
+
         /// Get the key used in the current transaction
         /// @return The keyId used in the current transaction
         function getTransactionKey() external view returns (address);
@@ -112,6 +216,12 @@ crate::sol! {
         error ZeroPublicKey();
         error ExpiryInPast();
         error KeyAlreadyRevoked();
+        error CallNotAllowed();
+        error InvalidMultisig();
+        error MultisigAlreadyExists();
+        error MultisigNotFound();
+        error MultisigSignerNotFound();
+        error MultisigThresholdNotMet();
     }
 }
 
@@ -162,4 +272,34 @@ impl AccountKeychainError {
     pub const fn key_already_revoked() -> Self {
         Self::KeyAlreadyRevoked(IAccountKeychain::KeyAlreadyRevoked {})
     }
+
+    /// Creates an error for a call outside the call scope of a key.
+    pub const fn call_not_allowed() -> Self {
+        Self::CallNotAllowed(IAccountKeychain::CallNotAllowed {})
+    }
+
+    /// Creates an error for an invalid signer set or threshold.
+    pub const fn invalid_multisig() -> Self {
+        Self::InvalidMultisig(IAccountKeychain::InvalidMultisig {})
+    }
+
+    /// Creates an error for a multisig account that already exists.
+    pub const fn multisig_already_exists() -> Self {
+        Self::MultisigAlreadyExists(IAccountKeychain::MultisigAlreadyExists {})
+    }
+
+    /// Creates an error for an account without a signer set.
+    pub const fn multisig_not_found() -> Self {
+        Self::MultisigNotFound(IAccountKeychain::MultisigNotFound {})
+    }
+
+    /// Creates an error for a signer that is not in the signer set of the account.
+    pub const fn multisig_signer_not_found() -> Self {
+        Self::MultisigSignerNotFound(IAccountKeychain::MultisigSignerNotFound {})
+    }
+
+    /// Creates an error for a multisig signature with fewer signers than the threshold.
+    pub const fn multisig_threshold_not_met() -> Self {
+        Self::MultisigThresholdNotMet(IAccountKeychain::MultisigThresholdNotMet {})
+    }
 }
@@ -17,6 +17,14 @@ crate::sol! {
     #[derive(Debug, PartialEq, Eq)]
     #[sol(abi)]
     interface IStablecoinExchange {
+        // Enums
+        enum TimeInForce {
+            GOOD_TILL_CANCELLED,
+            IMMEDIATE_OR_CANCEL,
+            FILL_OR_KILL,
+            POST_ONLY
+        }
+
         // Structs
         struct Order {
             uint128 orderId;
@@ -49,7 +57,14 @@ crate::sol! {
         function createPair(address base) external returns (bytes32 key);
         function place(address token, uint128 amount, bool isBid, int16 tick) external returns (uint128 orderId);
         function placeFlip(address token, uint128 amount, bool isBid, int16 tick, int16 flipTick) external returns (uint128 orderId);
+        /// Places an order with a time-in-force. Immediate-or-cancel and fill-or-kill orders are
+        /// matched against the book up to `tick` and never rest, so `orderId` is zero for them.
+        function placeWithOptions(address token, uint128 amount, bool isBid, int16 tick, TimeInForce timeInForce) external returns (uint128 orderId, uint128 amountFilled);
+        /// Places a limit order that is removed from the book and refunded once `expiresAt` is reached
+        function placeWithExpiry(address token, uint128 amount, bool isBid, int16 tick, uint64 expiresAt) external returns (uint128 orderId);
         function cancel(uint128 orderId) external;
+        /// Removes up to `maxOrders` expired orders from a book, scanning from the best ticks
+        function purgeExpired(bytes32 bookKey, uint64 maxOrders) external returns (uint64 purged);
         function executeBlock() external;
 
         // Swap Functions
@@ -57,6 +72,12 @@ crate::sol! {
         function swapExactAmountOut(address tokenIn, address tokenOut, uint128 amountOut, uint128 maxAmountIn) external returns (uint128 amountIn);
         function quoteSwapExactAmountIn(address tokenIn, address tokenOut, uint128 amountIn) external view returns (uint128 amountOut);
         function quoteSwapExactAmountOut(address tokenIn, address tokenOut, uint128 amountOut) external view returns (uint128 amountIn);
+        /// Swaps along an explicit `path` of adjacent pairs. `amounts[i]` is the amount of `path[i]`
+        /// that each hop executed, so `amounts[0]` is the input and the last entry the output.
+        function swapExactAmountInPath(address[] calldata path, uint128 amountIn, uint128 minAmountOut) external returns (uint128[] memory amounts);
+        function swapExactAmountOutPath(address[] calldata path, uint128 amountOut, uint128 maxAmountIn) external returns (uint128[] memory amounts);
+        function quoteSwapExactAmountInPath(address[] calldata path, uint128 amountIn) external view returns (uint128[] memory amounts);
+        function quoteSwapExactAmountOutPath(address[] calldata path, uint128 amountOut) external view returns (uint128[] memory amounts);
 
         // Balance Management
         function balanceOf(address user, address token) external view returns (uint128);
@@ -64,6 +85,8 @@ crate::sol! {
 
         // View Functions
         function getOrder(uint128 orderId) external view returns (Order memory);
+        /// Returns the timestamp at which an order expires, or zero if it never expires
+        function getOrderExpiry(uint128 orderId) external view returns (uint64 expiresAt);
 
         function getTickLevel(address base, int16 tick, bool isBid) external view returns (uint128 head, uint128 tail, uint128 totalLiquidity);
         function pairKey(address tokenA, address tokenB) external pure returns (bytes32);
@@ -71,6 +94,11 @@ crate::sol! {
         function nextOrderId() external view returns (uint128);
         function pendingOrderId() external view returns (uint128);
         function books(bytes32 pairKey) external view returns (Orderbook memory);
+        /// Returns the cumulative mid tick of a book as of each `secondsAgos` entry, sampled at the
+        /// start of each block that changes the book's best ticks. The time-weighted average tick
+        /// between two points is the difference of their cumulatives divided by the seconds
+        /// elapsed between them.
+        function observe(bytes32 bookKey, uint32[] calldata secondsAgos) external view returns (int64[] memory tickCumulatives);
 
         // Constants (exposed as view functions)
         function MIN_TICK() external pure returns (int16);
@@ -93,6 +121,9 @@ crate::sol! {
         /// Post-Allegretto: OrderFilled event with taker parameter
         event OrderFilled(uint128 indexed orderId, address indexed maker, address indexed taker, uint128 amountFilled, bool partialFill);
         event OrderCancelled(uint128 indexed orderId);
+        event OrderExpired(uint128 indexed orderId, address indexed maker);
+        event Swap(address indexed sender, address indexed tokenIn, address indexed tokenOut, address[] path, uint128[] amounts);
+        event ImmediateOrderExecuted(address indexed taker, address indexed token, bool isBid, int16 tick, TimeInForce timeInForce, uint128 amountFilled, uint128 amountCancelled);
 
         // Errors
         error Unauthorized();
@@ -110,6 +141,13 @@ crate::sol! {
         error MaxInputExceeded();
         error BelowMinimumOrderSize(uint128 amount);
         error InvalidBaseToken();
+        error InvalidTimeInForce();
+        error InvalidExpiry();
+        error InvalidPath();
+        error OracleNotInitialized();
+        error ObservationTooOld(uint32 secondsAgo);
+        error OrderWouldCross(int16 tick);
+        error FillOrKillNotFilled(uint128 amount, uint128 available);
     }
 }
 
@@ -188,4 +226,41 @@ impl StablecoinExchangeError {
     pub const fn invalid_base_token() -> Self {
         Self::InvalidBaseToken(IStablecoinExchange::InvalidBaseToken {})
     }
+
+    /// Creates an error for an order expiry that is not in the future.
+    pub const fn invalid_expiry() -> Self {
+        Self::InvalidExpiry(IStablecoinExchange::InvalidExpiry {})
+    }
+
+    /// Creates an error for a swap path that is too short or visits a token twice.
+    pub const fn invalid_path() -> Self {
+        Self::InvalidPath(IStablecoinExchange::InvalidPath {})
+    }
+
+    /// Creates an error for a book that has no price observations yet.
+    pub const fn oracle_not_initialized() -> Self {
+        Self::OracleNotInitialized(IStablecoinExchange::OracleNotInitialized {})
+    }
+
+    /// Creates an error for a requested observation older than the oracle history.
+    pub const fn observation_too_old(seconds_ago: u32) -> Self {
+        Self::ObservationTooOld(IStablecoinExchange::ObservationTooOld {
+            secondsAgo: seconds_ago,
+        })
+    }
+
+    /// Creates an error for an unknown time-in-force.
+    pub const fn invalid_time_in_force() -> Self {
+        Self::InvalidTimeInForce(IStablecoinExchange::InvalidTimeInForce {})
+    }
+
+    /// Creates an error for a post-only order that would cross the book.
+    pub const fn order_would_cross(tick: i16) -> Self {
+        Self::OrderWouldCross(IStablecoinExchange::OrderWouldCross { tick })
+    }
+
+    /// Creates an error for a fill-or-kill order that cannot be filled completely.
+    pub const fn fill_or_kill_not_filled(amount: u128, available: u128) -> Self {
+        Self::FillOrKillNotFilled(IStablecoinExchange::FillOrKillNotFilled { amount, available })
+    }
 }
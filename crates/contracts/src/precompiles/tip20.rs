@@ -1,6 +1,6 @@
 pub use IRolesAuth::{IRolesAuthErrors as RolesAuthError, IRolesAuthEvents as RolesAuthEvent};
 pub use ITIP20::{ITIP20Errors as TIP20Error, ITIP20Events as TIP20Event};
-use alloy_primitives::{Address, U256};
+use alloy_primitives::{Address, B256, U256};
 
 crate::sol! {
     #[derive(Debug, PartialEq, Eq)]
@@ -56,13 +56,31 @@ crate::sol! {
         function paused() external view returns (bool);
         function transferPolicyId() external view returns (uint64);
         function burnBlocked(address from, uint256 amount) external;
+        function forceTransfer(address from, address to, uint256 amount, bytes32 memo) external;
+        function isFrozen(address account) external view returns (bool);
         function mintWithMemo(address to, uint256 amount, bytes32 memo) external;
         function burnWithMemo(uint256 amount, bytes32 memo) external;
         function transferWithMemo(address to, uint256 amount, bytes32 memo) external;
         function transferFromWithMemo(address from, address to, uint256 amount, bytes32 memo) external returns (bool);
+        function transferBatch(address[] calldata to, uint256[] calldata amounts, bytes32[] calldata memos) external;
         function feeRecipient() external view returns (address);
         function setFeeRecipient(address newRecipient) external view returns (address);
 
+        // EIP-2612 Permit
+        function nonces(address owner) external view returns (uint256);
+        function DOMAIN_SEPARATOR() external view returns (bytes32);
+        function permit(address owner, address spender, uint256 value, uint256 deadline, uint8 v, bytes32 r, bytes32 s) external;
+        /// @notice Same as `permit`, but accepts any Tempo primitive signature (secp256k1, P256 or WebAuthn)
+        function permitWithSignature(address owner, address spender, uint256 value, uint256 deadline, bytes signature) external;
+
+        // ERC-3009 Transfer With Authorization
+        function authorizationState(address authorizer, bytes32 nonce) external view returns (bool);
+        function transferWithAuthorization(address from, address to, uint256 value, uint256 validAfter, uint256 validBefore, bytes32 nonce, uint8 v, bytes32 r, bytes32 s) external;
+        function receiveWithAuthorization(address from, address to, uint256 value, uint256 validAfter, uint256 validBefore, bytes32 nonce, uint8 v, bytes32 r, bytes32 s) external;
+        function transferWithAuthorizationWithMemo(address from, address to, uint256 value, uint256 validAfter, uint256 validBefore, bytes32 nonce, bytes32 memo, uint8 v, bytes32 r, bytes32 s) external;
+        function receiveWithAuthorizationWithMemo(address from, address to, uint256 value, uint256 validAfter, uint256 validBefore, bytes32 nonce, bytes32 memo, uint8 v, bytes32 r, bytes32 s) external;
+        function cancelAuthorization(address authorizer, bytes32 nonce, uint8 v, bytes32 r, bytes32 s) external;
+
         // Admin Functions
         function changeTransferPolicyId(uint64 newPolicyId) external;
         function setSupplyCap(uint256 newSupplyCap) external;
@@ -70,6 +88,17 @@ crate::sol! {
         function unpause() external;
         function setNextQuoteToken(address newQuoteToken) external;
         function completeQuoteTokenUpdate() external;
+        function freeze(address account) external;
+        function unfreeze(address account) external;
+
+        // Admin Timelock
+        function adminTimelockDelay() external view returns (uint64);
+        function setAdminTimelockDelay(uint64 delay) external;
+        function queuedActionEta(bytes32 actionId) external view returns (uint64);
+        /// @notice Queues an encoded call to a timelocked admin function for execution after the delay
+        function queueAction(bytes calldata data) external returns (bytes32 actionId);
+        function executeAction(bytes calldata data) external;
+        function cancelAction(bytes32 actionId) external;
 
         /// @notice Returns the role identifier for pausing the contract
         /// @return The pause role identifier
@@ -87,6 +116,14 @@ crate::sol! {
         /// @return The burn blocked role identifier
         function BURN_BLOCKED_ROLE() external view returns (bytes32);
 
+        /// @notice Returns the role identifier for forcing transfers out of any account
+        /// @return The force transfer role identifier
+        function FORCE_TRANSFER_ROLE() external view returns (bytes32);
+
+        /// @notice Returns the role identifier for freezing and unfreezing accounts
+        /// @return The freeze role identifier
+        function FREEZE_ROLE() external view returns (bytes32);
+
         struct RewardStream {
             address funder;
             uint64 startTime;
@@ -101,6 +138,11 @@ crate::sol! {
             uint256 rewardBalance;
         }
 
+        struct TokenRewardInfo {
+            uint256 rewardPerToken;
+            uint256 rewardBalance;
+        }
+
         // Reward Functions
         function startReward(uint256 amount, uint32 secs) external returns (uint64);
         function setRewardRecipient(address recipient) external;
@@ -113,22 +155,37 @@ crate::sol! {
         function nextStreamId() external view returns (uint64);
         function userRewardInfo(address account) external view returns (UserRewardInfo memory);
 
+        // Multi-asset Reward Functions
+        function startTokenReward(address rewardToken, uint256 amount, uint32 secs) external returns (uint64);
+        function claimRewards(address rewardToken) external returns (uint256);
+        function rewardTokens() external view returns (address[] memory);
+        function tokenRewardInfo(address account, address rewardToken) external view returns (TokenRewardInfo memory);
+
         // Events
         event Transfer(address indexed from, address indexed to, uint256 amount);
         event Approval(address indexed owner, address indexed spender, uint256 amount);
         event Mint(address indexed to, uint256 amount);
         event Burn(address indexed from, uint256 amount);
         event BurnBlocked(address indexed from, uint256 amount);
+        event ForcedTransfer(address indexed operator, address indexed from, address indexed to, uint256 amount, bytes32 memo);
         event TransferWithMemo(address indexed from, address indexed to, uint256 amount, bytes32 indexed memo);
         event TransferPolicyUpdate(address indexed updater, uint64 indexed newPolicyId);
         event SupplyCapUpdate(address indexed updater, uint256 indexed newSupplyCap);
         event PauseStateUpdate(address indexed updater, bool isPaused);
+        event FreezeStateUpdate(address indexed updater, address indexed account, bool isFrozen);
+        event AdminTimelockDelayUpdate(address indexed updater, uint64 newDelay);
+        event ActionQueued(bytes32 indexed actionId, address indexed queuer, bytes data, uint64 eta);
+        event ActionExecuted(bytes32 indexed actionId, address indexed executor);
+        event ActionCancelled(bytes32 indexed actionId, address indexed canceller);
         event NextQuoteTokenSet(address indexed updater, address indexed nextQuoteToken);
         event QuoteTokenUpdate(address indexed updater, address indexed newQuoteToken);
         event RewardScheduled(address indexed funder, uint64 indexed id, uint256 amount, uint32 durationSeconds);
         event RewardCanceled(address indexed funder, uint64 indexed id, uint256 refund);
+        event TokenRewardScheduled(address indexed funder, address indexed rewardToken, uint256 amount);
         event RewardRecipientSet(address indexed holder, address indexed recipient);
         event FeeRecipientUpdated(address indexed updater, address indexed newRecipient);
+        event AuthorizationUsed(address indexed authorizer, bytes32 indexed nonce);
+        event AuthorizationCanceled(address indexed authorizer, bytes32 indexed nonce);
 
         // Errors
         error InsufficientBalance(uint256 available, uint256 required, address token);
@@ -153,6 +210,20 @@ crate::sol! {
         error ProtectedAddress();
         error InvalidToken();
         error InvalidTransferPolicyId();
+        error PermitExpired();
+        error InvalidSignature();
+        error AuthorizationNotYetValid();
+        error AuthorizationExpired();
+        error AuthorizationAlreadyUsed();
+        error CallerNotPayee();
+        error AccountFrozen(address account);
+        error TimelockRequired();
+        error InvalidTimelockDelay();
+        error InvalidTimelockAction();
+        error ActionAlreadyQueued(bytes32 actionId);
+        error ActionNotQueued(bytes32 actionId);
+        error ActionNotReady(bytes32 actionId, uint64 eta);
+        error TooManyRewardTokens();
     }
 }
 
@@ -277,4 +348,81 @@ impl TIP20Error {
     pub const fn invalid_transfer_policy_id() -> Self {
         Self::InvalidTransferPolicyId(ITIP20::InvalidTransferPolicyId {})
     }
+
+    /// Error when a permit is submitted after its deadline
+    pub const fn permit_expired() -> Self {
+        Self::PermitExpired(ITIP20::PermitExpired {})
+    }
+
+    /// Error when a permit signature is malformed or not signed by the owner
+    pub const fn invalid_signature() -> Self {
+        Self::InvalidSignature(ITIP20::InvalidSignature {})
+    }
+
+    /// Error when a transfer authorization is submitted before `validAfter`
+    pub const fn authorization_not_yet_valid() -> Self {
+        Self::AuthorizationNotYetValid(ITIP20::AuthorizationNotYetValid {})
+    }
+
+    /// Error when a transfer authorization is submitted at or after `validBefore`
+    pub const fn authorization_expired() -> Self {
+        Self::AuthorizationExpired(ITIP20::AuthorizationExpired {})
+    }
+
+    /// Error when an authorization nonce has already been used or canceled
+    pub const fn authorization_already_used() -> Self {
+        Self::AuthorizationAlreadyUsed(ITIP20::AuthorizationAlreadyUsed {})
+    }
+
+    /// Error when `receiveWithAuthorization` is not called by the payee
+    pub const fn caller_not_payee() -> Self {
+        Self::CallerNotPayee(ITIP20::CallerNotPayee {})
+    }
+
+    /// Error when a frozen account sends or receives tokens
+    pub const fn account_frozen(account: Address) -> Self {
+        Self::AccountFrozen(ITIP20::AccountFrozen { account })
+    }
+
+    /// Error when a timelocked admin function is called directly while the timelock is enabled
+    pub const fn timelock_required() -> Self {
+        Self::TimelockRequired(ITIP20::TimelockRequired {})
+    }
+
+    /// Error when the admin timelock delay exceeds the maximum
+    pub const fn invalid_timelock_delay() -> Self {
+        Self::InvalidTimelockDelay(ITIP20::InvalidTimelockDelay {})
+    }
+
+    /// Error when a queued action is not a call to a timelocked admin function
+    pub const fn invalid_timelock_action() -> Self {
+        Self::InvalidTimelockAction(ITIP20::InvalidTimelockAction {})
+    }
+
+    /// Error when queueing an action that is already queued
+    pub const fn action_already_queued(action_id: B256) -> Self {
+        Self::ActionAlreadyQueued(ITIP20::ActionAlreadyQueued {
+            actionId: action_id,
+        })
+    }
+
+    /// Error when executing or cancelling an action that is not queued
+    pub const fn action_not_queued(action_id: B256) -> Self {
+        Self::ActionNotQueued(ITIP20::ActionNotQueued {
+            actionId: action_id,
+        })
+    }
+
+    /// Error when executing an action before its eta
+    pub const fn action_not_ready(action_id: B256, eta: u64) -> Self {
+        Self::ActionNotReady(ITIP20::ActionNotReady {
+            actionId: action_id,
+            eta,
+        })
+    }
+
+    /// Error when a token already distributes the maximum number of foreign reward tokens
+    pub const fn too_many_reward_tokens() -> Self {
+        Self::TooManyRewardTokens(ITIP20::TooManyRewardTokens {})
+    }
 }
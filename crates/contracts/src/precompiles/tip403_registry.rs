@@ -9,7 +9,20 @@ crate::sol! {
         // Enums
         enum PolicyType {
             WHITELIST,
-            BLACKLIST
+            BLACKLIST,
+            COMPOUND,
+            DIRECTIONAL
+        }
+
+        enum CompoundOperator {
+            AND,
+            OR
+        }
+
+        enum PolicyRole {
+            SENDER,
+            RECIPIENT,
+            MINT_RECIPIENT
         }
 
         // View Functions
@@ -17,6 +30,11 @@ crate::sol! {
         function policyExists(uint64 policyId) external view returns (bool);
         function policyData(uint64 policyId) external view returns (PolicyType policyType, address admin);
         function isAuthorized(uint64 policyId, address user) external view returns (bool);
+        function isAuthorizedAs(uint64 policyId, address user, PolicyRole role) external view returns (bool);
+        function isTransferAuthorized(uint64 policyId, address from, address to) external view returns (bool);
+        function compoundPolicyData(uint64 policyId) external view returns (CompoundOperator operator, uint64[] memory policyIds);
+        function directionalPolicyData(uint64 policyId) external view returns (uint64 senderPolicyId, uint64 recipientPolicyId, uint64 mintRecipientPolicyId);
+        function membershipExpiry(uint64 policyId, address account) external view returns (uint64);
 
         // State-Changing Functions
         function createPolicy(address admin, PolicyType policyType) external returns (uint64);
@@ -24,16 +42,27 @@ crate::sol! {
         function setPolicyAdmin(uint64 policyId, address admin) external;
         function modifyPolicyWhitelist(uint64 policyId, address account, bool allowed) external;
         function modifyPolicyBlacklist(uint64 policyId, address account, bool restricted) external;
+        function modifyPolicyMembers(uint64 policyId, address[] calldata accounts, bool allowed, uint64 expiry) external;
+        function createCompoundPolicy(address admin, CompoundOperator operator, uint64[] calldata policyIds) external returns (uint64);
+        function setCompoundPolicy(uint64 policyId, CompoundOperator operator, uint64[] calldata policyIds) external;
+        function createDirectionalPolicy(address admin, uint64 senderPolicyId, uint64 recipientPolicyId, uint64 mintRecipientPolicyId) external returns (uint64);
+        function setDirectionalPolicy(uint64 policyId, uint64 senderPolicyId, uint64 recipientPolicyId, uint64 mintRecipientPolicyId) external;
 
         // Events
         event PolicyAdminUpdated(uint64 indexed policyId, address indexed updater, address indexed admin);
         event PolicyCreated(uint64 indexed policyId, address indexed updater, PolicyType policyType);
         event WhitelistUpdated(uint64 indexed policyId, address indexed updater, address indexed account, bool allowed);
         event BlacklistUpdated(uint64 indexed policyId, address indexed updater, address indexed account, bool restricted);
+        event PolicyMembershipUpdated(uint64 indexed policyId, address indexed updater, address indexed account, bool isMember, uint64 expiry);
+        event CompoundPolicyUpdated(uint64 indexed policyId, address indexed updater, CompoundOperator operator, uint64[] policyIds);
+        event DirectionalPolicyUpdated(uint64 indexed policyId, address indexed updater, uint64 senderPolicyId, uint64 recipientPolicyId, uint64 mintRecipientPolicyId);
 
         // Errors
         error Unauthorized();
         error IncompatiblePolicyType();
+        error PolicyNotFound();
+        error InvalidCompoundPolicy();
+        error InvalidMembershipExpiry();
     }
 }
 
@@ -47,4 +76,19 @@ impl TIP403RegistryError {
     pub const fn incompatible_policy_type() -> Self {
         Self::IncompatiblePolicyType(ITIP403Registry::IncompatiblePolicyType {})
     }
+
+    /// Creates an error for references to policies that do not exist
+    pub const fn policy_not_found() -> Self {
+        Self::PolicyNotFound(ITIP403Registry::PolicyNotFound {})
+    }
+
+    /// Creates an error for compound policies with invalid members
+    pub const fn invalid_compound_policy() -> Self {
+        Self::InvalidCompoundPolicy(ITIP403Registry::InvalidCompoundPolicy {})
+    }
+
+    /// Creates an error for membership expiries that are already in the past
+    pub const fn invalid_membership_expiry() -> Self {
+        Self::InvalidMembershipExpiry(ITIP403Registry::InvalidMembershipExpiry {})
+    }
 }
@@ -77,6 +77,12 @@ sol! {
             address token1;
         }
 
+        /// Swap rates of a pool, scaled by `SCALE`
+        struct PoolFees {
+            uint16 feeSwapRate;
+            uint16 rebalanceRate;
+        }
+
 
         // Constants
         function M() external view returns (uint256);
@@ -88,15 +94,37 @@ sol! {
         function getPoolId(address userToken, address validatorToken) external pure returns (bytes32);
         function getPool(address userToken, address validatorToken) external view returns (Pool memory);
         function pools(bytes32 poolId) external view returns (Pool memory);
+        /// Returns the rates applied to fee swaps (`m`) and rebalance swaps (`n`) of a pool
+        function getPoolFees(address userToken, address validatorToken) external view returns (PoolFees memory);
+        /// Sets the swap rates of a pool. Only governance can set them, and not while fee swaps of
+        /// the pool are pending.
+        function setPoolFees(address userToken, address validatorToken, uint16 feeSwapRate, uint16 rebalanceRate) external;
 
         // Liquidity Operations
         function mint(address userToken, address validatorToken, uint256 amountUserToken, uint256 amountValidatorToken, address to) external returns (uint256 liquidity);
         function mintWithValidatorToken(address userToken, address validatorToken, uint256 amountValidatorToken, address to) external returns (uint256 liquidity);
         function burn(address userToken, address validatorToken, uint256 liquidity, address to) external returns (uint256 amountUserToken, uint256 amountValidatorToken);
+        /// Same as `mintWithValidatorToken`, reverting if fewer than `minLiquidity` LP tokens are
+        /// minted or the block timestamp is past `deadline`
+        function mintWithValidatorTokenBounded(address userToken, address validatorToken, uint256 amountValidatorToken, uint256 minLiquidity, address to, uint256 deadline) external returns (uint256 liquidity);
+        /// Same as `burn`, reverting if either amount is below its minimum or the block timestamp
+        /// is past `deadline`
+        function burnBounded(address userToken, address validatorToken, uint256 liquidity, uint256 minAmountUserToken, uint256 minAmountValidatorToken, address to, uint256 deadline) external returns (uint256 amountUserToken, uint256 amountValidatorToken);
+        /// Burns LP tokens and returns the whole withdrawal in `outToken`, swapping the other
+        /// token's share against the pool
+        function burnSingleSided(address userToken, address validatorToken, uint256 liquidity, address outToken, uint256 minOut, address to, uint256 deadline) external returns (uint256 amountOut);
 
         // Liquidity Balances
         function totalSupply(bytes32 poolId) external view returns (uint256);
         function liquidityBalances(bytes32 poolId, address user) external view returns (uint256);
+        function liquidityAllowance(bytes32 poolId, address owner, address spender) external view returns (uint256);
+
+        // Liquidity Transfers
+        /// Transfers LP tokens of a pool. Both tokens of the pool must authorize the transfer
+        /// under their TIP-403 policy.
+        function transferLiquidity(address userToken, address validatorToken, address to, uint256 liquidity) external returns (bool);
+        function approveLiquidity(address userToken, address validatorToken, address spender, uint256 liquidity) external returns (bool);
+        function transferLiquidityFrom(address userToken, address validatorToken, address from, address to, uint256 liquidity) external returns (bool);
 
         // Swapping
         function rebalanceSwap(address userToken, address validatorToken, uint256 amountOut, address to) external returns (uint256 amountIn);
@@ -104,6 +132,9 @@ sol! {
         // Events
         event Mint(address indexed sender, address indexed userToken, address indexed validatorToken, uint256 amountUserToken, uint256 amountValidatorToken, uint256 liquidity);
         event Burn(address indexed sender, address indexed userToken, address indexed validatorToken, uint256 amountUserToken, uint256 amountValidatorToken, uint256 liquidity, address to);
+        event LiquidityTransfer(bytes32 indexed poolId, address indexed from, address indexed to, uint256 liquidity);
+        event LiquidityApproval(bytes32 indexed poolId, address indexed owner, address indexed spender, uint256 liquidity);
+        event PoolFeesUpdate(address indexed userToken, address indexed validatorToken, address indexed updater, uint16 feeSwapRate, uint16 rebalanceRate);
         event RebalanceSwap(address indexed userToken, address indexed validatorToken, address indexed swapper, uint256 amountIn, uint256 amountOut);
         event FeeSwap(
             address indexed userToken,
@@ -134,6 +165,12 @@ sol! {
         error InsufficientLiquidityForPending();
         error TokenTransferFailed();
         error InternalError();
+        error InvalidPoolFees();
+        error OnlyGovernance();
+        error InsufficientLiquidityAllowance();
+        error LiquidityPolicyForbids();
+        error DeadlineExpired();
+        error SlippageExceeded();
     }
 }
 
@@ -215,6 +252,41 @@ impl TIPFeeAMMError {
         Self::InsufficientLiquidity(ITIPFeeAMM::InsufficientLiquidity {})
     }
 
+    /// Creates an error for pool swap rates outside of the protocol bounds.
+    pub const fn invalid_pool_fees() -> Self {
+        Self::InvalidPoolFees(ITIPFeeAMM::InvalidPoolFees {})
+    }
+
+    /// Creates an error for only governance access.
+    pub const fn only_governance() -> Self {
+        Self::OnlyGovernance(ITIPFeeAMM::OnlyGovernance {})
+    }
+
+    /// Creates an error for an operation that would invalidate pending fee swaps.
+    pub const fn cannot_support_pending_swaps() -> Self {
+        Self::CannotSupportPendingSwaps(ITIPFeeAMM::CannotSupportPendingSwaps {})
+    }
+
+    /// Creates an error for insufficient LP token allowance.
+    pub const fn insufficient_liquidity_allowance() -> Self {
+        Self::InsufficientLiquidityAllowance(ITIPFeeAMM::InsufficientLiquidityAllowance {})
+    }
+
+    /// Creates an error when a pool token's TIP-403 policy forbids an LP token transfer.
+    pub const fn liquidity_policy_forbids() -> Self {
+        Self::LiquidityPolicyForbids(ITIPFeeAMM::LiquidityPolicyForbids {})
+    }
+
+    /// Creates an error for a liquidity operation executed after its deadline.
+    pub const fn deadline_expired() -> Self {
+        Self::DeadlineExpired(ITIPFeeAMM::DeadlineExpired {})
+    }
+
+    /// Creates an error for a liquidity operation yielding less than its minimum amount.
+    pub const fn slippage_exceeded() -> Self {
+        Self::SlippageExceeded(ITIPFeeAMM::SlippageExceeded {})
+    }
+
     /// Creates an error for insufficient pool balance.
     pub const fn insufficient_pool_balance() -> Self {
         Self::InsufficientPoolBalance(ITIPFeeAMM::InsufficientPoolBalance {})
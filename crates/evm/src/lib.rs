@@ -38,7 +38,7 @@ use reth_evm_ethereum::EthEvmConfig;
 use tempo_chainspec::{TempoChainSpec, hardfork::TempoHardforks};
 use tempo_revm::evm::TempoContext;
 
-pub use tempo_revm::{TempoBlockEnv, TempoHaltReason, TempoStateAccess};
+pub use tempo_revm::{TempoBlockEnv, TempoHaltReason, TempoStateAccess, TempoTx};
 
 /// Tempo-related EVM configuration.
 #[derive(Debug, Clone)]
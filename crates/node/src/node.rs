@@ -2,9 +2,11 @@ use crate::{
     TempoPayloadTypes,
     engine::TempoEngineValidator,
     rpc::{
-        TempoAdminApi, TempoAdminApiServer, TempoAmm, TempoAmmApiServer, TempoDex,
-        TempoDexApiServer, TempoEthApiBuilder, TempoEthExt, TempoEthExtApiServer, TempoPolicy,
-        TempoPolicyApiServer, TempoToken, TempoTokenApiServer,
+        PolicyIndex, PoolIndex, TempoAdminApi, TempoAdminApiServer, TempoAmm, TempoAmmApiServer,
+        TempoDex, TempoDexApiServer, TempoEthApiBuilder, TempoEthExt, TempoEthExtApiServer,
+        TempoFee, TempoFeeApiServer, TempoPolicy, TempoPolicyApiServer, TempoToken,
+        TempoTokenApiServer, TokenIndex, TransactionIndex,
+        index::{INDEX_SNAPSHOT_DIR, maintain_index},
     },
 };
 use alloy_primitives::B256;
@@ -30,12 +32,13 @@ use reth_primitives_traits::SealedHeader;
 use reth_provider::{EthStorage, providers::ProviderFactoryBuilder};
 use reth_rpc_builder::{Identity, RethRpcModule};
 use reth_rpc_eth_api::{
-    RpcNodeCore,
+    EthApiTypes, RpcNodeCore,
     helpers::config::{EthConfigApiServer, EthConfigHandler},
 };
 use reth_tracing::tracing::{debug, info};
 use reth_transaction_pool::{TransactionValidationTaskExecutor, blobstore::InMemoryBlobStore};
 use std::{default::Default, sync::Arc};
+use tempo_alloy::TempoNetwork;
 use tempo_chainspec::spec::{TEMPO_BASE_FEE, TempoChainSpec};
 use tempo_consensus::TempoConsensus;
 use tempo_evm::{TempoEvmConfig, evm::TempoEvmFactory};
@@ -169,12 +172,16 @@ where
 
 impl<N, EthB, PVB, EVB> NodeAddOns<N> for TempoAddOns<N, EthB, PVB, EVB>
 where
-    N: FullNodeComponents<Types = TempoNode, Evm = TempoEvmConfig>,
+    N: FullNodeComponents<
+            Types = TempoNode,
+            Evm = TempoEvmConfig,
+            Pool = TempoTransactionPool<<N as FullNodeTypes>::Provider>,
+        >,
     EthB: EthApiBuilder<N>,
     PVB: Send + PayloadValidatorBuilder<N>,
     EVB: EngineValidatorBuilder<N>,
-    EthB::EthApi:
-        RpcNodeCore<Evm = TempoEvmConfig, Primitives: NodePrimitives<BlockHeader = TempoHeader>>,
+    EthB::EthApi: RpcNodeCore<Evm = TempoEvmConfig, Primitives: NodePrimitives<BlockHeader = TempoHeader>>
+        + EthApiTypes<NetworkTypes = TempoNetwork>,
 {
     type Handle = <RpcAddOns<N, EthB, PVB, NoopEngineApiBuilder, EVB> as NodeAddOns<N>>::Handle;
 
@@ -182,6 +189,46 @@ where
         let eth_config =
             EthConfigHandler::new(ctx.node.provider().clone(), ctx.node.evm_config().clone());
 
+        // Spawn the indexes backing the custom RPC namespaces
+        let index_dir = ctx.config.datadir().data_dir().join(INDEX_SNAPSHOT_DIR);
+        let token_index = TokenIndex::default();
+        ctx.node.task_executor().spawn_critical(
+            "rpc index - tokens",
+            maintain_index(
+                token_index.clone(),
+                ctx.node.provider().clone(),
+                index_dir.clone(),
+            ),
+        );
+        let pool_index = PoolIndex::default();
+        ctx.node.task_executor().spawn_critical(
+            "rpc index - pools",
+            maintain_index(
+                pool_index.clone(),
+                ctx.node.provider().clone(),
+                index_dir.clone(),
+            ),
+        );
+        let policy_index = PolicyIndex::default();
+        ctx.node.task_executor().spawn_critical(
+            "rpc index - policies",
+            maintain_index(
+                policy_index.clone(),
+                ctx.node.provider().clone(),
+                index_dir.clone(),
+            ),
+        );
+        let amm_liquidity_cache = ctx.node.pool().amm_liquidity_cache();
+        let transaction_index = TransactionIndex::default();
+        ctx.node.task_executor().spawn_critical(
+            "rpc index - transactions",
+            maintain_index(
+                transaction_index.clone(),
+                ctx.node.provider().clone(),
+                index_dir,
+            ),
+        );
+
         self.inner
             .launch_add_ons_with(ctx, move |container| {
                 let reth_node_builder::rpc::RpcModuleContainer {
@@ -190,10 +237,11 @@ where
 
                 let eth_api = registry.eth_api().clone();
                 let dex = TempoDex::new(eth_api.clone());
-                let amm = TempoAmm::new(eth_api.clone());
-                let token = TempoToken::new(eth_api.clone());
-                let policy = TempoPolicy::new(eth_api.clone());
-                let eth_ext = TempoEthExt::new(eth_api);
+                let amm = TempoAmm::new(eth_api.clone(), pool_index, token_index.clone());
+                let token = TempoToken::new(eth_api.clone(), token_index);
+                let policy = TempoPolicy::new(eth_api.clone(), policy_index);
+                let fee = TempoFee::new(eth_api.clone(), amm_liquidity_cache);
+                let eth_ext = TempoEthExt::new(eth_api, transaction_index);
                 let admin = TempoAdminApi::new(self.validator_key);
 
                 modules.merge_configured(dex.into_rpc())?;
@@ -201,6 +249,7 @@ where
                 modules.merge_configured(token.into_rpc())?;
                 modules.merge_configured(policy.into_rpc())?;
                 modules.merge_configured(eth_ext.into_rpc())?;
+                modules.merge_configured(fee.into_rpc())?;
                 modules.merge_if_module_configured(RethRpcModule::Admin, admin.into_rpc())?;
                 modules.merge_if_module_configured(RethRpcModule::Eth, eth_config.into_rpc())?;
 
@@ -212,12 +261,16 @@ where
 
 impl<N, EthB, PVB, EVB> RethRpcAddOns<N> for TempoAddOns<N, EthB, PVB, EVB>
 where
-    N: FullNodeComponents<Types = TempoNode, Evm = TempoEvmConfig>,
+    N: FullNodeComponents<
+            Types = TempoNode,
+            Evm = TempoEvmConfig,
+            Pool = TempoTransactionPool<<N as FullNodeTypes>::Provider>,
+        >,
     EthB: EthApiBuilder<N>,
     PVB: PayloadValidatorBuilder<N>,
     EVB: EngineValidatorBuilder<N>,
-    EthB::EthApi:
-        RpcNodeCore<Evm = TempoEvmConfig, Primitives: NodePrimitives<BlockHeader = TempoHeader>>,
+    EthB::EthApi: RpcNodeCore<Evm = TempoEvmConfig, Primitives: NodePrimitives<BlockHeader = TempoHeader>>
+        + EthApiTypes<NetworkTypes = TempoNetwork>,
 {
     type EthApi = EthB::EthApi;
 
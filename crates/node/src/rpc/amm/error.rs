@@ -0,0 +1,69 @@
+use crate::rpc::{index::IndexNotSynced, state::StateAccessError};
+use alloy_primitives::B256;
+use jsonrpsee::types::ErrorObject;
+use reth_rpc_eth_types::{EthApiError, error::ToRpcError};
+use tempo_precompiles::error::TempoPrecompileError;
+
+/// AMM API specific errors that extend [`EthApiError`].
+#[derive(Debug, thiserror::Error)]
+pub enum AmmApiError {
+    /// Precompile storage errors
+    #[error(transparent)]
+    Precompile(#[from] TempoPrecompileError),
+
+    /// Failed to access state
+    #[error(transparent)]
+    State(#[from] StateAccessError),
+
+    /// Invalid pool cursor format
+    #[error("invalid pool cursor: failed to parse as B256")]
+    InvalidPoolCursor(String),
+
+    /// Pool cursor not found in available pools
+    #[error("pool cursor {0} not found in available pools")]
+    PoolCursorNotFound(B256),
+
+    /// Unknown field to sort by
+    #[error("invalid sort field: {0}")]
+    InvalidSortField(String),
+
+    /// The index backing the endpoint has not caught up with the canonical chain
+    #[error(transparent)]
+    IndexNotSynced(#[from] IndexNotSynced),
+}
+
+impl AmmApiError {
+    /// Returns the rpc error for this error
+    const fn error_code(&self) -> i32 {
+        match self {
+            Self::InvalidPoolCursor(_)
+            | Self::PoolCursorNotFound(_)
+            | Self::InvalidSortField(_) => jsonrpsee::types::error::INVALID_PARAMS_CODE,
+            _ => jsonrpsee::types::error::INTERNAL_ERROR_CODE,
+        }
+    }
+}
+
+impl From<AmmApiError> for EthApiError {
+    fn from(err: AmmApiError) -> Self {
+        match err {
+            AmmApiError::State(StateAccessError::HeaderNotFound(block_id)) => {
+                Self::HeaderNotFound(block_id)
+            }
+            // All other errors use the Other variant with our error type
+            other => Self::other(other),
+        }
+    }
+}
+
+impl ToRpcError for AmmApiError {
+    fn to_rpc_error(&self) -> ErrorObject<'static> {
+        ErrorObject::owned(self.error_code(), self.to_string(), None::<()>)
+    }
+}
+
+impl From<AmmApiError> for ErrorObject<'static> {
+    fn from(value: AmmApiError) -> Self {
+        value.to_rpc_error()
+    }
+}
@@ -0,0 +1,189 @@
+use crate::rpc::index::{CanonicalIndex, IndexStatus, IndexedLog, indexed_logs};
+use alloy_primitives::{Address, B256, BlockNumber};
+use alloy_sol_types::SolEventInterface;
+use parking_lot::RwLock;
+use reth_primitives_traits::{AlloyBlockHeader, RecoveredBlock};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{collections::BTreeMap, sync::Arc};
+use tempo_precompiles::{
+    TIP_FEE_MANAGER_ADDRESS,
+    tip_fee_manager::{TIPFeeAMMEvent, amm::PoolKey},
+};
+use tempo_primitives::{Block, TempoReceipt};
+
+/// A fee AMM pool that was created through `mint` or `mintWithValidatorToken`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexedPool {
+    /// Token the fees are paid in
+    pub user_token: Address,
+    /// Token the validator receives
+    pub validator_token: Address,
+    /// Block the first liquidity was minted in
+    pub block_number: BlockNumber,
+}
+
+/// Index of all fee AMM pools, keyed by pool ID.
+///
+/// Pools are only ever created by minting liquidity, which always emits a `Mint` event, so the
+/// index is built from the `Mint` logs of the `TipFeeManager`. Reserves are read from state when
+/// serving requests.
+#[derive(Debug, Clone, Default)]
+pub struct PoolIndex {
+    inner: Arc<RwLock<PoolIndexInner>>,
+    status: IndexStatus,
+}
+
+impl PoolIndex {
+    /// Returns all indexed pools ordered by pool ID.
+    pub fn pools(&self) -> Vec<(B256, IndexedPool)> {
+        self.inner
+            .read()
+            .pools
+            .iter()
+            .map(|(pool_id, pool)| (*pool_id, *pool))
+            .collect()
+    }
+
+    /// Returns the pool with the given ID, if it has been indexed.
+    pub fn pool(&self, pool_id: B256) -> Option<IndexedPool> {
+        self.inner.read().pools.get(&pool_id).copied()
+    }
+
+    /// Applies a single log to the index.
+    fn apply_log(inner: &mut PoolIndexInner, log: IndexedLog<'_>) {
+        if log.log.address != TIP_FEE_MANAGER_ADDRESS {
+            return;
+        }
+
+        if let Ok(event) = TIPFeeAMMEvent::decode_log(log.log)
+            && let TIPFeeAMMEvent::Mint(mint) = event.data
+        {
+            let pool_id = PoolKey::new(mint.userToken, mint.validatorToken).get_id();
+            inner.pools.entry(pool_id).or_insert(IndexedPool {
+                user_token: mint.userToken,
+                validator_token: mint.validatorToken,
+                block_number: log.block_number,
+            });
+        }
+    }
+}
+
+impl CanonicalIndex for PoolIndex {
+    const NAME: &'static str = "pools";
+
+    fn apply_block(&self, block: &RecoveredBlock<Block>, receipts: &[TempoReceipt]) {
+        let mut inner = self.inner.write();
+        for log in indexed_logs(block, receipts) {
+            Self::apply_log(&mut inner, log);
+        }
+        inner.last_indexed_block = Some(block.header().number());
+    }
+
+    fn revert_from(&self, block_number: BlockNumber) {
+        let mut inner = self.inner.write();
+        inner
+            .pools
+            .retain(|_, pool| pool.block_number < block_number);
+        inner.last_indexed_block = block_number.checked_sub(1);
+    }
+
+    fn last_indexed_block(&self) -> Option<BlockNumber> {
+        self.inner.read().last_indexed_block
+    }
+
+    fn status(&self) -> &IndexStatus {
+        &self.status
+    }
+
+    fn save<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.inner.read().serialize(serializer)
+    }
+
+    fn load<'de, D: Deserializer<'de>>(&self, deserializer: D) -> Result<(), D::Error> {
+        *self.inner.write() = PoolIndexInner::deserialize(deserializer)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct PoolIndexInner {
+    /// Last block that was applied to the index.
+    last_indexed_block: Option<BlockNumber>,
+
+    /// pool ID -> pool, with the block the pool was created in.
+    pools: BTreeMap<B256, IndexedPool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{IntoLogData, Log, TxHash, U256};
+    use tempo_precompiles::{tip_fee_manager::ITIPFeeAMM, tip20::token_id_to_address};
+
+    fn apply(
+        index: &PoolIndex,
+        block_number: BlockNumber,
+        address: Address,
+        mint: ITIPFeeAMM::Mint,
+    ) {
+        let sender = mint.sender;
+        let log = Log {
+            address,
+            data: mint.into_log_data(),
+        };
+        let mut inner = index.inner.write();
+        PoolIndex::apply_log(
+            &mut inner,
+            IndexedLog {
+                log: &log,
+                sender,
+                transaction_hash: TxHash::with_last_byte(block_number as u8),
+                block_number,
+                timestamp: block_number * 1000,
+                log_index: 0,
+            },
+        );
+        inner.last_indexed_block = Some(block_number);
+    }
+
+    fn mint(user_token: Address, validator_token: Address) -> ITIPFeeAMM::Mint {
+        ITIPFeeAMM::Mint {
+            sender: Address::with_last_byte(0xff),
+            userToken: user_token,
+            validatorToken: validator_token,
+            amountUserToken: U256::ZERO,
+            amountValidatorToken: U256::from(1000),
+            liquidity: U256::from(500),
+        }
+    }
+
+    #[test]
+    fn test_pool_index_tracks_and_reverts() {
+        let index = PoolIndex::default();
+        let (a, b) = (token_id_to_address(1), token_id_to_address(2));
+        let ab = PoolKey::new(a, b).get_id();
+        let ba = PoolKey::new(b, a).get_id();
+
+        apply(&index, 1, TIP_FEE_MANAGER_ADDRESS, mint(a, b));
+        apply(&index, 2, TIP_FEE_MANAGER_ADDRESS, mint(a, b));
+        apply(&index, 3, TIP_FEE_MANAGER_ADDRESS, mint(b, a));
+        // Logs of other contracts are ignored
+        apply(&index, 3, Address::with_last_byte(1), mint(b, b));
+
+        assert_eq!(index.pools().len(), 2);
+        assert_eq!(
+            index.pool(ab),
+            Some(IndexedPool {
+                user_token: a,
+                validator_token: b,
+                block_number: 1,
+            })
+        );
+        assert_eq!(index.pool(ba).map(|pool| pool.block_number), Some(3));
+
+        index.revert_from(2);
+        assert_eq!(index.last_indexed_block(), Some(1));
+        assert!(index.pool(ba).is_none());
+        assert!(index.pool(ab).is_some());
+    }
+}
@@ -1,17 +1,43 @@
-use crate::rpc::amm::pools::PoolsResponse;
+use crate::rpc::{
+    amm::pools::PoolsResponse,
+    index::CanonicalIndex,
+    pagination::{Comparator, page_limit, paginate, sort_items},
+    state::with_storage_at_block,
+    token::TokenIndex,
+};
+use alloy_eips::BlockNumberOrTag;
+use alloy_primitives::{Address, B256, U256};
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
-use reth_node_core::rpc::result::internal_rpc_err;
-use reth_rpc_eth_api::RpcNodeCore;
+use reth_node_api::NodePrimitives;
+use reth_rpc_eth_api::{RpcNodeCore, helpers::SpawnBlocking};
+use reth_rpc_eth_types::{EthApiError, error::FromEthApiError};
+use std::collections::BTreeMap;
 use tempo_alloy::rpc::pagination::PaginationParams;
+use tempo_evm::TempoEvmConfig;
+use tempo_precompiles::{
+    tip_fee_manager::{
+        ITIPFeeAMM, TipFeeManager,
+        amm::{PoolKey, compute_amount_out},
+    },
+    tip20::token_id_to_address,
+    tip20_factory::TIP20Factory,
+};
+use tempo_primitives::TempoHeader;
 
 pub mod pools;
 pub use pools::{Pool, PoolsFilters};
 
+mod error;
+pub use error::AmmApiError;
+
+mod index;
+pub use index::{IndexedPool, PoolIndex};
+
 #[rpc(server, namespace = "amm")]
 pub trait TempoAmmApi {
     /// Gets paginated liquidity pools from the Fee AMM on Tempo.
     ///
-    /// Each pool is directional (userToken → validatorToken) with fixed swap rates for fee swaps (0.997) and rebalance swaps (0.9985).
+    /// Each pool is directional (userToken → validatorToken) with swap rates for fee swaps (0.997 by default) and rebalance swaps (0.9985 by default).
     ///
     /// Uses cursor-based pagination for stable iteration through pools.
     #[method(name = "getLiquidityPools")]
@@ -22,18 +48,163 @@ pub trait TempoAmmApi {
 #[derive(Debug, Clone, Default)]
 pub struct TempoAmm<EthApi> {
     eth_api: EthApi,
+    index: PoolIndex,
+    tokens: TokenIndex,
 }
 
 impl<EthApi> TempoAmm<EthApi> {
-    pub fn new(eth_api: EthApi) -> Self {
-        Self { eth_api }
+    /// Creates a new instance of the [`TempoAmm`] serving pools from the given indexes.
+    ///
+    /// The token index is used to find the tokens that were allocated at genesis, whose pools
+    /// were created without an indexed `Mint` event.
+    pub fn new(eth_api: EthApi, index: PoolIndex, tokens: TokenIndex) -> Self {
+        Self {
+            eth_api,
+            index,
+            tokens,
+        }
+    }
+}
+
+impl<
+    EthApi: RpcNodeCore<Evm = TempoEvmConfig, Primitives: NodePrimitives<BlockHeader = TempoHeader>>,
+> TempoAmm<EthApi>
+{
+    /// Implementation of the `amm_getLiquidityPools` endpoint
+    fn get_pools(
+        &self,
+        params: PaginationParams<PoolsFilters>,
+    ) -> Result<PoolsResponse, AmmApiError> {
+        self.index.ensure_synced()?;
+        self.tokens.ensure_synced()?;
+
+        let cursor = params
+            .cursor
+            .as_deref()
+            .map(parse_pool_cursor)
+            .transpose()?;
+
+        let mut pools = with_storage_at_block(
+            &self.eth_api,
+            BlockNumberOrTag::Latest.into(),
+            || -> Result<_, AmmApiError> {
+                let fee_manager = TipFeeManager::new();
+
+                // Indexed pools are known to exist, pools between genesis tokens are only
+                // candidates and are skipped if they were never minted.
+                let mut candidates = self
+                    .index
+                    .pools()
+                    .into_iter()
+                    .map(|(pool_id, pool)| (pool_id, (pool.user_token, pool.validator_token, true)))
+                    .collect::<BTreeMap<_, _>>();
+                let genesis_tokens = self.genesis_tokens()?;
+                for user_token in &genesis_tokens {
+                    for validator_token in &genesis_tokens {
+                        if user_token == validator_token {
+                            continue;
+                        }
+                        let pool_id = PoolKey::new(*user_token, *validator_token).get_id();
+                        candidates
+                            .entry(pool_id)
+                            .or_insert((*user_token, *validator_token, false));
+                    }
+                }
+
+                let mut pools = Vec::new();
+                for (pool_id, (user_token, validator_token, indexed)) in candidates {
+                    if params.filters.as_ref().is_some_and(|filter| {
+                        !pool_tokens_match_filter(user_token, validator_token, filter)
+                    }) {
+                        continue;
+                    }
+
+                    let total_supply = fee_manager.get_total_supply(pool_id)?;
+                    if !indexed && total_supply.is_zero() {
+                        continue;
+                    }
+
+                    let reserves = fee_manager.get_pool(ITIPFeeAMM::getPoolCall {
+                        userToken: user_token,
+                        validatorToken: validator_token,
+                    })?;
+                    let pending_fee_swap_in =
+                        U256::from(fee_manager.get_pending_fee_swap_in(pool_id)?);
+                    let reserve_validator_token = U256::from(reserves.reserve_validator_token);
+                    let fees = fee_manager.get_pool_fees(pool_id)?;
+
+                    let pool = Pool {
+                        effective_reserve_validator_token: reserve_validator_token
+                            .saturating_sub(compute_amount_out(pending_fee_swap_in, fees.m())?),
+                        fee_swap_rate: fees.m(),
+                        pending_fee_swap_in,
+                        pool_id,
+                        rebalance_rate: fees.n(),
+                        reserve_user_token: U256::from(reserves.reserve_user_token),
+                        reserve_validator_token,
+                        total_supply,
+                        user_token,
+                        validator_token,
+                    };
+
+                    if params
+                        .filters
+                        .as_ref()
+                        .is_none_or(|filter| pool_matches_filter(&pool, filter))
+                    {
+                        pools.push(pool);
+                    }
+                }
+                Ok(pools)
+            },
+        )?;
+
+        sort_items(&mut pools, params.sort.as_ref(), pool_comparator)
+            .map_err(AmmApiError::InvalidSortField)?;
+
+        let (pools, next_cursor) = paginate(pools, cursor, page_limit(&params), |p| p.pool_id)
+            .ok_or_else(|| AmmApiError::PoolCursorNotFound(cursor.unwrap_or_default()))?;
+
+        Ok(PoolsResponse {
+            next_cursor: next_cursor.map(|pool_id| pool_id.to_string()),
+            pools,
+        })
+    }
+
+    /// Returns the tokens that were not created through an indexed `TokenCreated` event.
+    ///
+    /// Must be called within a storage context.
+    fn genesis_tokens(&self) -> Result<Vec<Address>, AmmApiError> {
+        let token_count: u64 = TIP20Factory::new().token_id_counter()?.saturating_to();
+        Ok((0..token_count)
+            .map(token_id_to_address)
+            .filter(|token| self.tokens.token(*token).is_none())
+            .collect())
     }
 }
 
 #[async_trait::async_trait]
-impl<EthApi: RpcNodeCore> TempoAmmApiServer for TempoAmm<EthApi> {
-    async fn pools(&self, _params: PaginationParams<PoolsFilters>) -> RpcResult<PoolsResponse> {
-        Err(internal_rpc_err("unimplemented"))
+impl<
+    EthApi: RpcNodeCore<Evm = TempoEvmConfig, Primitives: NodePrimitives<BlockHeader = TempoHeader>>
+        + SpawnBlocking,
+> TempoAmmApiServer for TempoAmm<EthApi>
+{
+    /// Returns fee AMM pools based on pagination parameters.
+    ///
+    /// ## Cursor
+    /// The cursor for this method is the **Pool ID** (B256 encoded as hex string).
+    /// - When provided in the request, returns pools starting at the given pool
+    /// - Returns `next_cursor` in the response containing the first pool of the next page
+    async fn pools(&self, params: PaginationParams<PoolsFilters>) -> RpcResult<PoolsResponse> {
+        let this = self.clone();
+        self.eth_api
+            .spawn_blocking_io(move |_| {
+                Self::get_pools(&this, params)
+                    .map_err(EthApiError::from)
+                    .map_err(EthApi::Error::from_eth_err)
+            })
+            .await
+            .map_err(Into::into)
     }
 }
 
@@ -43,3 +214,88 @@ impl<EthApi: RpcNodeCore> TempoAmm<EthApi> {
         self.eth_api.provider()
     }
 }
+
+/// Checks if the tokens of a pool match the given filters
+fn pool_tokens_match_filter(
+    user_token: Address,
+    validator_token: Address,
+    filter: &PoolsFilters,
+) -> bool {
+    filter.user_token.is_none_or(|token| token == user_token)
+        && filter
+            .validator_token
+            .is_none_or(|token| token == validator_token)
+}
+
+/// Checks if a pool matches the given filters
+fn pool_matches_filter(pool: &Pool, filter: &PoolsFilters) -> bool {
+    if !pool_tokens_match_filter(pool.user_token, pool.validator_token, filter) {
+        return false;
+    }
+
+    if filter
+        .effective_validator_reserve
+        .as_ref()
+        .is_some_and(|range| !range.in_range(pool.effective_reserve_validator_token))
+    {
+        return false;
+    }
+
+    if filter
+        .pending_fee_swap_in
+        .as_ref()
+        .is_some_and(|range| !range.in_range(pool.pending_fee_swap_in))
+    {
+        return false;
+    }
+
+    if filter
+        .reserve_user_token
+        .as_ref()
+        .is_some_and(|range| !range.in_range(pool.reserve_user_token))
+    {
+        return false;
+    }
+
+    if filter
+        .reserve_validator_token
+        .as_ref()
+        .is_some_and(|range| !range.in_range(pool.reserve_validator_token))
+    {
+        return false;
+    }
+
+    if filter
+        .total_supply
+        .as_ref()
+        .is_some_and(|range| !range.in_range(pool.total_supply))
+    {
+        return false;
+    }
+
+    true
+}
+
+/// Returns the comparator for sorting pools by the given field.
+fn pool_comparator(field: &str) -> Option<Comparator<Pool>> {
+    let compare: fn(&Pool, &Pool) -> std::cmp::Ordering = match field {
+        "poolId" => |a, b| a.pool_id.cmp(&b.pool_id),
+        "effectiveReserveValidatorToken" => |a, b| {
+            a.effective_reserve_validator_token
+                .cmp(&b.effective_reserve_validator_token)
+        },
+        "pendingFeeSwapIn" => |a, b| a.pending_fee_swap_in.cmp(&b.pending_fee_swap_in),
+        "reserveUserToken" => |a, b| a.reserve_user_token.cmp(&b.reserve_user_token),
+        "reserveValidatorToken" => |a, b| a.reserve_validator_token.cmp(&b.reserve_validator_token),
+        "totalSupply" => |a, b| a.total_supply.cmp(&b.total_supply),
+        _ => return None,
+    };
+    Some(Box::new(compare))
+}
+
+/// Parses a cursor string into a pool ID
+fn parse_pool_cursor(cursor: &str) -> Result<B256, AmmApiError> {
+    cursor
+        .parse::<B256>()
+        .map_err(|_| AmmApiError::InvalidPoolCursor(cursor.to_string()))
+}
@@ -35,8 +35,14 @@ pub struct PoolsFilters {
 pub struct Pool {
     /// Effective reserve of validator token after pending swaps
     pub effective_reserve_validator_token: U256,
+    /// Rate applied to fee swaps, scaled by 10000
+    pub fee_swap_rate: U256,
+    /// Amount of user token reserved by fee swaps that are pending until the end of the block
+    pub pending_fee_swap_in: U256,
     /// Pool ID (keccak256 of userToken and validatorToken)
     pub pool_id: B256,
+    /// Rate applied to rebalance swaps, scaled by 10000
+    pub rebalance_rate: U256,
     /// User token reserve
     pub reserve_user_token: U256,
     /// Validator token reserve
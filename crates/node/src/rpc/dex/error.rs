@@ -1,4 +1,4 @@
-use alloy_eips::BlockId;
+use crate::rpc::state::StateAccessError;
 use alloy_primitives::B256;
 use jsonrpsee::types::ErrorObject;
 use reth_rpc_eth_types::{EthApiError, error::ToRpcError};
@@ -11,19 +11,9 @@ pub enum DexApiError {
     #[error(transparent)]
     Precompile(#[from] TempoPrecompileError),
 
-    /// Header not found for block
-    #[error("header not found for block {0:?}")]
-    HeaderNotFound(BlockId),
-
-    /// Provider error when getting header
-    /// Boxed because Provider::Error is an associated type
-    #[error("internal node error: failed to get header: {0}")]
-    Provider(#[source] Box<dyn std::error::Error + Send + Sync>),
-
-    /// Failed to create EVM context
-    /// Boxed because ConfigureEvm::Error is an associated type
-    #[error("internal node error: failed to create EVM")]
-    CreateEvm(#[source] Box<dyn std::error::Error + Send + Sync>),
+    /// Failed to access state
+    #[error(transparent)]
+    State(#[from] StateAccessError),
 
     /// Invalid hex string in order cursor
     #[error("invalid order cursor: expected hex string, got {0}")]
@@ -57,7 +47,9 @@ impl DexApiError {
 impl From<DexApiError> for EthApiError {
     fn from(err: DexApiError) -> Self {
         match err {
-            DexApiError::HeaderNotFound(block_id) => Self::HeaderNotFound(block_id),
+            DexApiError::State(StateAccessError::HeaderNotFound(block_id)) => {
+                Self::HeaderNotFound(block_id)
+            }
             // All other errors use the Other variant with our error type
             other => Self::other(other),
         }
@@ -1,11 +1,11 @@
-use crate::rpc::{TempoDexApiServer, dex::orders::OrdersResponse};
+use crate::rpc::{
+    TempoDexApiServer, dex::orders::OrdersResponse, pagination::page_limit,
+    state::with_storage_at_block,
+};
 use alloy_eips::{BlockId, BlockNumberOrTag};
-use alloy_primitives::{Address, B256, Sealable};
+use alloy_primitives::{Address, B256};
 use jsonrpsee::core::RpcResult;
-use reth_ethereum::evm::revm::database::StateProviderDatabase;
-use reth_evm::{EvmInternals, revm::database::CacheDB};
-use reth_node_api::{ConfigureEvm, NodePrimitives};
-use reth_provider::{BlockReaderIdExt, StateProviderFactory};
+use reth_node_api::NodePrimitives;
 use reth_rpc_eth_api::{RpcNodeCore, helpers::SpawnBlocking};
 use reth_rpc_eth_types::{EthApiError, error::FromEthApiError};
 use tempo_alloy::rpc::pagination::PaginationParams;
@@ -15,7 +15,7 @@ use tempo_precompiles::{
         Order as PrecompileOrder, Orderbook as PrecompileOrderbook, StablecoinExchange, TickLevel,
         orderbook::{OrderbookHandler, compute_book_key},
     },
-    storage::{ContractStorage, Handler, StorageCtx, evm::EvmPrecompileStorageProvider},
+    storage::{ContractStorage, Handler, StorageCtx},
 };
 use tempo_primitives::TempoHeader;
 
@@ -30,12 +30,6 @@ pub use books::{Orderbook, OrderbooksFilter, OrderbooksResponse};
 mod error;
 pub use error::DexApiError;
 
-/// Default limit for pagination
-const DEFAULT_LIMIT: usize = 10;
-
-/// Maximum limit for pagination
-const MAX_LIMIT: usize = 100;
-
 /// The JSON-RPC handlers for the `dex_` namespace.
 #[derive(Debug, Clone, Default)]
 pub struct TempoDex<EthApi> {
@@ -58,96 +52,97 @@ impl<
         &self,
         params: PaginationParams<OrdersFilters>,
     ) -> Result<OrdersResponse, DexApiError> {
-        let response = self.with_storage_at_block(BlockNumberOrTag::Latest.into(), || {
-            let exchange = StablecoinExchange::new();
-            let exchange_address = exchange.address();
-
-            // Determine which books to iterate based on filter
-            let base_token = params.filters.as_ref().and_then(|f| f.base_token);
-            let quote_token = params.filters.as_ref().and_then(|f| f.quote_token);
-            let book_keys = get_book_keys_for_iteration(&exchange, base_token, quote_token)?;
-
-            let is_bid = params
-                .filters
-                .as_ref()
-                .is_none_or(|f| f.is_bid.unwrap_or(false));
-
-            let mut cursor = params
-                .cursor
-                .map(|cursor| parse_order_cursor(&cursor))
-                .transpose()?;
-
-            let limit = params
-                .limit
-                .map(|l| l.min(MAX_LIMIT))
-                .unwrap_or(DEFAULT_LIMIT);
-
-            let mut all_orders: Vec<Order> = Vec::new();
-            let mut next_cursor = None;
-
-            // Iterate through books collecting orders until we reach the limit
-            for book_key in book_keys {
-                let orderbook = exchange.books(book_key)?;
-
-                // Check if this book matches the base/quote filter
-                if !orderbook.matches_tokens(base_token, quote_token) {
-                    continue;
-                }
+        let response = with_storage_at_block(
+            &self.eth_api,
+            BlockNumberOrTag::Latest.into(),
+            || -> Result<_, DexApiError> {
+                let exchange = StablecoinExchange::new();
+                let exchange_address = exchange.address();
+
+                // Determine which books to iterate based on filter
+                let base_token = params.filters.as_ref().and_then(|f| f.base_token);
+                let quote_token = params.filters.as_ref().and_then(|f| f.quote_token);
+                let book_keys = get_book_keys_for_iteration(&exchange, base_token, quote_token)?;
+
+                let is_bid = params
+                    .filters
+                    .as_ref()
+                    .is_none_or(|f| f.is_bid.unwrap_or(false));
+
+                let limit = page_limit(&params);
+
+                let mut cursor = params
+                    .cursor
+                    .map(|cursor| parse_order_cursor(&cursor))
+                    .transpose()?;
+
+                let mut all_orders: Vec<Order> = Vec::new();
+                let mut next_cursor = None;
+
+                // Iterate through books collecting orders until we reach the limit
+                for book_key in book_keys {
+                    let orderbook = exchange.books(book_key)?;
+
+                    // Check if this book matches the base/quote filter
+                    if !orderbook.matches_tokens(base_token, quote_token) {
+                        continue;
+                    }
 
-                // If the cursor exists and the starting order is not in the book, skip this book
-                if let Some(cursor) = cursor
-                    && exchange.get_order(cursor).is_err()
-                {
-                    continue;
-                }
+                    // If the cursor exists and the starting order is not in the book, skip this book
+                    if let Some(cursor) = cursor
+                        && exchange.get_order(cursor).is_err()
+                    {
+                        continue;
+                    }
 
-                let starting_order = if all_orders.is_empty() {
-                    // If the cursor is in this book then use it
-                    cursor.take()
-                } else {
-                    None
-                };
+                    let starting_order = if all_orders.is_empty() {
+                        // If the cursor is in this book then use it
+                        cursor.take()
+                    } else {
+                        None
+                    };
+
+                    let book_iterator = BookIterator::new(
+                        &orderbook,
+                        exchange_address,
+                        is_bid,
+                        starting_order,
+                        params.filters.clone(),
+                    );
+
+                    // Collect orders from this book, up to limit + 1
+                    for order_result in book_iterator {
+                        let order = order_result?;
+                        let rpc_order = self.to_rpc_order(order, &orderbook);
+                        all_orders.push(rpc_order);
+
+                        // stop once we have limit + 1 orders, we can't always use the next order
+                        // ID as the next cursor because of queue and book boundaries
+                        if all_orders.len() > limit {
+                            // Use the last order for cursor
+                            let last = &all_orders[limit];
+                            next_cursor = Some(format!("0x{:x}", last.order_id));
+                            break;
+                        }
+                    }
 
-                let book_iterator = BookIterator::new(
-                    &orderbook,
-                    exchange_address,
-                    is_bid,
-                    starting_order,
-                    params.filters.clone(),
-                );
-
-                // Collect orders from this book, up to limit + 1
-                for order_result in book_iterator {
-                    let order = order_result?;
-                    let rpc_order = self.to_rpc_order(order, &orderbook);
-                    all_orders.push(rpc_order);
-
-                    // stop once we have limit + 1 orders, we can't always use the next order
-                    // ID as the next cursor because of queue and book boundaries
+                    // If we have enough orders, stop iterating through books
                     if all_orders.len() > limit {
-                        // Use the last order for cursor
-                        let last = &all_orders[limit];
-                        next_cursor = Some(format!("0x{:x}", last.order_id));
                         break;
                     }
                 }
 
-                // If we have enough orders, stop iterating through books
-                if all_orders.len() > limit {
-                    break;
-                }
-            }
-
-            // Truncate to limit
-            all_orders.truncate(limit);
-            let orders = all_orders;
+                // Truncate to limit
+                all_orders.truncate(limit);
+                let orders = all_orders;
 
-            let response = OrdersResponse {
-                next_cursor,
-                orders,
-            };
-            Ok(response)
-        })?;
+                let response = OrdersResponse {
+                    next_cursor,
+                    orders,
+                };
+                Ok(response)
+            },
+        )?;
         Ok(response)
     }
 
@@ -172,46 +167,13 @@ impl<
         })
     }
 
-    /// Creates an `EvmPrecompileStorageProvider` at the given block.
-    /// This handles the boilerplate of creating the EVM context and state provider.
-    fn with_storage_at_block<F, R>(&self, at: BlockId, f: F) -> Result<R, DexApiError>
-    where
-        F: FnOnce() -> Result<R, DexApiError>,
-    {
-        // Get the header for the specified block
-        let provider = self.eth_api.provider();
-        let header = provider
-            .header_by_id(at)
-            .map_err(|e| DexApiError::Provider(Box::new(e)))?
-            .ok_or(DexApiError::HeaderNotFound(at))?;
-
-        let block_hash = header.hash_slow();
-        let state_provider = provider
-            .state_by_block_hash(block_hash)
-            .map_err(|e| DexApiError::Provider(Box::new(e)))?;
-
-        // Create EVM using state provider db
-        let db = CacheDB::new(StateProviderDatabase::new(state_provider));
-        let mut evm = self
-            .eth_api
-            .evm_config()
-            .evm_for_block(db, &header)
-            .map_err(|e| DexApiError::CreateEvm(Box::new(e)))?;
-
-        let ctx = evm.ctx_mut();
-        let internals = EvmInternals::new(&mut ctx.journaled_state, &ctx.block);
-        let mut storage = EvmPrecompileStorageProvider::new_max_gas(internals, &ctx.cfg);
-
-        StorageCtx::enter(&mut storage, f)
-    }
-
     /// Creates a `StablecoinExchange` instance at the given block.
-    /// This builds on `with_storage_at_block` to provide the exchange.
+    /// This builds on [`with_storage_at_block`] to provide the exchange.
     fn with_exchange_at_block<F, R>(&self, at: BlockId, f: F) -> Result<R, DexApiError>
     where
         F: FnOnce(&mut StablecoinExchange) -> Result<R, DexApiError>,
     {
-        self.with_storage_at_block(at, || {
+        with_storage_at_block(&self.eth_api, at, || {
             let mut exchange = StablecoinExchange::new();
             f(&mut exchange)
         })
@@ -242,10 +204,7 @@ impl<
 
             // Convert keys to orderbooks, starting from cursor position
             let mut orderbooks = Vec::new();
-            let limit = params
-                .limit
-                .map(|l| l.min(MAX_LIMIT))
-                .unwrap_or(DEFAULT_LIMIT);
+            let limit = page_limit(&params);
 
             let mut iter = keys.into_iter().skip(start_idx);
 
@@ -290,11 +249,13 @@ impl<
             next,
             is_flip,
             flip_tick,
+            expires_at,
         } = order;
 
         Order {
             amount,
             base_token: book.base,
+            expires_at,
             flip_tick,
             is_bid,
             is_flip,
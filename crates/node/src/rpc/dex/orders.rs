@@ -88,6 +88,9 @@ pub struct Order {
     pub base_token: Address,
     /// Address of the quote token
     pub quote_token: Address,
+    /// Timestamp at which the order expires (0 if the order never expires)
+    #[serde(with = "alloy_serde::quantity")]
+    pub expires_at: u64,
 }
 
 #[cfg(test)]
@@ -0,0 +1,63 @@
+use crate::rpc::index::IndexNotSynced;
+use alloy_primitives::TxHash;
+use jsonrpsee::types::ErrorObject;
+use reth_provider::ProviderError;
+use reth_rpc_eth_types::{EthApiError, error::ToRpcError};
+
+/// `eth_` extension API specific errors that extend [`EthApiError`].
+#[derive(Debug, thiserror::Error)]
+pub enum EthExtApiError {
+    /// Failed to read a transaction from the database
+    #[error(transparent)]
+    Provider(#[from] ProviderError),
+
+    /// Invalid transaction cursor format
+    #[error("invalid transaction cursor: failed to parse as transaction hash")]
+    InvalidTransactionCursor(String),
+
+    /// Transaction cursor not found in indexed transactions
+    #[error("transaction cursor {0} not found in indexed transactions")]
+    TransactionCursorNotFound(TxHash),
+
+    /// Unknown field to sort by
+    #[error("invalid sort field: {0}")]
+    InvalidSortField(String),
+
+    /// The index backing the endpoint has not caught up with the canonical chain
+    #[error(transparent)]
+    IndexNotSynced(#[from] IndexNotSynced),
+}
+
+impl EthExtApiError {
+    /// Returns the rpc error for this error
+    const fn error_code(&self) -> i32 {
+        match self {
+            Self::InvalidTransactionCursor(_)
+            | Self::TransactionCursorNotFound(_)
+            | Self::InvalidSortField(_) => jsonrpsee::types::error::INVALID_PARAMS_CODE,
+            _ => jsonrpsee::types::error::INTERNAL_ERROR_CODE,
+        }
+    }
+}
+
+impl From<EthExtApiError> for EthApiError {
+    fn from(err: EthExtApiError) -> Self {
+        match err {
+            EthExtApiError::Provider(err) => err.into(),
+            // All other errors use the Other variant with our error type
+            other => Self::other(other),
+        }
+    }
+}
+
+impl ToRpcError for EthExtApiError {
+    fn to_rpc_error(&self) -> ErrorObject<'static> {
+        ErrorObject::owned(self.error_code(), self.to_string(), None::<()>)
+    }
+}
+
+impl From<EthExtApiError> for ErrorObject<'static> {
+    fn from(value: EthExtApiError) -> Self {
+        value.to_rpc_error()
+    }
+}
@@ -0,0 +1,435 @@
+use crate::rpc::index::{CanonicalIndex, IndexStatus, map_entries};
+use alloy_primitives::{Address, BlockNumber, TxHash};
+use parking_lot::RwLock;
+use reth_primitives_traits::{AlloyBlockHeader, RecoveredBlock, transaction::TxHashRef};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, hash_map::Entry},
+    ops::Bound,
+    sync::Arc,
+};
+use tempo_primitives::{Block, TempoReceipt, TempoTxEnvelope, TempoTxType};
+
+/// Position of a transaction in the chain: block number and index within the block.
+pub type TxPosition = (BlockNumber, u64);
+
+/// A canonical transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexedTransaction {
+    /// Position of the transaction in the chain
+    pub position: TxPosition,
+    /// Transaction hash
+    pub hash: TxHash,
+    /// Recovered sender of the transaction
+    pub sender: Address,
+    /// Transaction type
+    pub tx_type: TempoTxType,
+}
+
+/// Filters that are applied when querying the [`TransactionIndex`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TransactionQuery {
+    /// Only include transactions sent or paid for by this address
+    pub from: Option<Address>,
+    /// Only include transactions calling this address
+    pub to: Option<Address>,
+    /// Only include transactions of this type
+    pub tx_type: Option<TempoTxType>,
+}
+
+/// Index of canonical transactions by the addresses they involve.
+///
+/// A transaction is indexed as `from` its sender and, if different, its fee payer. It is indexed
+/// as `to` every address it calls, which for Tempo transactions includes the target of every call
+/// in the batch.
+///
+/// The index is kept in memory and periodically snapshotted to the data directory. For every
+/// transaction it holds the transaction, its hash and one position per address it involves, and
+/// for every block the addresses indexed in it, so memory and snapshot size grow linearly with
+/// the number of indexed transactions and their calls.
+#[derive(Debug, Clone, Default)]
+pub struct TransactionIndex {
+    inner: Arc<RwLock<TransactionIndexInner>>,
+    status: IndexStatus,
+}
+
+impl TransactionIndex {
+    /// Returns the position of the transaction with the given hash, if it has been indexed.
+    pub fn position(&self, hash: TxHash) -> Option<TxPosition> {
+        self.inner.read().positions.get(&hash).copied()
+    }
+
+    /// Returns up to `limit` transactions matching `query`, starting at `start` (inclusive).
+    ///
+    /// Transactions are returned in chain order, or in reverse chain order if `descending` is
+    /// set. The first transaction of the next page is returned as well.
+    pub fn transactions(
+        &self,
+        query: TransactionQuery,
+        start: Option<TxPosition>,
+        descending: bool,
+        limit: usize,
+    ) -> (Vec<IndexedTransaction>, Option<IndexedTransaction>) {
+        let inner = self.inner.read();
+
+        let empty = BTreeSet::new();
+        let from = query
+            .from
+            .map(|address| inner.from.get(&address).unwrap_or(&empty));
+        let to = query
+            .to
+            .map(|address| inner.to.get(&address).unwrap_or(&empty));
+
+        // Walk the smallest set of candidates and check the others for membership
+        let candidates = match (from, to) {
+            (Some(from), Some(to)) => Some(if from.len() <= to.len() { from } else { to }),
+            (from, to) => from.or(to),
+        };
+        let bounds = match start.map_or(Bound::Unbounded, Bound::Included) {
+            start if descending => (Bound::Unbounded, start),
+            start => (start, Bound::Unbounded),
+        };
+        let range = match candidates {
+            Some(candidates) => ordered(candidates.range(bounds), descending),
+            None => ordered(
+                inner
+                    .transactions
+                    .range(bounds)
+                    .map(|(position, _)| position),
+                descending,
+            ),
+        };
+
+        let mut matches = range
+            .filter(|position| from.is_none_or(|from| from.contains(position)))
+            .filter(|position| to.is_none_or(|to| to.contains(position)))
+            .filter_map(|position| inner.transactions.get(position))
+            .filter(|tx| query.tx_type.is_none_or(|tx_type| tx_type == tx.tx_type))
+            .copied();
+
+        let page = matches.by_ref().take(limit).collect();
+        let next = matches.next();
+
+        (page, next)
+    }
+
+    /// Applies a single transaction to the index.
+    fn apply_transaction(
+        inner: &mut TransactionIndexInner,
+        position: TxPosition,
+        tx: &TempoTxEnvelope,
+        sender: Address,
+    ) {
+        let hash = *tx.tx_hash();
+
+        inner.transactions.insert(
+            position,
+            IndexedTransaction {
+                position,
+                hash,
+                sender,
+                tx_type: tx.tx_type(),
+            },
+        );
+        inner.positions.insert(hash, position);
+
+        let mut from = vec![sender];
+        if let Ok(fee_payer) = tx.fee_payer(sender) {
+            from.push(fee_payer);
+        }
+        let to = tx
+            .calls()
+            .filter_map(|(kind, _)| kind.to().copied())
+            .collect::<Vec<_>>();
+
+        for address in &from {
+            inner.from.entry(*address).or_default().insert(position);
+        }
+        for address in &to {
+            inner.to.entry(*address).or_default().insert(position);
+        }
+
+        let block = inner.blocks.entry(position.0).or_default();
+        block.from.extend(from);
+        block.to.extend(to);
+    }
+}
+
+impl CanonicalIndex for TransactionIndex {
+    const NAME: &'static str = "transactions";
+
+    fn apply_block(&self, block: &RecoveredBlock<Block>, _receipts: &[TempoReceipt]) {
+        let block_number = block.header().number();
+
+        let mut inner = self.inner.write();
+        for (tx_index, (sender, tx)) in block.transactions_with_sender().enumerate() {
+            Self::apply_transaction(&mut inner, (block_number, tx_index as u64), tx, *sender);
+        }
+        inner.last_indexed_block = Some(block_number);
+    }
+
+    fn revert_from(&self, block_number: BlockNumber) {
+        let mut inner = self.inner.write();
+        let first_reverted = (block_number, 0);
+
+        let reverted = inner.transactions.split_off(&first_reverted);
+        for tx in reverted.values() {
+            inner.positions.remove(&tx.hash);
+        }
+
+        // Only the addresses indexed in the reverted blocks have positions to remove
+        for block in inner.blocks.split_off(&block_number).into_values() {
+            for address in block.from {
+                revert_positions(&mut inner.from, address, first_reverted);
+            }
+            for address in block.to {
+                revert_positions(&mut inner.to, address, first_reverted);
+            }
+        }
+
+        inner.last_indexed_block = block_number.checked_sub(1);
+    }
+
+    fn last_indexed_block(&self) -> Option<BlockNumber> {
+        self.inner.read().last_indexed_block
+    }
+
+    fn status(&self) -> &IndexStatus {
+        &self.status
+    }
+
+    fn save<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.inner.read().serialize(serializer)
+    }
+
+    fn load<'de, D: Deserializer<'de>>(&self, deserializer: D) -> Result<(), D::Error> {
+        *self.inner.write() = TransactionIndexInner::deserialize(deserializer)?;
+        Ok(())
+    }
+}
+
+/// Removes the positions of `address` starting at `first_reverted`, and the address itself if it
+/// has no positions left.
+fn revert_positions(
+    index: &mut HashMap<Address, BTreeSet<TxPosition>>,
+    address: Address,
+    first_reverted: TxPosition,
+) {
+    if let Entry::Occupied(mut positions) = index.entry(address) {
+        positions.get_mut().split_off(&first_reverted);
+        if positions.get().is_empty() {
+            positions.remove();
+        }
+    }
+}
+
+/// Returns the positions in chain order, or in reverse chain order if `descending` is set.
+fn ordered<'a>(
+    positions: impl DoubleEndedIterator<Item = &'a TxPosition> + 'a,
+    descending: bool,
+) -> Box<dyn Iterator<Item = &'a TxPosition> + 'a> {
+    if descending {
+        Box::new(positions.rev())
+    } else {
+        Box::new(positions)
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct TransactionIndexInner {
+    /// Last block that was applied to the index.
+    last_indexed_block: Option<BlockNumber>,
+
+    /// position -> transaction.
+    #[serde(with = "map_entries")]
+    transactions: BTreeMap<TxPosition, IndexedTransaction>,
+
+    /// hash -> position, used to resolve cursors.
+    positions: HashMap<TxHash, TxPosition>,
+
+    /// sender or fee payer -> positions of its transactions.
+    from: HashMap<Address, BTreeSet<TxPosition>>,
+
+    /// called address -> positions of the transactions calling it.
+    to: HashMap<Address, BTreeSet<TxPosition>>,
+
+    /// block number -> addresses indexed in the block, used to revert blocks.
+    blocks: BTreeMap<BlockNumber, BlockAddresses>,
+}
+
+/// Addresses indexed in a single block.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BlockAddresses {
+    /// Senders and fee payers.
+    from: HashSet<Address>,
+    /// Called addresses.
+    to: HashSet<Address>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::consensus::{Signed, TxEip1559, TxLegacy};
+    use alloy_primitives::{Bytes, Signature, TxKind, U256};
+    use tempo_primitives::{
+        TempoSignature, TempoTransaction,
+        transaction::{Call, PrimitiveSignature},
+    };
+
+    fn legacy(nonce: u64, to: Address) -> TempoTxEnvelope {
+        TempoTxEnvelope::Legacy(Signed::new_unhashed(
+            TxLegacy {
+                nonce,
+                to: TxKind::Call(to),
+                ..Default::default()
+            },
+            Signature::test_signature(),
+        ))
+    }
+
+    fn eip1559(nonce: u64, to: Address) -> TempoTxEnvelope {
+        TempoTxEnvelope::Eip1559(Signed::new_unhashed(
+            TxEip1559 {
+                nonce,
+                to: TxKind::Call(to),
+                ..Default::default()
+            },
+            Signature::test_signature(),
+        ))
+    }
+
+    fn tempo(nonce: u64, calls: &[Address]) -> TempoTxEnvelope {
+        let tx = TempoTransaction {
+            nonce,
+            calls: calls
+                .iter()
+                .map(|to| Call {
+                    to: TxKind::Call(*to),
+                    value: U256::ZERO,
+                    input: Bytes::new(),
+                })
+                .collect(),
+            ..Default::default()
+        };
+        TempoTxEnvelope::AA(tx.into_signed(TempoSignature::Primitive(
+            PrimitiveSignature::Secp256k1(Signature::test_signature()),
+        )))
+    }
+
+    fn apply(
+        index: &TransactionIndex,
+        block_number: BlockNumber,
+        txs: &[(Address, TempoTxEnvelope)],
+    ) {
+        let mut inner = index.inner.write();
+        for (tx_index, (sender, tx)) in txs.iter().enumerate() {
+            TransactionIndex::apply_transaction(
+                &mut inner,
+                (block_number, tx_index as u64),
+                tx,
+                *sender,
+            );
+        }
+        inner.last_indexed_block = Some(block_number);
+    }
+
+    fn positions(page: &[IndexedTransaction]) -> Vec<TxPosition> {
+        page.iter().map(|tx| tx.position).collect()
+    }
+
+    #[test]
+    fn test_transaction_index_queries_and_reverts() {
+        let index = TransactionIndex::default();
+        let (alice, bob) = (Address::with_last_byte(1), Address::with_last_byte(2));
+        let (token, dex) = (Address::with_last_byte(0x10), Address::with_last_byte(0x20));
+
+        apply(
+            &index,
+            1,
+            &[(alice, legacy(0, token)), (bob, eip1559(0, dex))],
+        );
+        apply(&index, 2, &[(alice, tempo(1, &[token, dex]))]);
+
+        let all = TransactionQuery::default();
+        let (page, next) = index.transactions(all, None, true, 2);
+        assert_eq!(positions(&page), vec![(2, 0), (1, 1)]);
+        assert_eq!(next.map(|tx| tx.position), Some((1, 0)));
+        let (page, next) = index.transactions(all, next.map(|tx| tx.position), true, 2);
+        assert_eq!(positions(&page), vec![(1, 0)]);
+        assert_eq!(next, None);
+
+        // Every call of a Tempo transaction is matched
+        let to_dex = TransactionQuery {
+            to: Some(dex),
+            ..Default::default()
+        };
+        let (page, _) = index.transactions(to_dex, None, false, 10);
+        assert_eq!(positions(&page), vec![(1, 1), (2, 0)]);
+
+        let from_alice_to_dex = TransactionQuery {
+            from: Some(alice),
+            to: Some(dex),
+            ..Default::default()
+        };
+        let (page, _) = index.transactions(from_alice_to_dex, None, false, 10);
+        assert_eq!(positions(&page), vec![(2, 0)]);
+        assert_eq!(page[0].tx_type, TempoTxType::AA);
+
+        let legacy_only = TransactionQuery {
+            tx_type: Some(TempoTxType::Legacy),
+            ..Default::default()
+        };
+        let (page, _) = index.transactions(legacy_only, None, false, 10);
+        assert_eq!(positions(&page), vec![(1, 0)]);
+
+        let hash = page[0].hash;
+        assert_eq!(index.position(hash), Some((1, 0)));
+
+        index.revert_from(2);
+        assert_eq!(index.last_indexed_block(), Some(1));
+        let (page, _) = index.transactions(from_alice_to_dex, None, false, 10);
+        assert!(page.is_empty());
+        let (page, _) = index.transactions(all, None, false, 10);
+        assert_eq!(positions(&page), vec![(1, 0), (1, 1)]);
+
+        index.revert_from(0);
+        assert_eq!(index.position(hash), None);
+        let inner = index.inner.read();
+        assert!(inner.from.is_empty() && inner.to.is_empty() && inner.blocks.is_empty());
+    }
+
+    #[test]
+    fn test_transaction_index_snapshot_round_trip() {
+        let index = TransactionIndex::default();
+        let (alice, bob) = (Address::with_last_byte(1), Address::with_last_byte(2));
+        let dex = Address::with_last_byte(0x20);
+        apply(
+            &index,
+            1,
+            &[(alice, legacy(0, dex)), (bob, tempo(0, &[dex]))],
+        );
+
+        let mut snapshot = Vec::new();
+        index
+            .save(&mut serde_json::Serializer::new(&mut snapshot))
+            .unwrap();
+        let restored = TransactionIndex::default();
+        restored
+            .load(&mut serde_json::Deserializer::from_slice(&snapshot))
+            .unwrap();
+
+        let to_dex = TransactionQuery {
+            to: Some(dex),
+            ..Default::default()
+        };
+        assert_eq!(restored.last_indexed_block(), Some(1));
+        assert_eq!(
+            restored.transactions(to_dex, None, false, 10),
+            index.transactions(to_dex, None, false, 10)
+        );
+
+        restored.revert_from(1);
+        assert_eq!(restored.last_indexed_block(), None);
+        assert!(restored.transactions(to_dex, None, false, 10).0.is_empty());
+    }
+}
@@ -1,12 +1,28 @@
-use crate::rpc::eth_ext::transactions::TransactionsResponse;
+use crate::rpc::{
+    eth_ext::transactions::{Transaction, TransactionsResponse},
+    index::CanonicalIndex,
+    pagination::page_limit,
+};
+use alloy_primitives::TxHash;
+use alloy_rpc_types_eth::TransactionInfo;
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
-use reth_node_core::rpc::result::internal_rpc_err;
-use reth_rpc_eth_api::RpcNodeCore;
-use tempo_alloy::rpc::pagination::PaginationParams;
+use reth_node_api::NodePrimitives;
+use reth_primitives_traits::Recovered;
+use reth_provider::TransactionsProvider;
+use reth_rpc_eth_api::{RpcNodeCore, helpers::SpawnBlocking};
+use reth_rpc_eth_types::{EthApiError, error::FromEthApiError};
+use tempo_alloy::rpc::pagination::{PaginationParams, SortOrder};
+use tempo_primitives::TempoTxEnvelope;
 
 pub mod transactions;
 pub use transactions::TransactionsFilter;
 
+mod error;
+pub use error::EthExtApiError;
+
+mod index;
+pub use index::{IndexedTransaction, TransactionIndex, TransactionQuery, TxPosition};
+
 #[rpc(server, namespace = "eth")]
 pub trait TempoEthExtApi {
     /// Gets paginated transactions on Tempo with flexible filtering and sorting.
@@ -23,21 +39,110 @@ pub trait TempoEthExtApi {
 #[derive(Debug, Clone, Default)]
 pub struct TempoEthExt<EthApi> {
     eth_api: EthApi,
+    index: TransactionIndex,
 }
 
 impl<EthApi> TempoEthExt<EthApi> {
-    pub fn new(eth_api: EthApi) -> Self {
-        Self { eth_api }
+    /// Creates a new instance of the [`TempoEthExt`] serving indexed data from the given index.
+    pub fn new(eth_api: EthApi, index: TransactionIndex) -> Self {
+        Self { eth_api, index }
+    }
+}
+
+impl<EthApi: RpcNodeCore<Primitives: NodePrimitives<SignedTx = TempoTxEnvelope>>>
+    TempoEthExt<EthApi>
+{
+    /// Implementation of the `eth_getTransactions` endpoint
+    fn get_transactions(
+        &self,
+        params: PaginationParams<TransactionsFilter>,
+    ) -> Result<TransactionsResponse, EthExtApiError> {
+        self.index.ensure_synced()?;
+
+        let start = params
+            .cursor
+            .as_deref()
+            .map(|cursor| {
+                let hash = parse_transaction_cursor(cursor)?;
+                self.index
+                    .position(hash)
+                    .ok_or(EthExtApiError::TransactionCursorNotFound(hash))
+            })
+            .transpose()?;
+
+        // Transactions can only be sorted by their position in the chain, newest first by default
+        let descending = match &params.sort {
+            Some(sort) if sort.on != "blockNumber" => {
+                return Err(EthExtApiError::InvalidSortField(sort.on.clone()));
+            }
+            Some(sort) => sort.order == SortOrder::Desc,
+            None => true,
+        };
+
+        let filter = params.filters.clone().unwrap_or_default();
+        let query = TransactionQuery {
+            from: filter.from,
+            to: filter.to,
+            tx_type: filter.type_,
+        };
+
+        let (indexed, next) =
+            self.index
+                .transactions(query, start, descending, page_limit(&params));
+
+        let mut transactions = Vec::with_capacity(indexed.len());
+        for indexed in indexed {
+            // The index may be ahead of the provider
+            let Some((tx, meta)) = self
+                .eth_api
+                .provider()
+                .transaction_by_hash_with_meta(indexed.hash)?
+            else {
+                continue;
+            };
+
+            transactions.push(Transaction::from_transaction(
+                Recovered::new_unchecked(tx, indexed.sender),
+                TransactionInfo {
+                    hash: Some(meta.tx_hash),
+                    index: Some(meta.index),
+                    block_hash: Some(meta.block_hash),
+                    block_number: Some(meta.block_number),
+                    base_fee: meta.base_fee,
+                },
+            ));
+        }
+
+        Ok(TransactionsResponse {
+            next_cursor: next.map(|tx| tx.hash.to_string()),
+            transactions,
+        })
     }
 }
 
 #[async_trait::async_trait]
-impl<EthApi: RpcNodeCore> TempoEthExtApiServer for TempoEthExt<EthApi> {
+impl<EthApi: RpcNodeCore<Primitives: NodePrimitives<SignedTx = TempoTxEnvelope>> + SpawnBlocking>
+    TempoEthExtApiServer for TempoEthExt<EthApi>
+{
+    /// Returns transactions based on pagination parameters.
+    ///
+    /// ## Cursor
+    /// The cursor for this method is the **Transaction Hash**.
+    /// - When provided in the request, returns transactions starting at the given transaction
+    /// - Returns `next_cursor` in the response containing the first transaction of the next page
     async fn transactions(
         &self,
-        _params: PaginationParams<TransactionsFilter>,
+        params: PaginationParams<TransactionsFilter>,
     ) -> RpcResult<TransactionsResponse> {
-        Err(internal_rpc_err("unimplemented"))
+        let this = self.clone();
+        self.eth_api
+            .spawn_blocking_io(move |_| {
+                Self::get_transactions(&this, params)
+                    .map_err(EthApiError::from)
+                    .map_err(EthApi::Error::from_eth_err)
+            })
+            .await
+            .map_err(Into::into)
     }
 }
 
@@ -47,3 +152,10 @@ impl<EthApi: RpcNodeCore> TempoEthExt<EthApi> {
         self.eth_api.provider()
     }
 }
+
+/// Parses a cursor string into a transaction hash
+fn parse_transaction_cursor(cursor: &str) -> Result<TxHash, EthExtApiError> {
+    cursor
+        .parse::<TxHash>()
+        .map_err(|_| EthExtApiError::InvalidTransactionCursor(cursor.to_string()))
+}
@@ -0,0 +1,60 @@
+use crate::rpc::state::StateAccessError;
+use alloy_primitives::Address;
+use jsonrpsee::types::ErrorObject;
+use reth_provider::ProviderError;
+use reth_rpc_eth_types::{EthApiError, error::ToRpcError};
+use tempo_precompiles::error::TempoPrecompileError;
+
+/// Fee API specific errors that extend [`EthApiError`].
+#[derive(Debug, thiserror::Error)]
+pub enum FeeApiError {
+    /// Precompile storage errors
+    #[error(transparent)]
+    Precompile(#[from] TempoPrecompileError),
+
+    /// Failed to access state
+    #[error(transparent)]
+    State(#[from] StateAccessError),
+
+    /// Provider error while checking AMM liquidity
+    #[error(transparent)]
+    Provider(#[from] ProviderError),
+
+    /// The resolved fee token cannot be used to pay fees
+    #[error("invalid fee token: {0}")]
+    InvalidFeeToken(Address),
+}
+
+impl FeeApiError {
+    /// Returns the rpc error for this error
+    const fn error_code(&self) -> i32 {
+        match self {
+            Self::InvalidFeeToken(_) => jsonrpsee::types::error::INVALID_PARAMS_CODE,
+            _ => jsonrpsee::types::error::INTERNAL_ERROR_CODE,
+        }
+    }
+}
+
+impl From<FeeApiError> for EthApiError {
+    fn from(err: FeeApiError) -> Self {
+        match err {
+            FeeApiError::State(StateAccessError::HeaderNotFound(block_id)) => {
+                Self::HeaderNotFound(block_id)
+            }
+            // All other errors use the Other variant with our error type
+            other => Self::other(other),
+        }
+    }
+}
+
+impl ToRpcError for FeeApiError {
+    fn to_rpc_error(&self) -> ErrorObject<'static> {
+        ErrorObject::owned(self.error_code(), self.to_string(), None::<()>)
+    }
+}
+
+impl From<FeeApiError> for ErrorObject<'static> {
+    fn from(value: FeeApiError) -> Self {
+        value.to_rpc_error()
+    }
+}
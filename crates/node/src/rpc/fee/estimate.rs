@@ -0,0 +1,22 @@
+use alloy_primitives::{Address, U256};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeEstimate {
+    /// Estimated gas used by the transaction
+    pub gas: U256,
+    /// Token the fee is charged in
+    pub fee_token: Address,
+    /// Maximum fee charged in the fee token for the estimated gas
+    pub fee: U256,
+    /// Token the validator of the next block is expected to receive fees in
+    pub validator_token: Address,
+    /// Fee received by the validator after the fee AMM conversion
+    pub validator_fee: U256,
+    /// Whether the transaction pool would accept the fee given the fee AMM liquidity
+    ///
+    /// Only set when quoting at the latest block, as the pool validates against the latest state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_enough_liquidity: Option<bool>,
+}
@@ -0,0 +1,193 @@
+use crate::rpc::state::state_at_block;
+use alloy::consensus::{BlockHeader, transaction::Either};
+use alloy_eips::BlockId;
+use alloy_primitives::{Address, Bytes, TxKind, U256};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use reth_node_api::NodePrimitives;
+use reth_provider::BlockNumReader;
+use reth_rpc_eth_api::{
+    EthApiTypes, RpcNodeCore,
+    helpers::{SpawnBlocking, estimate::EstimateCall},
+};
+use reth_rpc_eth_types::{EthApiError, error::FromEthApiError};
+use tempo_alloy::{TempoNetwork, rpc::TempoTransactionRequest};
+use tempo_chainspec::hardfork::TempoHardforks;
+use tempo_evm::{TempoEvmConfig, TempoStateAccess, TempoTx};
+use tempo_precompiles::tip_fee_manager::{
+    TipFeeManager,
+    amm::{PoolKey, compute_amount_out},
+};
+use tempo_primitives::{TempoHeader, transaction::calc_gas_balance_spending};
+use tempo_transaction_pool::amm::AmmLiquidityCache;
+
+pub mod estimate;
+pub use estimate::FeeEstimate;
+
+mod error;
+pub use error::FeeApiError;
+
+#[rpc(server, namespace = "tempo")]
+pub trait TempoFeeApi {
+    /// Estimates the fee of a transaction in the token it would be charged in.
+    ///
+    /// The fee token is resolved and the fee AMM liquidity is checked the same way the transaction
+    /// pool validates transactions, the latter only when quoting at the latest block. The validator
+    /// token is the fee token preference of the beneficiary of the given block, which is assumed to
+    /// propose the next one.
+    #[method(name = "estimateFee")]
+    async fn estimate_fee(
+        &self,
+        request: TempoTransactionRequest,
+        block: Option<BlockId>,
+    ) -> RpcResult<FeeEstimate>;
+}
+
+/// The JSON-RPC handlers for the `tempo_` namespace.
+#[derive(Debug, Clone)]
+pub struct TempoFee<EthApi> {
+    eth_api: EthApi,
+    amm_liquidity_cache: AmmLiquidityCache,
+}
+
+impl<EthApi> TempoFee<EthApi> {
+    /// Creates a new instance of the [`TempoFee`] checking liquidity against the given cache of
+    /// the transaction pool.
+    pub fn new(eth_api: EthApi, amm_liquidity_cache: AmmLiquidityCache) -> Self {
+        Self {
+            eth_api,
+            amm_liquidity_cache,
+        }
+    }
+}
+
+impl<
+    EthApi: RpcNodeCore<Evm = TempoEvmConfig, Primitives: NodePrimitives<BlockHeader = TempoHeader>>,
+> TempoFee<EthApi>
+{
+    /// Quotes the fee for `gas` units of gas at the state of the given block
+    fn quote_fee(
+        &self,
+        request: &TempoTransactionRequest,
+        gas: u64,
+        at: BlockId,
+    ) -> Result<FeeEstimate, FeeApiError> {
+        let (header, mut state_provider) = state_at_block(&self.eth_api, at)?;
+
+        let spec = self
+            .eth_api
+            .evm_config()
+            .chain_spec()
+            .tempo_hardfork_at(header.timestamp());
+
+        // Requests have no fee payer signature, so the sender always pays
+        let fee_payer = request.from.unwrap_or_default();
+        let fee_token =
+            state_provider.get_fee_token(RequestTx(request), Address::ZERO, fee_payer, spec)?;
+        if !state_provider.is_valid_fee_token(fee_token, spec)? {
+            return Err(FeeApiError::InvalidFeeToken(fee_token));
+        }
+
+        let gas_price = request
+            .max_fee_per_gas
+            .or(request.gas_price)
+            .unwrap_or_else(|| header.base_fee_per_gas().unwrap_or_default().into());
+        let fee = calc_gas_balance_spending(gas, gas_price);
+
+        let (validator_token, validator_fee) =
+            state_provider.with_read_only_storage_ctx(spec, || {
+                let fee_manager = TipFeeManager::new();
+                let validator_token = fee_manager.get_validator_token(header.beneficiary())?;
+                if validator_token == fee_token {
+                    return Ok::<_, FeeApiError>((validator_token, fee));
+                }
+
+                let pool_id = PoolKey::new(fee_token, validator_token).get_id();
+                let fees = fee_manager.get_pool_fees(pool_id)?;
+                Ok((validator_token, compute_amount_out(fee, fees.m())?))
+            })?;
+
+        // The liquidity cache of the transaction pool only tracks the latest state
+        let has_enough_liquidity = (header.number()
+            == self.eth_api.provider().best_block_number()?)
+        .then(|| {
+            self.amm_liquidity_cache
+                .has_enough_liquidity(fee_token, fee, &state_provider)
+        })
+        .transpose()?;
+
+        Ok(FeeEstimate {
+            gas: U256::from(gas),
+            fee_token,
+            fee,
+            validator_token,
+            validator_fee,
+            has_enough_liquidity,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl<
+    EthApi: RpcNodeCore<Evm = TempoEvmConfig, Primitives: NodePrimitives<BlockHeader = TempoHeader>>
+        + EthApiTypes<NetworkTypes = TempoNetwork>
+        + EstimateCall
+        + SpawnBlocking,
+> TempoFeeApiServer for TempoFee<EthApi>
+{
+    /// Estimates the gas of the request and quotes its fee at the given block, latest by default.
+    async fn estimate_fee(
+        &self,
+        request: TempoTransactionRequest,
+        block: Option<BlockId>,
+    ) -> RpcResult<FeeEstimate> {
+        let at = block.unwrap_or_default();
+        let gas = EstimateCall::estimate_gas_at(&self.eth_api, request.clone(), at, None)
+            .await
+            .map_err(Into::into)?;
+
+        let this = self.clone();
+        self.eth_api
+            .spawn_blocking_io(move |_| {
+                this.quote_fee(&request, gas.saturating_to(), at)
+                    .map_err(EthApiError::from)
+                    .map_err(EthApi::Error::from_eth_err)
+            })
+            .await
+            .map_err(Into::into)
+    }
+}
+
+/// Adapts a [`TempoTransactionRequest`] to [`TempoTx`] for fee token resolution.
+struct RequestTx<'a>(&'a TempoTransactionRequest);
+
+impl TempoTx for RequestTx<'_> {
+    fn fee_token(&self) -> Option<Address> {
+        self.0.fee_token
+    }
+
+    /// Mirrors how requests are converted into a transaction environment for execution.
+    fn is_aa(&self) -> bool {
+        !self.0.calls.is_empty()
+            || !self.0.tempo_authorization_list.is_empty()
+            || self.0.nonce_key.is_some()
+            || self.0.key_authorization.is_some()
+            || self.0.key_id.is_some()
+    }
+
+    fn calls(&self) -> impl Iterator<Item = (TxKind, &Bytes)> {
+        static EMPTY_INPUT: Bytes = Bytes::new();
+
+        if self.0.calls.is_empty() {
+            Either::Right(core::iter::once((
+                self.0.to.unwrap_or_default(),
+                self.0.input.input().unwrap_or(&EMPTY_INPUT),
+            )))
+        } else {
+            Either::Left(self.0.calls.iter().map(|call| (call.to, &call.input)))
+        }
+    }
+
+    fn caller(&self) -> Address {
+        self.0.from.unwrap_or_default()
+    }
+}
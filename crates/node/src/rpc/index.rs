@@ -0,0 +1,427 @@
+//! Node-side indexes that back the Tempo RPC namespaces.
+//!
+//! Some RPC endpoints need data that is not directly enumerable from state, e.g. the set of
+//! tokens an account has interacted with. These are served from in-memory indexes that are kept
+//! up to date with canonical state notifications, including reorgs. Indexes are periodically
+//! persisted as snapshots in the node's data directory, so that on startup only the blocks after
+//! the last snapshot need to be backfilled from stored blocks and receipts. Until an index has
+//! caught up with the canonical chain, the endpoints it backs return an [`IndexNotSynced`] error
+//! instead of serving incomplete results.
+
+use alloy_primitives::{Address, B256, BlockNumber, Log, TxHash};
+use eyre::OptionExt;
+use futures::StreamExt;
+use reth_primitives_traits::{AlloyBlockHeader, RecoveredBlock, transaction::TxHashRef};
+use reth_provider::{BlockReader, CanonStateSubscriptions, ProviderResult};
+use reth_tracing::tracing::{debug, error};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::{self, File},
+    io::{self, BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+use tempo_primitives::{Block, TempoPrimitives, TempoReceipt};
+
+/// Number of blocks that are loaded from the database at once when backfilling an index.
+const BACKFILL_BATCH_SIZE: u64 = 1_000;
+
+/// Minimum number of blocks that are applied to an index between two snapshots.
+const SNAPSHOT_INTERVAL: u64 = 10_000;
+
+/// Directory within the node's data directory that index snapshots are stored in.
+pub const INDEX_SNAPSHOT_DIR: &str = "rpc-index";
+
+/// An index that is derived from canonical blocks and their receipts.
+pub trait CanonicalIndex: Clone + Send + Sync + 'static {
+    /// Name of the index, used for logging.
+    const NAME: &'static str;
+
+    /// Applies a canonical block and its receipts to the index.
+    fn apply_block(&self, block: &RecoveredBlock<Block>, receipts: &[TempoReceipt]);
+
+    /// Removes everything that was indexed from `block_number` onwards.
+    fn revert_from(&self, block_number: BlockNumber);
+
+    /// Returns the number of the last indexed block, if any.
+    fn last_indexed_block(&self) -> Option<BlockNumber>;
+
+    /// Returns the sync status of the index.
+    fn status(&self) -> &IndexStatus;
+
+    /// Serializes the indexed data, so that it can be restored after a restart.
+    fn save<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+
+    /// Replaces the indexed data with data that was serialized by [`CanonicalIndex::save`].
+    fn load<'de, D: Deserializer<'de>>(&self, deserializer: D) -> Result<(), D::Error>;
+
+    /// Returns an error unless the index has caught up with the canonical chain.
+    fn ensure_synced(&self) -> Result<(), IndexNotSynced> {
+        if self.status().is_synced() {
+            Ok(())
+        } else {
+            Err(IndexNotSynced(Self::NAME))
+        }
+    }
+}
+
+/// Whether a [`CanonicalIndex`] has caught up with the canonical chain.
+///
+/// Clones share the same status. An index starts out as not synced and is marked as synced by
+/// [`maintain_index`] once all stored blocks have been applied.
+#[derive(Debug, Clone, Default)]
+pub struct IndexStatus {
+    synced: Arc<AtomicBool>,
+}
+
+impl IndexStatus {
+    /// Returns `true` if the index has caught up with the canonical chain.
+    pub fn is_synced(&self) -> bool {
+        self.synced.load(Ordering::Acquire)
+    }
+
+    /// Marks the index as synced or not synced.
+    pub fn set_synced(&self, synced: bool) {
+        self.synced.store(synced, Ordering::Release);
+    }
+}
+
+/// Error returned by endpoints whose index has not caught up with the canonical chain yet.
+#[derive(Debug, Clone, Copy, thiserror::Error)]
+#[error("{0} index is not synced yet")]
+pub struct IndexNotSynced(pub &'static str);
+
+/// A log emitted by a canonical transaction together with its inclusion context.
+#[derive(Debug, Clone, Copy)]
+pub struct IndexedLog<'a> {
+    /// The emitted log.
+    pub log: &'a Log,
+    /// Recovered sender of the transaction that emitted the log.
+    pub sender: Address,
+    /// Hash of the transaction that emitted the log.
+    pub transaction_hash: TxHash,
+    /// Number of the block the transaction was included in.
+    pub block_number: BlockNumber,
+    /// Timestamp (seconds) of the block the transaction was included in.
+    pub timestamp: u64,
+    /// Index of the log within the block.
+    pub log_index: u64,
+}
+
+/// Returns all logs of the given block in execution order.
+pub fn indexed_logs<'a>(
+    block: &'a RecoveredBlock<Block>,
+    receipts: &'a [TempoReceipt],
+) -> impl Iterator<Item = IndexedLog<'a>> + 'a {
+    let block_number = block.header().number();
+    let timestamp = block.header().timestamp();
+
+    block
+        .transactions_with_sender()
+        .zip(receipts)
+        .flat_map(|((sender, tx), receipt)| {
+            let transaction_hash = *tx.tx_hash();
+            receipt
+                .logs
+                .iter()
+                .map(move |log| (*sender, transaction_hash, log))
+        })
+        .enumerate()
+        .map(
+            move |(log_index, (sender, transaction_hash, log))| IndexedLog {
+                log,
+                sender,
+                transaction_hash,
+                block_number,
+                timestamp,
+                log_index: log_index as u64,
+            },
+        )
+}
+
+/// (De)serializes a map as a list of `(key, value)` entries.
+///
+/// Used for maps whose keys can't be represented as strings, e.g. tuples.
+pub mod map_entries {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes the map as a list of entries.
+    pub fn serialize<'a, M, S>(map: &'a M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        &'a M: IntoIterator,
+        <&'a M as IntoIterator>::Item: Serialize,
+        S: Serializer,
+    {
+        serializer.collect_seq(map)
+    }
+
+    /// Deserializes a map from a list of entries.
+    pub fn deserialize<'de, M, D>(deserializer: D) -> Result<M, D::Error>
+    where
+        M: IntoIterator + FromIterator<<M as IntoIterator>::Item>,
+        <M as IntoIterator>::Item: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        Ok(Vec::<M::Item>::deserialize(deserializer)?
+            .into_iter()
+            .collect())
+    }
+}
+
+/// Removes all entries first seen at or after `block_number`.
+///
+/// Entries only record the first block they were seen in, so entries that were seen both before
+/// and after `block_number` are kept.
+pub fn retain_before<K, V>(
+    map: &mut HashMap<K, BTreeMap<V, BlockNumber>>,
+    block_number: BlockNumber,
+) where
+    K: Eq + std::hash::Hash,
+{
+    map.retain(|_, entries| {
+        entries.retain(|_, first_seen| *first_seen < block_number);
+        !entries.is_empty()
+    });
+}
+
+/// An endless future that keeps the given [`CanonicalIndex`] in sync with the canonical chain.
+///
+/// The index is first restored from its snapshot in `dir` and backfilled from the blocks and
+/// receipts that are stored after it, and is then updated on every canonical state notification.
+/// Reverted blocks are removed from the index before the new canonical blocks are applied. A new
+/// snapshot is written whenever at least [`SNAPSHOT_INTERVAL`] blocks were applied since the last
+/// one.
+///
+/// Notifications can be missed, e.g. if the subscription lags behind, in which case the index is
+/// backfilled again from its last indexed block. The index is only marked as synced while it has
+/// no gaps. Backfills and snapshots run on blocking tasks.
+pub async fn maintain_index<I, Client>(index: I, client: Client, dir: PathBuf)
+where
+    I: CanonicalIndex,
+    Client: BlockReader<Block = Block, Receipt = TempoReceipt>
+        + CanonStateSubscriptions<Primitives = TempoPrimitives>
+        + Clone
+        + 'static,
+{
+    let path = dir.join(format!("{}.json", I::NAME));
+
+    // Subscribe before backfilling so that no block is missed in between
+    let mut events = client.canonical_state_stream();
+
+    let (task_index, task_client, task_path) = (index.clone(), client.clone(), path.clone());
+    if let Err(err) =
+        run_blocking(move || load_snapshot(&task_index, &task_client, &task_path)).await
+    {
+        error!(target: "rpc::index", index = I::NAME, ?err, "Failed to load index snapshot");
+    }
+    let mut last_snapshot = index.last_indexed_block();
+
+    catch_up(&index, &client).await;
+    save_snapshot_if_due(&index, &client, &path, &mut last_snapshot).await;
+
+    while let Some(notification) = events.next().await {
+        if let Some(reverted) = notification.reverted() {
+            let first_reverted = reverted.first().header().number();
+            debug!(target: "rpc::index", index = I::NAME, first_reverted, "Reverting index");
+            index.revert_from(first_reverted);
+        }
+
+        let committed = notification.committed();
+        let next = index.last_indexed_block().map_or(0, |last| last + 1);
+        if !index.status().is_synced() || committed.first().header().number() > next {
+            catch_up(&index, &client).await;
+        }
+
+        for (block, receipts) in committed.blocks_and_receipts() {
+            let number = block.header().number();
+            let next = index.last_indexed_block().map_or(0, |last| last + 1);
+
+            // Skip blocks that were already picked up by the backfill
+            if number < next {
+                continue;
+            }
+
+            // Blocks that are still missing are picked up by the next backfill
+            if number > next {
+                index.status().set_synced(false);
+                break;
+            }
+
+            index.apply_block(block, receipts);
+        }
+
+        save_snapshot_if_due(&index, &client, &path, &mut last_snapshot).await;
+    }
+}
+
+/// Runs `f` on a blocking task.
+async fn run_blocking<R>(f: impl FnOnce() -> eyre::Result<R> + Send + 'static) -> eyre::Result<R>
+where
+    R: Send + 'static,
+{
+    tokio::task::spawn_blocking(f).await?
+}
+
+/// Backfills the index on a blocking task and marks it as synced if all stored blocks were
+/// applied.
+async fn catch_up<I, Client>(index: &I, client: &Client)
+where
+    I: CanonicalIndex,
+    Client: BlockReader<Block = Block, Receipt = TempoReceipt> + Clone + 'static,
+{
+    index.status().set_synced(false);
+
+    let (task_index, task_client) = (index.clone(), client.clone());
+    match run_blocking(move || Ok(backfill_index(&task_index, &task_client)?)).await {
+        Ok(()) => index.status().set_synced(true),
+        Err(err) => {
+            error!(target: "rpc::index", index = I::NAME, ?err, "Failed to backfill index");
+        }
+    }
+}
+
+/// Writes a snapshot of the synced index on a blocking task if at least [`SNAPSHOT_INTERVAL`]
+/// blocks were applied since `last_snapshot`.
+async fn save_snapshot_if_due<I, Client>(
+    index: &I,
+    client: &Client,
+    path: &Path,
+    last_snapshot: &mut Option<BlockNumber>,
+) where
+    I: CanonicalIndex,
+    Client: BlockReader<Block = Block, Receipt = TempoReceipt> + Clone + 'static,
+{
+    let Some(last_indexed) = index.last_indexed_block() else {
+        return;
+    };
+    if !index.status().is_synced()
+        || last_snapshot.is_some_and(|last| last_indexed < last.saturating_add(SNAPSHOT_INTERVAL))
+    {
+        return;
+    }
+
+    let (task_index, task_client, task_path) = (index.clone(), client.clone(), path.to_owned());
+    match run_blocking(move || save_snapshot(&task_index, &task_client, &task_path)).await {
+        Ok(()) => *last_snapshot = Some(last_indexed),
+        Err(err) => {
+            error!(target: "rpc::index", index = I::NAME, ?err, "Failed to save index snapshot");
+        }
+    }
+}
+
+/// Block an index snapshot was taken at.
+#[derive(Debug, Serialize, Deserialize)]
+struct SnapshotCheckpoint {
+    /// Last block that was applied to the index.
+    block_number: BlockNumber,
+    /// Hash of the last block that was applied to the index.
+    block_hash: B256,
+}
+
+/// Restores the index from the snapshot at `path`, if there is one.
+///
+/// The snapshot is ignored if the block it was taken at is not canonical anymore, e.g. because
+/// the node was unwound or crashed before persisting that block.
+fn load_snapshot<I, Client>(index: &I, client: &Client, path: &Path) -> eyre::Result<()>
+where
+    I: CanonicalIndex,
+    Client: BlockReader,
+{
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err.into()),
+    };
+
+    // The snapshot file holds the checkpoint followed by the indexed data
+    let mut deserializer = serde_json::Deserializer::from_reader(BufReader::new(file));
+    let checkpoint = SnapshotCheckpoint::deserialize(&mut deserializer)?;
+    if client.block_hash(checkpoint.block_number)? != Some(checkpoint.block_hash) {
+        debug!(
+            target: "rpc::index",
+            index = I::NAME,
+            block_number = checkpoint.block_number,
+            "Ignoring non-canonical index snapshot"
+        );
+        return Ok(());
+    }
+
+    index.load(&mut deserializer)?;
+    deserializer.end()?;
+
+    debug!(
+        target: "rpc::index",
+        index = I::NAME,
+        block_number = checkpoint.block_number,
+        "Loaded index snapshot"
+    );
+
+    Ok(())
+}
+
+/// Writes a snapshot of the index to `path`, replacing the previous one.
+fn save_snapshot<I, Client>(index: &I, client: &Client, path: &Path) -> eyre::Result<()>
+where
+    I: CanonicalIndex,
+    Client: BlockReader,
+{
+    let Some(block_number) = index.last_indexed_block() else {
+        return Ok(());
+    };
+    let block_hash = client
+        .block_hash(block_number)?
+        .ok_or_eyre("last indexed block not found")?;
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    // Written to a temporary file first, so that a crash can't leave a partial snapshot behind
+    let tmp_path = path.with_extension("json.tmp");
+    let mut writer = BufWriter::new(File::create(&tmp_path)?);
+    serde_json::to_writer(
+        &mut writer,
+        &SnapshotCheckpoint {
+            block_number,
+            block_hash,
+        },
+    )?;
+    writer.write_all(b"\n")?;
+    index.save(&mut serde_json::Serializer::new(&mut writer))?;
+    writer.flush()?;
+    fs::rename(tmp_path, path)?;
+
+    debug!(target: "rpc::index", index = I::NAME, block_number, "Saved index snapshot");
+
+    Ok(())
+}
+
+/// Applies all stored blocks that have not been indexed yet.
+fn backfill_index<I, Client>(index: &I, client: &Client) -> ProviderResult<()>
+where
+    I: CanonicalIndex,
+    Client: BlockReader<Block = Block, Receipt = TempoReceipt>,
+{
+    let tip = client.best_block_number()?;
+    let mut start = index.last_indexed_block().map_or(0, |last| last + 1);
+
+    debug!(target: "rpc::index", index = I::NAME, start, tip, "Backfilling index");
+
+    while start <= tip {
+        let end = start.saturating_add(BACKFILL_BATCH_SIZE - 1).min(tip);
+
+        let blocks = client.recovered_block_range(start..=end)?;
+        let receipts = client.receipts_by_block_range(start..=end)?;
+        for (block, receipts) in blocks.iter().zip(receipts) {
+            index.apply_block(block, &receipts);
+        }
+
+        start = end + 1;
+    }
+
+    Ok(())
+}
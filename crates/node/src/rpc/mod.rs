@@ -3,17 +3,22 @@ pub mod amm;
 pub mod dex;
 pub mod error;
 pub mod eth_ext;
+pub mod fee;
+pub mod index;
+pub mod pagination;
 pub mod policy;
+pub mod state;
 pub mod token;
 
 pub use admin::{TempoAdminApi, TempoAdminApiServer};
 use alloy_primitives::{Address, B256};
 use alloy_rpc_types_eth::{Log, ReceiptWithBloom};
-pub use amm::{TempoAmm, TempoAmmApiServer};
+pub use amm::{PoolIndex, TempoAmm, TempoAmmApiServer};
 pub use dex::{TempoDex, api::TempoDexApiServer};
-pub use eth_ext::{TempoEthExt, TempoEthExtApiServer};
+pub use eth_ext::{TempoEthExt, TempoEthExtApiServer, TransactionIndex};
+pub use fee::{TempoFee, TempoFeeApiServer};
 use futures::{TryFutureExt, future::Either};
-pub use policy::{TempoPolicy, TempoPolicyApiServer};
+pub use policy::{PolicyIndex, TempoPolicy, TempoPolicyApiServer};
 use reth_errors::RethError;
 use reth_primitives_traits::{Recovered, TransactionMeta, WithEncoded, transaction::TxHashRef};
 use reth_transaction_pool::PoolPooledTx;
@@ -22,7 +27,7 @@ pub use tempo_alloy::rpc::TempoTransactionRequest;
 use tempo_chainspec::{TempoChainSpec, hardfork::TempoHardfork};
 use tempo_evm::TempoStateAccess;
 use tempo_precompiles::{NONCE_PRECOMPILE_ADDRESS, nonce::NonceManager};
-pub use token::{TempoToken, TempoTokenApiServer};
+pub use token::{TempoToken, TempoTokenApiServer, TokenIndex};
 
 use crate::{node::TempoNode, rpc::error::TempoEthApiError};
 use alloy::{
@@ -0,0 +1,123 @@
+//! Cursor pagination helpers shared by the Tempo RPC namespaces.
+
+use std::cmp::Ordering;
+use tempo_alloy::rpc::pagination::{PaginationParams, Sort, SortOrder};
+
+/// Default limit for pagination
+pub const DEFAULT_LIMIT: usize = 10;
+
+/// Maximum limit for pagination
+pub const MAX_LIMIT: usize = 100;
+
+/// Returns the page size requested by the given params, capped at [`MAX_LIMIT`].
+pub fn page_limit<Filters>(params: &PaginationParams<Filters>) -> usize {
+    params
+        .limit
+        .map(|l| l.min(MAX_LIMIT))
+        .unwrap_or(DEFAULT_LIMIT)
+}
+
+/// Compares two items by a single field.
+pub type Comparator<T> = Box<dyn Fn(&T, &T) -> Ordering>;
+
+/// Sorts `items` by the field requested in `sort`.
+///
+/// `comparator` maps a field name to a [`Comparator`], and returns `None` if the field is unknown,
+/// in which case the field name is returned as error. Items are kept in their original order if
+/// no sort is requested.
+pub fn sort_items<T>(
+    items: &mut [T],
+    sort: Option<&Sort>,
+    comparator: impl Fn(&str) -> Option<Comparator<T>>,
+) -> Result<(), String> {
+    let Some(sort) = sort else {
+        return Ok(());
+    };
+
+    let compare = comparator(&sort.on).ok_or_else(|| sort.on.clone())?;
+    items.sort_by(|a, b| match sort.order {
+        SortOrder::Asc => compare(a, b),
+        SortOrder::Desc => compare(b, a),
+    });
+
+    Ok(())
+}
+
+/// Returns the page of `items` that starts at `cursor` together with the cursor of the next page.
+///
+/// The cursor of an item is its key, and the page includes the item the cursor points to. Returns
+/// `None` if the cursor doesn't match any item.
+pub fn paginate<T, K: PartialEq>(
+    items: Vec<T>,
+    cursor: Option<K>,
+    limit: usize,
+    key: impl Fn(&T) -> K,
+) -> Option<(Vec<T>, Option<K>)> {
+    let start = match cursor {
+        Some(cursor) => items.iter().position(|item| key(item) == cursor)?,
+        None => 0,
+    };
+
+    let mut iter = items.into_iter().skip(start);
+    let page = iter.by_ref().take(limit).collect();
+    let next_cursor = iter.next().map(|next| key(&next));
+
+    Some((page, next_cursor))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paginate() {
+        let items = vec![1u64, 2, 3, 4, 5];
+
+        let (page, next) = paginate(items.clone(), None, 2, |i| *i).unwrap();
+        assert_eq!(page, vec![1, 2]);
+        assert_eq!(next, Some(3));
+
+        let (page, next) = paginate(items.clone(), next, 2, |i| *i).unwrap();
+        assert_eq!(page, vec![3, 4]);
+        assert_eq!(next, Some(5));
+
+        let (page, next) = paginate(items.clone(), next, 2, |i| *i).unwrap();
+        assert_eq!(page, vec![5]);
+        assert_eq!(next, None);
+
+        assert!(paginate(items, Some(42), 2, |i| *i).is_none());
+    }
+
+    #[test]
+    fn test_sort_items() {
+        let compare =
+            |field: &str| (field == "value").then(|| Box::new(u64::cmp) as Comparator<u64>);
+
+        let mut items = vec![2u64, 3, 1];
+        sort_items(&mut items, None, compare).unwrap();
+        assert_eq!(items, vec![2, 3, 1]);
+
+        let sort = Sort {
+            on: "value".to_string(),
+            order: SortOrder::Asc,
+        };
+        sort_items(&mut items, Some(&sort), compare).unwrap();
+        assert_eq!(items, vec![1, 2, 3]);
+
+        let sort = Sort {
+            on: "value".to_string(),
+            order: SortOrder::Desc,
+        };
+        sort_items(&mut items, Some(&sort), compare).unwrap();
+        assert_eq!(items, vec![3, 2, 1]);
+
+        let sort = Sort {
+            on: "unknown".to_string(),
+            order: SortOrder::Asc,
+        };
+        assert_eq!(
+            sort_items(&mut items, Some(&sort), compare),
+            Err("unknown".to_string())
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use crate::rpc::{index::IndexNotSynced, state::StateAccessError};
+use alloy_primitives::Address;
+use jsonrpsee::types::ErrorObject;
+use reth_rpc_eth_types::{EthApiError, error::ToRpcError};
+use tempo_precompiles::error::TempoPrecompileError;
+
+/// Policy API specific errors that extend [`EthApiError`].
+#[derive(Debug, thiserror::Error)]
+pub enum PolicyApiError {
+    /// Precompile storage errors
+    #[error(transparent)]
+    Precompile(#[from] TempoPrecompileError),
+
+    /// Failed to access state
+    #[error(transparent)]
+    State(#[from] StateAccessError),
+
+    /// Policy does not exist
+    #[error("policy {0} does not exist")]
+    PolicyNotFound(u64),
+
+    /// Invalid address cursor format
+    #[error("invalid address cursor: failed to parse as address")]
+    InvalidAddressCursor(String),
+
+    /// Address cursor not found in the policy
+    #[error("address cursor {0} not found in policy")]
+    AddressCursorNotFound(Address),
+
+    /// Unknown field to sort by
+    #[error("invalid sort field: {0}")]
+    InvalidSortField(String),
+
+    /// The index backing the endpoint has not caught up with the canonical chain
+    #[error(transparent)]
+    IndexNotSynced(#[from] IndexNotSynced),
+}
+
+impl PolicyApiError {
+    /// Returns the rpc error for this error
+    const fn error_code(&self) -> i32 {
+        match self {
+            Self::PolicyNotFound(_)
+            | Self::InvalidAddressCursor(_)
+            | Self::AddressCursorNotFound(_)
+            | Self::InvalidSortField(_) => jsonrpsee::types::error::INVALID_PARAMS_CODE,
+            _ => jsonrpsee::types::error::INTERNAL_ERROR_CODE,
+        }
+    }
+}
+
+impl From<PolicyApiError> for EthApiError {
+    fn from(err: PolicyApiError) -> Self {
+        match err {
+            PolicyApiError::State(StateAccessError::HeaderNotFound(block_id)) => {
+                Self::HeaderNotFound(block_id)
+            }
+            // All other errors use the Other variant with our error type
+            other => Self::other(other),
+        }
+    }
+}
+
+impl ToRpcError for PolicyApiError {
+    fn to_rpc_error(&self) -> ErrorObject<'static> {
+        ErrorObject::owned(self.error_code(), self.to_string(), None::<()>)
+    }
+}
+
+impl From<PolicyApiError> for ErrorObject<'static> {
+    fn from(value: PolicyApiError) -> Self {
+        value.to_rpc_error()
+    }
+}
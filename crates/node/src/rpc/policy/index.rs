@@ -0,0 +1,257 @@
+use crate::rpc::index::{CanonicalIndex, IndexStatus, IndexedLog, indexed_logs, retain_before};
+use alloy_primitives::{Address, BlockNumber};
+use alloy_sol_types::SolEventInterface;
+use parking_lot::RwLock;
+use reth_primitives_traits::{AlloyBlockHeader, RecoveredBlock};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+use tempo_precompiles::{
+    TIP403_REGISTRY_ADDRESS,
+    tip403_registry::{ITIP403Registry::PolicyType, TIP403RegistryEvent},
+};
+use tempo_primitives::{Block, TempoReceipt};
+
+/// A transfer policy created through the `TIP403Registry`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexedPolicy {
+    /// Whether the policy is a whitelist or a blacklist
+    #[serde(with = "policy_type")]
+    pub policy_type: PolicyType,
+    /// Block the policy was created in
+    pub block_number: BlockNumber,
+}
+
+/// (De)serializes a [`PolicyType`] as its `u8` value.
+mod policy_type {
+    use super::PolicyType;
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    pub(super) fn serialize<S: Serializer>(
+        policy_type: &PolicyType,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(*policy_type as u8)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<PolicyType, D::Error> {
+        u8::deserialize(deserializer)?
+            .try_into()
+            .map_err(|_| D::Error::custom("invalid policy type"))
+    }
+}
+
+/// Index of TIP-403 policies and the accounts that have been added to or removed from them.
+///
+/// The registry only stores a membership bit per (policy, account), so the accounts are
+/// collected from `WhitelistUpdated`, `BlacklistUpdated` and `PolicyMembershipUpdated` logs. The
+/// first two are also emitted for the initial accounts of `createPolicyWithAccounts`. Current
+/// memberships are read from state when serving requests.
+#[derive(Debug, Clone, Default)]
+pub struct PolicyIndex {
+    inner: Arc<RwLock<PolicyIndexInner>>,
+    status: IndexStatus,
+}
+
+impl PolicyIndex {
+    /// Returns the policy with the given ID, if it has been indexed.
+    pub fn policy(&self, policy_id: u64) -> Option<IndexedPolicy> {
+        self.inner.read().policies.get(&policy_id).copied()
+    }
+
+    /// Returns all accounts that have been added to or removed from the policy, ordered by
+    /// address.
+    ///
+    /// Accounts may have been removed since, so membership still needs to be checked against
+    /// state.
+    pub fn accounts_of(&self, policy_id: u64) -> Vec<Address> {
+        self.inner
+            .read()
+            .accounts
+            .get(&policy_id)
+            .map(|accounts| accounts.keys().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Applies a single log to the index.
+    fn apply_log(inner: &mut PolicyIndexInner, log: IndexedLog<'_>) {
+        if log.log.address != TIP403_REGISTRY_ADDRESS {
+            return;
+        }
+
+        let Ok(event) = TIP403RegistryEvent::decode_log(log.log) else {
+            return;
+        };
+
+        match event.data {
+            TIP403RegistryEvent::PolicyCreated(created) => {
+                inner.policies.insert(
+                    created.policyId,
+                    IndexedPolicy {
+                        policy_type: created.policyType,
+                        block_number: log.block_number,
+                    },
+                );
+            }
+            TIP403RegistryEvent::WhitelistUpdated(update) => {
+                inner.track_account(update.policyId, update.account, log.block_number)
+            }
+            TIP403RegistryEvent::BlacklistUpdated(update) => {
+                inner.track_account(update.policyId, update.account, log.block_number)
+            }
+            TIP403RegistryEvent::PolicyMembershipUpdated(update) => {
+                inner.track_account(update.policyId, update.account, log.block_number)
+            }
+            _ => {}
+        }
+    }
+}
+
+impl CanonicalIndex for PolicyIndex {
+    const NAME: &'static str = "policies";
+
+    fn apply_block(&self, block: &RecoveredBlock<Block>, receipts: &[TempoReceipt]) {
+        let mut inner = self.inner.write();
+        for log in indexed_logs(block, receipts) {
+            Self::apply_log(&mut inner, log);
+        }
+        inner.last_indexed_block = Some(block.header().number());
+    }
+
+    fn revert_from(&self, block_number: BlockNumber) {
+        let mut inner = self.inner.write();
+
+        inner
+            .policies
+            .retain(|_, policy| policy.block_number < block_number);
+        retain_before(&mut inner.accounts, block_number);
+
+        inner.last_indexed_block = block_number.checked_sub(1);
+    }
+
+    fn last_indexed_block(&self) -> Option<BlockNumber> {
+        self.inner.read().last_indexed_block
+    }
+
+    fn status(&self) -> &IndexStatus {
+        &self.status
+    }
+
+    fn save<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.inner.read().serialize(serializer)
+    }
+
+    fn load<'de, D: Deserializer<'de>>(&self, deserializer: D) -> Result<(), D::Error> {
+        *self.inner.write() = PolicyIndexInner::deserialize(deserializer)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct PolicyIndexInner {
+    /// Last block that was applied to the index.
+    last_indexed_block: Option<BlockNumber>,
+
+    /// Policies created through the registry.
+    policies: BTreeMap<u64, IndexedPolicy>,
+
+    /// policy -> account -> first block the account was added to or removed from the policy.
+    accounts: HashMap<u64, BTreeMap<Address, BlockNumber>>,
+}
+
+impl PolicyIndexInner {
+    fn track_account(&mut self, policy_id: u64, account: Address, block_number: BlockNumber) {
+        self.accounts
+            .entry(policy_id)
+            .or_default()
+            .entry(account)
+            .or_insert(block_number);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{IntoLogData, Log, TxHash};
+    use tempo_precompiles::tip403_registry::ITIP403Registry;
+
+    fn apply(index: &PolicyIndex, block_number: BlockNumber, event: impl IntoLogData) {
+        let log = Log {
+            address: TIP403_REGISTRY_ADDRESS,
+            data: event.into_log_data(),
+        };
+        let mut inner = index.inner.write();
+        PolicyIndex::apply_log(
+            &mut inner,
+            IndexedLog {
+                log: &log,
+                sender: Address::with_last_byte(0xff),
+                transaction_hash: TxHash::with_last_byte(block_number as u8),
+                block_number,
+                timestamp: block_number * 1000,
+                log_index: 0,
+            },
+        );
+        inner.last_indexed_block = Some(block_number);
+    }
+
+    #[test]
+    fn test_policy_index_tracks_and_reverts() {
+        let index = PolicyIndex::default();
+        let updater = Address::with_last_byte(0xff);
+        let (alice, bob) = (Address::with_last_byte(1), Address::with_last_byte(2));
+
+        // `createPolicyWithAccounts` emits the seed updates before `PolicyCreated`
+        apply(
+            &index,
+            1,
+            ITIP403Registry::WhitelistUpdated {
+                policyId: 2,
+                updater,
+                account: bob,
+                allowed: true,
+            },
+        );
+        apply(
+            &index,
+            1,
+            ITIP403Registry::PolicyCreated {
+                policyId: 2,
+                updater,
+                policyType: PolicyType::WHITELIST,
+            },
+        );
+        apply(
+            &index,
+            2,
+            ITIP403Registry::WhitelistUpdated {
+                policyId: 2,
+                updater,
+                account: alice,
+                allowed: false,
+            },
+        );
+
+        assert_eq!(
+            index.policy(2),
+            Some(IndexedPolicy {
+                policy_type: PolicyType::WHITELIST,
+                block_number: 1,
+            })
+        );
+        assert_eq!(index.accounts_of(2), vec![alice, bob]);
+        assert!(index.accounts_of(3).is_empty());
+
+        index.revert_from(2);
+        assert_eq!(index.last_indexed_block(), Some(1));
+        assert_eq!(index.accounts_of(2), vec![bob]);
+
+        index.revert_from(1);
+        assert!(index.policy(2).is_none());
+        assert!(index.accounts_of(2).is_empty());
+    }
+}
@@ -1,12 +1,29 @@
 pub use addresses::{AddressesFilters, PolicyAddress};
 
-use crate::rpc::policy::addresses::{AddressesParams, AddressesResponse};
+use crate::rpc::{
+    index::CanonicalIndex,
+    pagination::{Comparator, page_limit, paginate, sort_items},
+    policy::addresses::{AddressesParams, AddressesResponse},
+    state::with_storage_at_block,
+};
+use alloy_eips::BlockNumberOrTag;
+use alloy_primitives::Address;
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
-use reth_node_core::rpc::result::internal_rpc_err;
-use reth_rpc_eth_api::RpcNodeCore;
+use reth_node_api::NodePrimitives;
+use reth_rpc_eth_api::{RpcNodeCore, helpers::SpawnBlocking};
+use reth_rpc_eth_types::{EthApiError, error::FromEthApiError};
+use tempo_evm::TempoEvmConfig;
+use tempo_precompiles::tip403_registry::{ITIP403Registry, TIP403Registry};
+use tempo_primitives::TempoHeader;
 
 pub mod addresses;
 
+mod error;
+pub use error::PolicyApiError;
+
+mod index;
+pub use index::{IndexedPolicy, PolicyIndex};
+
 #[rpc(server, namespace = "policy")]
 pub trait TempoPolicyApi {
     /// Gets paginated addresses in a transfer policy on Tempo.
@@ -22,18 +39,121 @@ pub trait TempoPolicyApi {
 #[derive(Debug, Clone, Default)]
 pub struct TempoPolicy<EthApi> {
     eth_api: EthApi,
+    index: PolicyIndex,
 }
 
 impl<EthApi> TempoPolicy<EthApi> {
-    pub fn new(eth_api: EthApi) -> Self {
-        Self { eth_api }
+    /// Creates a new instance of the [`TempoPolicy`] serving indexed data from the given index.
+    pub fn new(eth_api: EthApi, index: PolicyIndex) -> Self {
+        Self { eth_api, index }
+    }
+}
+
+impl<
+    EthApi: RpcNodeCore<Evm = TempoEvmConfig, Primitives: NodePrimitives<BlockHeader = TempoHeader>>,
+> TempoPolicy<EthApi>
+{
+    /// Implementation of the `policy_getAddresses` endpoint
+    fn get_addresses(&self, params: AddressesParams) -> Result<AddressesResponse, PolicyApiError> {
+        self.index.ensure_synced()?;
+
+        let AddressesParams { policy_id, params } = params;
+        let cursor = params
+            .cursor
+            .as_deref()
+            .map(parse_address_cursor)
+            .transpose()?;
+
+        let mut addresses = with_storage_at_block(
+            &self.eth_api,
+            BlockNumberOrTag::Latest.into(),
+            || -> Result<_, PolicyApiError> {
+                let registry = TIP403Registry::new();
+                if !registry.policy_exists(ITIP403Registry::policyExistsCall {
+                    policyId: policy_id,
+                })? {
+                    return Err(PolicyApiError::PolicyNotFound(policy_id));
+                }
+
+                // The built-in always-reject and always-allow policies have no members
+                if policy_id < 2 {
+                    return Ok(Vec::new());
+                }
+
+                let policy_type = registry
+                    .policy_data(ITIP403Registry::policyDataCall {
+                        policyId: policy_id,
+                    })?
+                    .policyType;
+
+                let mut addresses = Vec::new();
+                for address in self.index.accounts_of(policy_id) {
+                    let authorized = registry.is_authorized(ITIP403Registry::isAuthorizedCall {
+                        policyId: policy_id,
+                        user: address,
+                    })?;
+
+                    // Whitelist members are authorized, blacklist members are restricted
+                    let is_member = match policy_type {
+                        ITIP403Registry::PolicyType::WHITELIST => authorized,
+                        _ => !authorized,
+                    };
+                    if !is_member {
+                        continue;
+                    }
+
+                    let address = PolicyAddress {
+                        address,
+                        authorized,
+                    };
+                    if params
+                        .filters
+                        .as_ref()
+                        .is_none_or(|filter| address_matches_filter(&address, filter))
+                    {
+                        addresses.push(address);
+                    }
+                }
+                Ok(addresses)
+            },
+        )?;
+
+        sort_items(&mut addresses, params.sort.as_ref(), address_comparator)
+            .map_err(PolicyApiError::InvalidSortField)?;
+
+        let (addresses, next_cursor) =
+            paginate(addresses, cursor, page_limit(&params), |a| a.address)
+                .ok_or_else(|| PolicyApiError::AddressCursorNotFound(cursor.unwrap_or_default()))?;
+
+        Ok(AddressesResponse {
+            next_cursor: next_cursor.map(|address| address.to_string()),
+            addresses,
+        })
     }
 }
 
 #[async_trait::async_trait]
-impl<EthApi: RpcNodeCore> TempoPolicyApiServer for TempoPolicy<EthApi> {
-    async fn addresses(&self, _params: AddressesParams) -> RpcResult<AddressesResponse> {
-        Err(internal_rpc_err("unimplemented"))
+impl<
+    EthApi: RpcNodeCore<Evm = TempoEvmConfig, Primitives: NodePrimitives<BlockHeader = TempoHeader>>
+        + SpawnBlocking,
+> TempoPolicyApiServer for TempoPolicy<EthApi>
+{
+    /// Returns the members of a policy based on pagination parameters.
+    ///
+    /// ## Cursor
+    /// The cursor for this method is the **Account Address**.
+    /// - When provided in the request, returns addresses starting at the given address
+    /// - Returns `next_cursor` in the response containing the first address of the next page
+    async fn addresses(&self, params: AddressesParams) -> RpcResult<AddressesResponse> {
+        let this = self.clone();
+        self.eth_api
+            .spawn_blocking_io(move |_| {
+                Self::get_addresses(&this, params)
+                    .map_err(EthApiError::from)
+                    .map_err(EthApi::Error::from_eth_err)
+            })
+            .await
+            .map_err(Into::into)
     }
 }
 
@@ -43,3 +163,26 @@ impl<EthApi: RpcNodeCore> TempoPolicy<EthApi> {
         self.eth_api.provider()
     }
 }
+
+/// Checks if a policy address matches the given filters
+fn address_matches_filter(address: &PolicyAddress, filter: &AddressesFilters) -> bool {
+    filter
+        .authorized
+        .is_none_or(|authorized| authorized == address.authorized)
+}
+
+/// Returns the comparator for sorting policy addresses by the given field.
+fn address_comparator(field: &str) -> Option<Comparator<PolicyAddress>> {
+    let compare: fn(&PolicyAddress, &PolicyAddress) -> std::cmp::Ordering = match field {
+        "address" => |a, b| a.address.cmp(&b.address),
+        _ => return None,
+    };
+    Some(Box::new(compare))
+}
+
+/// Parses a cursor string into an account address
+fn parse_address_cursor(cursor: &str) -> Result<Address, PolicyApiError> {
+    cursor
+        .parse::<Address>()
+        .map_err(|_| PolicyApiError::InvalidAddressCursor(cursor.to_string()))
+}
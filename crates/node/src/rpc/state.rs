@@ -0,0 +1,80 @@
+//! Helpers for reading precompile storage from RPC handlers.
+
+use alloy_eips::BlockId;
+use alloy_primitives::Sealable;
+use reth_ethereum::evm::revm::database::StateProviderDatabase;
+use reth_evm::{EvmInternals, revm::database::CacheDB};
+use reth_node_api::{ConfigureEvm, NodePrimitives};
+use reth_provider::{BlockReaderIdExt, StateProviderBox, StateProviderFactory};
+use reth_rpc_eth_api::RpcNodeCore;
+use tempo_evm::TempoEvmConfig;
+use tempo_precompiles::storage::{StorageCtx, evm::EvmPrecompileStorageProvider};
+use tempo_primitives::TempoHeader;
+
+/// Errors that can occur when setting up precompile storage access for an RPC request.
+#[derive(Debug, thiserror::Error)]
+pub enum StateAccessError {
+    /// Header not found for block
+    #[error("header not found for block {0:?}")]
+    HeaderNotFound(BlockId),
+
+    /// Provider error when getting the header or state
+    /// Boxed because Provider::Error is an associated type
+    #[error("internal node error: failed to get state: {0}")]
+    Provider(#[source] Box<dyn std::error::Error + Send + Sync>),
+
+    /// Failed to create EVM context
+    /// Boxed because ConfigureEvm::Error is an associated type
+    #[error("internal node error: failed to create EVM")]
+    CreateEvm(#[source] Box<dyn std::error::Error + Send + Sync>),
+}
+
+/// Returns the header of the given block and a provider for the state at that block.
+pub fn state_at_block<EthApi>(
+    eth_api: &EthApi,
+    at: BlockId,
+) -> Result<(TempoHeader, StateProviderBox), StateAccessError>
+where
+    EthApi:
+        RpcNodeCore<Evm = TempoEvmConfig, Primitives: NodePrimitives<BlockHeader = TempoHeader>>,
+{
+    let provider = eth_api.provider();
+    let header = provider
+        .header_by_id(at)
+        .map_err(|e| StateAccessError::Provider(Box::new(e)))?
+        .ok_or(StateAccessError::HeaderNotFound(at))?;
+
+    let block_hash = header.hash_slow();
+    let state_provider = provider
+        .state_by_block_hash(block_hash)
+        .map_err(|e| StateAccessError::Provider(Box::new(e)))?;
+
+    Ok((header, state_provider))
+}
+
+/// Runs `f` with precompile storage access at the given block.
+///
+/// This handles the boilerplate of creating the EVM context and state provider, so that `f` can
+/// instantiate precompiles and read their storage directly.
+pub fn with_storage_at_block<EthApi, F, R, E>(eth_api: &EthApi, at: BlockId, f: F) -> Result<R, E>
+where
+    EthApi:
+        RpcNodeCore<Evm = TempoEvmConfig, Primitives: NodePrimitives<BlockHeader = TempoHeader>>,
+    F: FnOnce() -> Result<R, E>,
+    E: From<StateAccessError>,
+{
+    let (header, state_provider) = state_at_block(eth_api, at)?;
+
+    // Create EVM using state provider db
+    let db = CacheDB::new(StateProviderDatabase::new(state_provider));
+    let mut evm = eth_api
+        .evm_config()
+        .evm_for_block(db, &header)
+        .map_err(|e| StateAccessError::CreateEvm(Box::new(e)))?;
+
+    let ctx = evm.ctx_mut();
+    let internals = EvmInternals::new(&mut ctx.journaled_state, &ctx.block);
+    let mut storage = EvmPrecompileStorageProvider::new_max_gas(internals, &ctx.cfg);
+
+    StorageCtx::enter(&mut storage, f)
+}
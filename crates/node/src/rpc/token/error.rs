@@ -0,0 +1,79 @@
+use crate::rpc::{index::IndexNotSynced, state::StateAccessError};
+use alloy_primitives::Address;
+use jsonrpsee::types::ErrorObject;
+use reth_rpc_eth_types::{EthApiError, error::ToRpcError};
+use tempo_precompiles::error::TempoPrecompileError;
+
+/// Token API specific errors that extend [`EthApiError`].
+#[derive(Debug, thiserror::Error)]
+pub enum TokenApiError {
+    /// Precompile storage errors
+    #[error(transparent)]
+    Precompile(#[from] TempoPrecompileError),
+
+    /// Failed to access state
+    #[error(transparent)]
+    State(#[from] StateAccessError),
+
+    /// Invalid token cursor format
+    #[error("invalid token cursor: failed to parse as address")]
+    InvalidTokenCursor(String),
+
+    /// Token cursor not found in available tokens
+    #[error("token cursor {0} not found in available tokens")]
+    TokenCursorNotFound(Address),
+
+    /// Invalid role change cursor format
+    #[error("invalid role change cursor: expected `<block number>:<log index>`, got {0}")]
+    InvalidRoleChangeCursor(String),
+
+    /// Role change cursor not found in the indexed role changes
+    #[error("role change cursor {0} not found in indexed role changes")]
+    RoleChangeCursorNotFound(String),
+
+    /// Unknown field to sort by
+    #[error("invalid sort field: {0}")]
+    InvalidSortField(String),
+
+    /// The index backing the endpoint has not caught up with the canonical chain
+    #[error(transparent)]
+    IndexNotSynced(#[from] IndexNotSynced),
+}
+
+impl TokenApiError {
+    /// Returns the rpc error for this error
+    const fn error_code(&self) -> i32 {
+        match self {
+            Self::InvalidTokenCursor(_)
+            | Self::TokenCursorNotFound(_)
+            | Self::InvalidRoleChangeCursor(_)
+            | Self::RoleChangeCursorNotFound(_)
+            | Self::InvalidSortField(_) => jsonrpsee::types::error::INVALID_PARAMS_CODE,
+            _ => jsonrpsee::types::error::INTERNAL_ERROR_CODE,
+        }
+    }
+}
+
+impl From<TokenApiError> for EthApiError {
+    fn from(err: TokenApiError) -> Self {
+        match err {
+            TokenApiError::State(StateAccessError::HeaderNotFound(block_id)) => {
+                Self::HeaderNotFound(block_id)
+            }
+            // All other errors use the Other variant with our error type
+            other => Self::other(other),
+        }
+    }
+}
+
+impl ToRpcError for TokenApiError {
+    fn to_rpc_error(&self) -> ErrorObject<'static> {
+        ErrorObject::owned(self.error_code(), self.to_string(), None::<()>)
+    }
+}
+
+impl From<TokenApiError> for ErrorObject<'static> {
+    fn from(value: TokenApiError) -> Self {
+        value.to_rpc_error()
+    }
+}
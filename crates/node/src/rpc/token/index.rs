@@ -0,0 +1,446 @@
+use crate::rpc::{
+    index::{CanonicalIndex, IndexStatus, IndexedLog, indexed_logs, map_entries, retain_before},
+    token::role_history::RoleChange,
+};
+use alloy_primitives::{Address, B256, BlockNumber};
+use alloy_sol_types::SolEventInterface;
+use parking_lot::RwLock;
+use reth_primitives_traits::{AlloyBlockHeader, RecoveredBlock};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    collections::{BTreeMap, HashMap},
+    ops::RangeBounds,
+    sync::Arc,
+};
+use tempo_precompiles::{
+    TIP20_FACTORY_ADDRESS,
+    tip20::{RolesAuthEvent, TIP20Event, is_tip20_prefix, roles::DEFAULT_ADMIN_ROLE},
+    tip20_factory::TIP20FactoryEvent,
+};
+use tempo_primitives::{Block, TempoReceipt};
+
+/// A TIP-20 token created through the `TIP20Factory`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexedToken {
+    /// Token address
+    pub address: Address,
+    /// Address that sent the token creation transaction
+    pub creator: Address,
+    /// Timestamp of the block the token was created in
+    pub created_at: u64,
+    /// Block the token was created in
+    pub block_number: BlockNumber,
+}
+
+/// A `RoleMembershipUpdated` event emitted by a TIP-20 token.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexedRoleChange {
+    /// Index of the log within its block
+    pub log_index: u64,
+    /// The role change
+    pub change: RoleChange,
+}
+
+impl IndexedRoleChange {
+    /// Returns the position of the role change in the chain.
+    pub const fn position(&self) -> (BlockNumber, u64) {
+        (self.change.block_number, self.log_index)
+    }
+}
+
+/// Index of TIP-20 tokens and the accounts that interacted with them.
+///
+/// Built from `TokenCreated`, `Transfer`, `Mint`, `Burn` and `RoleMembershipUpdated` logs. The
+/// index only records *which* accounts and roles are associated with a token, current balances
+/// and role memberships are read from state when serving requests. The full history of role
+/// changes is kept as well.
+#[derive(Debug, Clone, Default)]
+pub struct TokenIndex {
+    inner: Arc<RwLock<TokenIndexInner>>,
+    status: IndexStatus,
+}
+
+impl TokenIndex {
+    /// Returns the token created at the given address, if it was created through the factory.
+    pub fn token(&self, address: Address) -> Option<IndexedToken> {
+        self.inner.read().tokens.get(&address).copied()
+    }
+
+    /// Returns the addresses of all tokens the account has held or has been granted a role for.
+    pub fn tokens_of(&self, account: Address) -> Vec<Address> {
+        self.inner
+            .read()
+            .holdings
+            .get(&account)
+            .map(|tokens| tokens.keys().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns all roles that have been granted to the account for the given token.
+    ///
+    /// Roles may have been revoked since, so membership still needs to be checked against state.
+    pub fn roles_of(&self, token: Address, account: Address) -> Vec<B256> {
+        self.inner
+            .read()
+            .roles
+            .get(&(token, account))
+            .map(|roles| roles.keys().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns all role changes within the given block range that match `filter`, in the order
+    /// they were emitted.
+    pub fn role_changes(
+        &self,
+        blocks: impl RangeBounds<BlockNumber>,
+        filter: impl Fn(&RoleChange) -> bool,
+    ) -> Vec<IndexedRoleChange> {
+        self.inner
+            .read()
+            .role_changes
+            .range(blocks)
+            .flat_map(|(_, changes)| changes)
+            .filter(|indexed| filter(&indexed.change))
+            .cloned()
+            .collect()
+    }
+
+    /// Applies a single log to the index.
+    fn apply_log(inner: &mut TokenIndexInner, log: IndexedLog<'_>) {
+        let block_number = log.block_number;
+
+        if log.log.address == TIP20_FACTORY_ADDRESS {
+            if let Ok(event) = TIP20FactoryEvent::decode_log(log.log)
+                && let TIP20FactoryEvent::TokenCreated(created) = event.data
+            {
+                inner.tokens.insert(
+                    created.token,
+                    IndexedToken {
+                        address: created.token,
+                        creator: log.sender,
+                        created_at: log.timestamp,
+                        block_number,
+                    },
+                );
+
+                // The initial admin is granted without a `RoleMembershipUpdated` event
+                inner.track_role(
+                    created.token,
+                    created.admin,
+                    DEFAULT_ADMIN_ROLE,
+                    block_number,
+                );
+            }
+            return;
+        }
+
+        if !is_tip20_prefix(log.log.address) {
+            return;
+        }
+        let token = log.log.address;
+
+        if let Ok(event) = TIP20Event::decode_log(log.log) {
+            match event.data {
+                TIP20Event::Transfer(transfer) => {
+                    inner.track_holding(transfer.from, token, block_number);
+                    inner.track_holding(transfer.to, token, block_number);
+                }
+                TIP20Event::Mint(mint) => inner.track_holding(mint.to, token, block_number),
+                TIP20Event::Burn(burn) => inner.track_holding(burn.from, token, block_number),
+                _ => {}
+            }
+        } else if let Ok(event) = RolesAuthEvent::decode_log(log.log)
+            && let RolesAuthEvent::RoleMembershipUpdated(update) = event.data
+        {
+            if update.hasRole {
+                inner.track_role(token, update.account, update.role, block_number);
+            }
+
+            inner
+                .role_changes
+                .entry(block_number)
+                .or_default()
+                .push(IndexedRoleChange {
+                    log_index: log.log_index,
+                    change: RoleChange {
+                        account: update.account,
+                        block_number,
+                        granted: update.hasRole,
+                        role: update.role,
+                        sender: update.sender,
+                        timestamp: log.timestamp,
+                        token,
+                        transaction_hash: log.transaction_hash,
+                    },
+                });
+        }
+    }
+}
+
+impl CanonicalIndex for TokenIndex {
+    const NAME: &'static str = "tokens";
+
+    fn apply_block(&self, block: &RecoveredBlock<Block>, receipts: &[TempoReceipt]) {
+        let mut inner = self.inner.write();
+        for log in indexed_logs(block, receipts) {
+            Self::apply_log(&mut inner, log);
+        }
+        inner.last_indexed_block = Some(block.header().number());
+    }
+
+    fn revert_from(&self, block_number: BlockNumber) {
+        let mut inner = self.inner.write();
+
+        inner
+            .tokens
+            .retain(|_, token| token.block_number < block_number);
+        retain_before(&mut inner.holdings, block_number);
+        retain_before(&mut inner.roles, block_number);
+        inner.role_changes.split_off(&block_number);
+
+        inner.last_indexed_block = block_number.checked_sub(1);
+    }
+
+    fn last_indexed_block(&self) -> Option<BlockNumber> {
+        self.inner.read().last_indexed_block
+    }
+
+    fn status(&self) -> &IndexStatus {
+        &self.status
+    }
+
+    fn save<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.inner.read().serialize(serializer)
+    }
+
+    fn load<'de, D: Deserializer<'de>>(&self, deserializer: D) -> Result<(), D::Error> {
+        *self.inner.write() = TokenIndexInner::deserialize(deserializer)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct TokenIndexInner {
+    /// Last block that was applied to the index.
+    last_indexed_block: Option<BlockNumber>,
+
+    /// Tokens created through the factory.
+    tokens: BTreeMap<Address, IndexedToken>,
+
+    /// account -> token -> first block the account was involved in a transfer, mint or burn or
+    /// was granted a role.
+    holdings: HashMap<Address, BTreeMap<Address, BlockNumber>>,
+
+    /// (token, account) -> role -> first block the role was granted.
+    #[serde(with = "map_entries")]
+    roles: HashMap<(Address, Address), BTreeMap<B256, BlockNumber>>,
+
+    /// block -> role changes emitted in the block, in log order.
+    role_changes: BTreeMap<BlockNumber, Vec<IndexedRoleChange>>,
+}
+
+impl TokenIndexInner {
+    fn track_holding(&mut self, account: Address, token: Address, block_number: BlockNumber) {
+        if account.is_zero() {
+            return;
+        }
+        self.holdings
+            .entry(account)
+            .or_default()
+            .entry(token)
+            .or_insert(block_number);
+    }
+
+    fn track_role(
+        &mut self,
+        token: Address,
+        account: Address,
+        role: B256,
+        block_number: BlockNumber,
+    ) {
+        self.track_holding(account, token, block_number);
+        self.roles
+            .entry((token, account))
+            .or_default()
+            .entry(role)
+            .or_insert(block_number);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{IntoLogData, Log, TxHash, U256};
+    use tempo_precompiles::{
+        tip20::{IRolesAuth, ISSUER_ROLE, ITIP20, token_id_to_address},
+        tip20_factory::ITIP20Factory,
+    };
+
+    fn log(address: Address, event: impl IntoLogData) -> Log {
+        Log {
+            address,
+            data: event.into_log_data(),
+        }
+    }
+
+    fn apply(index: &TokenIndex, block_number: BlockNumber, log: Log) {
+        let mut inner = index.inner.write();
+        TokenIndex::apply_log(
+            &mut inner,
+            IndexedLog {
+                log: &log,
+                sender: Address::with_last_byte(0xff),
+                transaction_hash: TxHash::with_last_byte(block_number as u8),
+                block_number,
+                timestamp: block_number * 1000,
+                log_index: 0,
+            },
+        );
+        inner.last_indexed_block = Some(block_number);
+    }
+
+    #[test]
+    fn test_token_index_tracks_and_reverts() {
+        let index = TokenIndex::default();
+        let token = token_id_to_address(1);
+        let admin = Address::with_last_byte(1);
+        let holder = Address::with_last_byte(2);
+
+        apply(
+            &index,
+            1,
+            log(
+                TIP20_FACTORY_ADDRESS,
+                ITIP20Factory::TokenCreated {
+                    token,
+                    tokenId: U256::ONE,
+                    name: "Test".to_string(),
+                    symbol: "TST".to_string(),
+                    currency: "USD".to_string(),
+                    quoteToken: token_id_to_address(0),
+                    admin,
+                },
+            ),
+        );
+        apply(
+            &index,
+            2,
+            log(
+                token,
+                IRolesAuth::RoleMembershipUpdated {
+                    role: *ISSUER_ROLE,
+                    account: admin,
+                    sender: admin,
+                    hasRole: true,
+                },
+            ),
+        );
+        apply(
+            &index,
+            3,
+            log(
+                token,
+                ITIP20::Transfer {
+                    from: Address::ZERO,
+                    to: holder,
+                    amount: U256::ONE,
+                },
+            ),
+        );
+
+        let indexed = index.token(token).unwrap();
+        assert_eq!(indexed.creator, Address::with_last_byte(0xff));
+        assert_eq!(indexed.created_at, 1000);
+        assert_eq!(index.tokens_of(admin), vec![token]);
+        assert_eq!(index.tokens_of(holder), vec![token]);
+        assert!(index.tokens_of(Address::ZERO).is_empty());
+        assert_eq!(
+            index.roles_of(token, admin),
+            vec![DEFAULT_ADMIN_ROLE, *ISSUER_ROLE]
+        );
+
+        let changes = index.role_changes(.., |_| true);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].position(), (2, 0));
+        assert_eq!(
+            changes[0].change,
+            RoleChange {
+                account: admin,
+                block_number: 2,
+                granted: true,
+                role: *ISSUER_ROLE,
+                sender: admin,
+                timestamp: 2000,
+                token,
+                transaction_hash: TxHash::with_last_byte(2),
+            }
+        );
+        assert!(index.role_changes(3.., |_| true).is_empty());
+        assert!(index.role_changes(.., |change| !change.granted).is_empty());
+
+        index.revert_from(2);
+        assert_eq!(index.last_indexed_block(), Some(1));
+        assert!(index.role_changes(.., |_| true).is_empty());
+        assert!(index.tokens_of(holder).is_empty());
+        assert_eq!(index.tokens_of(admin), vec![token]);
+        assert_eq!(index.roles_of(token, admin), vec![DEFAULT_ADMIN_ROLE]);
+
+        index.revert_from(0);
+        assert_eq!(index.last_indexed_block(), None);
+        assert!(index.token(token).is_none());
+        assert!(index.tokens_of(admin).is_empty());
+    }
+
+    #[test]
+    fn test_token_index_snapshot_round_trip() {
+        let index = TokenIndex::default();
+        let token = token_id_to_address(1);
+        let admin = Address::with_last_byte(1);
+        apply(
+            &index,
+            1,
+            log(
+                token,
+                IRolesAuth::RoleMembershipUpdated {
+                    role: *ISSUER_ROLE,
+                    account: admin,
+                    sender: admin,
+                    hasRole: true,
+                },
+            ),
+        );
+
+        let mut snapshot = Vec::new();
+        index
+            .save(&mut serde_json::Serializer::new(&mut snapshot))
+            .unwrap();
+        let restored = TokenIndex::default();
+        restored
+            .load(&mut serde_json::Deserializer::from_slice(&snapshot))
+            .unwrap();
+
+        assert_eq!(restored.last_indexed_block(), Some(1));
+        assert_eq!(restored.roles_of(token, admin), vec![*ISSUER_ROLE]);
+        assert_eq!(
+            restored.role_changes(.., |_| true)[0].change,
+            index.role_changes(.., |_| true)[0].change
+        );
+
+        // Reverting a restored block removes it like any other
+        restored.revert_from(1);
+        assert!(restored.roles_of(token, admin).is_empty());
+    }
+
+    #[test]
+    fn test_token_index_sync_status() {
+        let index = TokenIndex::default();
+        assert!(index.ensure_synced().is_err());
+
+        // Clones share the status with the index maintained by the node
+        index.clone().status().set_synced(true);
+        assert!(index.ensure_synced().is_ok());
+
+        index.status().set_synced(false);
+        assert!(index.ensure_synced().is_err());
+    }
+}
@@ -1,17 +1,43 @@
-use crate::rpc::token::{
-    role_history::{RoleHistoryFilters, RoleHistoryResponse},
-    tokens::{TokensFilters, TokensResponse},
-    tokens_by_address::{TokensByAddressParams, TokensByAddressResponse},
+use crate::rpc::{
+    index::CanonicalIndex,
+    pagination::{Comparator, page_limit, paginate, sort_items},
+    state::with_storage_at_block,
+    token::{
+        role_history::{RoleChange, RoleHistoryFilters, RoleHistoryResponse},
+        tokens::{Token, TokensFilters, TokensResponse},
+        tokens_by_address::{AccountToken, TokensByAddressParams, TokensByAddressResponse},
+    },
 };
+use alloy_eips::BlockNumberOrTag;
+use alloy_primitives::{Address, B256};
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
-use reth_node_core::rpc::result::internal_rpc_err;
-use reth_rpc_eth_api::RpcNodeCore;
+use reth_node_api::NodePrimitives;
+use reth_rpc_eth_api::{RpcNodeCore, helpers::SpawnBlocking};
+use reth_rpc_eth_types::{EthApiError, error::FromEthApiError};
+use std::{collections::BTreeSet, ops::Bound};
 use tempo_alloy::rpc::pagination::PaginationParams;
+use tempo_evm::TempoEvmConfig;
+use tempo_precompiles::{
+    path_usd::{RECEIVE_WITH_MEMO_ROLE, TRANSFER_ROLE},
+    tip20::{
+        BURN_BLOCKED_ROLE, FORCE_TRANSFER_ROLE, FREEZE_ROLE, ISSUER_ROLE, ITIP20, PAUSE_ROLE,
+        TIP20Token, UNPAUSE_ROLE, address_to_token_id_unchecked, roles::DEFAULT_ADMIN_ROLE,
+        token_id_to_address,
+    },
+    tip20_factory::TIP20Factory,
+};
+use tempo_primitives::TempoHeader;
 
 pub mod role_history;
 pub mod tokens;
 pub mod tokens_by_address;
 
+mod error;
+pub use error::TokenApiError;
+
+mod index;
+pub use index::{IndexedRoleChange, IndexedToken, TokenIndex};
+
 #[rpc(server, namespace = "token")]
 pub trait TempoTokenApi {
     /// Gets paginated role change history for TIP-20 tokens on Tempo.
@@ -47,32 +73,316 @@ pub trait TempoTokenApi {
 #[derive(Debug, Clone, Default)]
 pub struct TempoToken<EthApi> {
     eth_api: EthApi,
+    index: TokenIndex,
+}
+
+impl<EthApi> TempoToken<EthApi> {
+    /// Creates a new instance of the [`TempoToken`] serving indexed data from the given index.
+    pub fn new(eth_api: EthApi, index: TokenIndex) -> Self {
+        Self { eth_api, index }
+    }
 }
 
 impl<EthApi> TempoToken<EthApi> {
-    pub fn new(eth_api: EthApi) -> Self {
-        Self { eth_api }
+    /// Implementation of the `token_getRoleHistory` endpoint
+    fn get_role_history(
+        &self,
+        params: PaginationParams<RoleHistoryFilters>,
+    ) -> Result<RoleHistoryResponse, TokenApiError> {
+        self.index.ensure_synced()?;
+
+        let cursor = params
+            .cursor
+            .as_deref()
+            .map(parse_role_change_cursor)
+            .transpose()?;
+
+        let filters = params.filters.clone().unwrap_or_default();
+        let blocks = filters
+            .block_number
+            .as_ref()
+            .map(|range| {
+                (
+                    range.min.map_or(Bound::Unbounded, Bound::Included),
+                    range.max.map_or(Bound::Unbounded, Bound::Included),
+                )
+            })
+            .unwrap_or((Bound::Unbounded, Bound::Unbounded));
+
+        let mut changes = self.index.role_changes(blocks, |change| {
+            role_change_matches_filter(change, &filters)
+        });
+
+        sort_items(&mut changes, params.sort.as_ref(), role_change_comparator)
+            .map_err(TokenApiError::InvalidSortField)?;
+
+        let (changes, next_cursor) = paginate(
+            changes,
+            cursor,
+            page_limit(&params),
+            IndexedRoleChange::position,
+        )
+        .ok_or_else(|| {
+            TokenApiError::RoleChangeCursorNotFound(params.cursor.clone().unwrap_or_default())
+        })?;
+
+        Ok(RoleHistoryResponse {
+            next_cursor: next_cursor
+                .map(|(block_number, log_index)| format!("{block_number}:{log_index}")),
+            role_changes: changes.into_iter().map(|indexed| indexed.change).collect(),
+        })
+    }
+}
+
+impl<
+    EthApi: RpcNodeCore<Evm = TempoEvmConfig, Primitives: NodePrimitives<BlockHeader = TempoHeader>>,
+> TempoToken<EthApi>
+{
+    /// Implementation of the `token_getTokens` endpoint
+    fn get_tokens(
+        &self,
+        params: PaginationParams<TokensFilters>,
+    ) -> Result<TokensResponse, TokenApiError> {
+        self.index.ensure_synced()?;
+
+        let cursor = params
+            .cursor
+            .as_deref()
+            .map(parse_token_cursor)
+            .transpose()?;
+
+        let mut tokens = with_storage_at_block(
+            &self.eth_api,
+            BlockNumberOrTag::Latest.into(),
+            || -> Result<_, TokenApiError> {
+                let mut tokens = Vec::new();
+                for token_id in 0..token_count()? {
+                    let token = self.read_token(token_id)?;
+                    if params
+                        .filters
+                        .as_ref()
+                        .is_none_or(|filter| token_matches_filter(&token, filter))
+                    {
+                        tokens.push(token);
+                    }
+                }
+                Ok(tokens)
+            },
+        )?;
+
+        sort_items(&mut tokens, params.sort.as_ref(), token_comparator)
+            .map_err(TokenApiError::InvalidSortField)?;
+
+        let (tokens, next_cursor) = paginate(tokens, cursor, page_limit(&params), |t| t.address)
+            .ok_or_else(|| TokenApiError::TokenCursorNotFound(cursor.unwrap_or_default()))?;
+
+        Ok(TokensResponse {
+            next_cursor: next_cursor.map(|address| address.to_string()),
+            tokens,
+        })
+    }
+
+    /// Implementation of the `token_getTokensByAddress` endpoint
+    fn get_tokens_by_address(
+        &self,
+        params: TokensByAddressParams,
+    ) -> Result<TokensByAddressResponse, TokenApiError> {
+        self.index.ensure_synced()?;
+
+        let TokensByAddressParams {
+            address: account,
+            params,
+        } = params;
+        let cursor = params
+            .cursor
+            .as_deref()
+            .map(parse_token_cursor)
+            .transpose()?;
+
+        let mut tokens = with_storage_at_block(
+            &self.eth_api,
+            BlockNumberOrTag::Latest.into(),
+            || -> Result<_, TokenApiError> {
+                let token_count = token_count()?;
+
+                // Tokens that were not created through an indexed `TokenCreated` event, e.g. the
+                // ones allocated at genesis, can have holders without any indexed logs, so they
+                // are always checked.
+                let mut candidates = self
+                    .index
+                    .tokens_of(account)
+                    .into_iter()
+                    .collect::<BTreeSet<_>>();
+                candidates.extend(
+                    (0..token_count)
+                        .map(token_id_to_address)
+                        .filter(|token| self.index.token(*token).is_none()),
+                );
+
+                let mut tokens = Vec::new();
+                for token_address in candidates {
+                    // The index may be ahead of the latest state
+                    let token_id = address_to_token_id_unchecked(token_address);
+                    if token_id >= token_count {
+                        continue;
+                    }
+
+                    let token = self.read_token(token_id)?;
+                    if params
+                        .filters
+                        .as_ref()
+                        .is_some_and(|filter| !token_matches_filter(&token, filter))
+                    {
+                        continue;
+                    }
+
+                    let tip20 = TIP20Token::new(token_id);
+                    let balance = tip20.balance_of(ITIP20::balanceOfCall { account })?;
+
+                    let mut roles = Vec::new();
+                    for role in self.candidate_roles(token_address, account) {
+                        if tip20.has_role_internal(account, role)? {
+                            roles.push(role);
+                        }
+                    }
+
+                    if balance.is_zero() && roles.is_empty() {
+                        continue;
+                    }
+
+                    tokens.push(AccountToken {
+                        balance,
+                        roles,
+                        token,
+                    });
+                }
+                Ok(tokens)
+            },
+        )?;
+
+        sort_items(&mut tokens, params.sort.as_ref(), account_token_comparator)
+            .map_err(TokenApiError::InvalidSortField)?;
+
+        let (tokens, next_cursor) =
+            paginate(tokens, cursor, page_limit(&params), |t| t.token.address)
+                .ok_or_else(|| TokenApiError::TokenCursorNotFound(cursor.unwrap_or_default()))?;
+
+        Ok(TokensByAddressResponse {
+            next_cursor: next_cursor.map(|address| address.to_string()),
+            tokens,
+        })
+    }
+
+    /// Reads the token with the given ID from state.
+    ///
+    /// Must be called within a storage context.
+    fn read_token(&self, token_id: u64) -> Result<Token, TokenApiError> {
+        let address = token_id_to_address(token_id);
+        let indexed = self.index.token(address);
+        let token = TIP20Token::new(token_id);
+
+        Ok(Token {
+            address,
+            created_at: indexed.map(|t| t.created_at).unwrap_or_default(),
+            creator: indexed.map(|t| t.creator).unwrap_or_default(),
+            currency: token.currency()?,
+            decimals: token.decimals()?.into(),
+            name: token.name()?,
+            paused: token.paused()?,
+            quote_token: token.quote_token()?,
+            supply_cap: token.supply_cap()?.saturating_to(),
+            symbol: token.symbol()?,
+            token_id,
+            total_supply: token.total_supply()?.saturating_to(),
+            transfer_policy_id: token.transfer_policy_id()?,
+        })
+    }
+
+    /// Returns the roles that need to be checked for the account on the given token.
+    ///
+    /// This includes all indexed grants and the built-in roles, which may have been granted
+    /// without an indexed event, e.g. at genesis.
+    fn candidate_roles(&self, token: Address, account: Address) -> BTreeSet<B256> {
+        let mut roles = self
+            .index
+            .roles_of(token, account)
+            .into_iter()
+            .collect::<BTreeSet<_>>();
+        roles.extend([
+            DEFAULT_ADMIN_ROLE,
+            *ISSUER_ROLE,
+            *PAUSE_ROLE,
+            *UNPAUSE_ROLE,
+            *BURN_BLOCKED_ROLE,
+            *FORCE_TRANSFER_ROLE,
+            *FREEZE_ROLE,
+        ]);
+        if address_to_token_id_unchecked(token) == 0 {
+            roles.extend([*TRANSFER_ROLE, *RECEIVE_WITH_MEMO_ROLE]);
+        }
+        roles
     }
 }
 
 #[async_trait::async_trait]
-impl<EthApi: RpcNodeCore> TempoTokenApiServer for TempoToken<EthApi> {
+impl<
+    EthApi: RpcNodeCore<Evm = TempoEvmConfig, Primitives: NodePrimitives<BlockHeader = TempoHeader>>
+        + SpawnBlocking,
+> TempoTokenApiServer for TempoToken<EthApi>
+{
+    /// Returns role changes based on pagination parameters.
+    ///
+    /// ## Cursor
+    /// The cursor for this method is the **Log Position** (`<block number>:<log index>`).
+    /// - When provided in the request, returns role changes starting at the given position
+    /// - Returns `next_cursor` in the response containing the first role change of the next page
     async fn role_history(
         &self,
-        _params: PaginationParams<RoleHistoryFilters>,
+        params: PaginationParams<RoleHistoryFilters>,
     ) -> RpcResult<RoleHistoryResponse> {
-        Err(internal_rpc_err("unimplemented"))
+        Self::get_role_history(self, params)
+            .map_err(EthApiError::from)
+            .map_err(EthApi::Error::from_eth_err)
+            .map_err(Into::into)
     }
 
-    async fn tokens(&self, _params: PaginationParams<TokensFilters>) -> RpcResult<TokensResponse> {
-        Err(internal_rpc_err("unimplemented"))
+    /// Returns tokens based on pagination parameters.
+    ///
+    /// ## Cursor
+    /// The cursor for this method is the **Token Address**.
+    /// - When provided in the request, returns tokens starting at the given token
+    /// - Returns `next_cursor` in the response containing the first token of the next page
+    async fn tokens(&self, params: PaginationParams<TokensFilters>) -> RpcResult<TokensResponse> {
+        let this = self.clone();
+        self.eth_api
+            .spawn_blocking_io(move |_| {
+                Self::get_tokens(&this, params)
+                    .map_err(EthApiError::from)
+                    .map_err(EthApi::Error::from_eth_err)
+            })
+            .await
+            .map_err(Into::into)
     }
 
+    /// Returns the tokens an account holds or has roles for based on pagination parameters.
+    ///
+    /// ## Cursor
+    /// The cursor for this method is the **Token Address**.
+    /// - When provided in the request, returns tokens starting at the given token
+    /// - Returns `next_cursor` in the response containing the first token of the next page
     async fn tokens_by_address(
         &self,
-        _params: TokensByAddressParams,
+        params: TokensByAddressParams,
     ) -> RpcResult<TokensByAddressResponse> {
-        Err(internal_rpc_err("unimplemented"))
+        let this = self.clone();
+        self.eth_api
+            .spawn_blocking_io(move |_| {
+                Self::get_tokens_by_address(&this, params)
+                    .map_err(EthApiError::from)
+                    .map_err(EthApi::Error::from_eth_err)
+            })
+            .await
+            .map_err(Into::into)
     }
 }
 
@@ -82,3 +392,187 @@ impl<EthApi: RpcNodeCore> TempoToken<EthApi> {
         self.eth_api.provider()
     }
 }
+
+/// Returns the number of tokens created through the factory.
+///
+/// Must be called within a storage context.
+fn token_count() -> Result<u64, TokenApiError> {
+    Ok(TIP20Factory::new().token_id_counter()?.saturating_to())
+}
+
+/// Checks if a token matches the given filters
+fn token_matches_filter(token: &Token, filter: &TokensFilters) -> bool {
+    if filter
+        .currency
+        .as_ref()
+        .is_some_and(|currency| *currency != token.currency)
+    {
+        return false;
+    }
+
+    if filter
+        .creator
+        .is_some_and(|creator| creator != token.creator)
+    {
+        return false;
+    }
+
+    if filter
+        .created_at
+        .as_ref()
+        .is_some_and(|range| !range.in_range(token.created_at))
+    {
+        return false;
+    }
+
+    if filter
+        .name
+        .as_ref()
+        .is_some_and(|name| !name.eq_ignore_ascii_case(&token.name))
+    {
+        return false;
+    }
+
+    if filter.paused.is_some_and(|paused| paused != token.paused) {
+        return false;
+    }
+
+    if filter
+        .quote_token
+        .is_some_and(|quote_token| quote_token != token.quote_token)
+    {
+        return false;
+    }
+
+    if filter
+        .supply_cap
+        .as_ref()
+        .is_some_and(|range| !range.in_range(token.supply_cap))
+    {
+        return false;
+    }
+
+    if filter
+        .symbol
+        .as_ref()
+        .is_some_and(|symbol| *symbol != token.symbol)
+    {
+        return false;
+    }
+
+    if filter
+        .total_supply
+        .as_ref()
+        .is_some_and(|range| !range.in_range(token.total_supply))
+    {
+        return false;
+    }
+
+    true
+}
+
+/// Checks if a role change matches the given filters
+fn role_change_matches_filter(change: &RoleChange, filter: &RoleHistoryFilters) -> bool {
+    if filter
+        .account
+        .is_some_and(|account| account != change.account)
+    {
+        return false;
+    }
+
+    if filter
+        .block_number
+        .as_ref()
+        .is_some_and(|range| !range.in_range(change.block_number))
+    {
+        return false;
+    }
+
+    if filter
+        .granted
+        .is_some_and(|granted| granted != change.granted)
+    {
+        return false;
+    }
+
+    if filter.role.is_some_and(|role| role != change.role) {
+        return false;
+    }
+
+    if filter.sender.is_some_and(|sender| sender != change.sender) {
+        return false;
+    }
+
+    if filter
+        .timestamp
+        .as_ref()
+        .is_some_and(|range| !range.in_range(change.timestamp))
+    {
+        return false;
+    }
+
+    if filter.token.is_some_and(|token| token != change.token) {
+        return false;
+    }
+
+    true
+}
+
+/// Returns the comparator for sorting role changes by the given field.
+fn role_change_comparator(field: &str) -> Option<Comparator<IndexedRoleChange>> {
+    let compare: fn(&IndexedRoleChange, &IndexedRoleChange) -> std::cmp::Ordering = match field {
+        "blockNumber" => |a, b| a.position().cmp(&b.position()),
+        "timestamp" => {
+            |a, b| (a.change.timestamp, a.position()).cmp(&(b.change.timestamp, b.position()))
+        }
+        _ => return None,
+    };
+    Some(Box::new(compare))
+}
+
+/// Returns the comparator for sorting tokens by the given field.
+fn token_comparator(field: &str) -> Option<Comparator<Token>> {
+    let compare: fn(&Token, &Token) -> std::cmp::Ordering = match field {
+        "tokenId" => |a, b| a.token_id.cmp(&b.token_id),
+        "createdAt" => |a, b| a.created_at.cmp(&b.created_at),
+        "name" => |a, b| a.name.cmp(&b.name),
+        "symbol" => |a, b| a.symbol.cmp(&b.symbol),
+        "supplyCap" => |a, b| a.supply_cap.cmp(&b.supply_cap),
+        "totalSupply" => |a, b| a.total_supply.cmp(&b.total_supply),
+        _ => return None,
+    };
+    Some(Box::new(compare))
+}
+
+/// Returns the comparator for sorting account tokens by the given field.
+///
+/// Supports sorting by `balance` in addition to all token fields.
+fn account_token_comparator(field: &str) -> Option<Comparator<AccountToken>> {
+    if field == "balance" {
+        return Some(Box::new(|a: &AccountToken, b: &AccountToken| {
+            a.balance.cmp(&b.balance)
+        }));
+    }
+
+    let compare = token_comparator(field)?;
+    Some(Box::new(move |a: &AccountToken, b: &AccountToken| {
+        compare(&a.token, &b.token)
+    }))
+}
+
+/// Parses a `<block number>:<log index>` cursor string into a role change position
+fn parse_role_change_cursor(cursor: &str) -> Result<(u64, u64), TokenApiError> {
+    cursor
+        .split_once(':')
+        .and_then(|(block_number, log_index)| {
+            Some((block_number.parse().ok()?, log_index.parse().ok()?))
+        })
+        .ok_or_else(|| TokenApiError::InvalidRoleChangeCursor(cursor.to_string()))
+}
+
+/// Parses a cursor string into a token address
+fn parse_token_cursor(cursor: &str) -> Result<Address, TokenApiError> {
+    cursor
+        .parse::<Address>()
+        .map_err(|_| TokenApiError::InvalidTokenCursor(cursor.to_string()))
+}
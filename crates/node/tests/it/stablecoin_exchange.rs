@@ -1,7 +1,10 @@
 use alloy::{
-    primitives::U256, providers::ProviderBuilder, signers::local::MnemonicBuilder,
+    primitives::U256,
+    providers::{Provider, ProviderBuilder},
+    signers::local::MnemonicBuilder,
     sol_types::SolError,
 };
+use alloy_eips::BlockNumberOrTag;
 use tempo_contracts::precompiles::{
     IStablecoinExchange,
     ITIP20::{self, ITIP20Instance},
@@ -803,3 +806,84 @@ async fn test_place_flip_rejects_order_below_dust_limit() -> eyre::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_place_with_expiry() -> eyre::Result<()> {
+    reth_tracing::init_test_tracing();
+
+    // Setup node
+    let setup = TestNodeBuilder::new()
+        .vivace_activated()
+        .build_http_only()
+        .await?;
+    let http_url = setup.http_url;
+
+    let wallet = MnemonicBuilder::from_phrase(crate::utils::TEST_MNEMONIC).build()?;
+    let caller = wallet.address();
+    let provider = ProviderBuilder::new()
+        .wallet(wallet)
+        .connect_http(http_url.clone());
+
+    let base = setup_test_token(provider.clone(), caller).await?;
+    let quote = ITIP20Instance::new(token_id_to_address(0), provider.clone());
+    let exchange = IStablecoinExchange::new(STABLECOIN_EXCHANGE_ADDRESS, provider.clone());
+
+    let mut pending = vec![
+        quote
+            .mint(caller, U256::from(1000000000000u128))
+            .send()
+            .await?,
+        quote
+            .approve(STABLECOIN_EXCHANGE_ADDRESS, U256::MAX)
+            .send()
+            .await?,
+    ];
+    await_receipts(&mut pending).await?;
+
+    let latest = provider
+        .get_block_by_number(BlockNumberOrTag::Latest)
+        .await?
+        .expect("Could not get latest block");
+    let expires_at = latest.header.timestamp + 3600;
+
+    let order_amount = MIN_ORDER_AMOUNT;
+    let tick = 20;
+
+    let call = exchange.placeWithExpiry(*base.address(), order_amount, true, tick, expires_at);
+    let order_id = call.call().await?;
+    call.send().await?.get_receipt().await?;
+
+    // `getOrder` is unchanged, the expiry is exposed by its own view
+    let order = exchange.getOrder(order_id).call().await?;
+    assert_eq!(order.maker, caller);
+    assert!(order.isBid);
+    assert_eq!(order.tick, tick);
+    assert_eq!(order.remaining, order_amount);
+    assert_eq!(exchange.getOrderExpiry(order_id).call().await?, expires_at);
+
+    // Orders placed without an expiry never expire
+    let call = exchange.place(*base.address(), order_amount, true, tick);
+    let order_id = call.call().await?;
+    call.send().await?.get_receipt().await?;
+    assert_eq!(exchange.getOrderExpiry(order_id).call().await?, 0);
+
+    // Expiries must be in the future
+    let err = exchange
+        .placeWithExpiry(
+            *base.address(),
+            order_amount,
+            true,
+            tick,
+            latest.header.timestamp - 1,
+        )
+        .call()
+        .await
+        .expect_err("Expected error");
+    let expected_selector = format!(
+        "0x{}",
+        alloy::hex::encode(IStablecoinExchange::InvalidExpiry::SELECTOR)
+    );
+    assert!(err.to_string().contains(&expected_selector));
+
+    Ok(())
+}
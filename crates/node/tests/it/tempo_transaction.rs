@@ -252,6 +252,7 @@ fn create_signed_key_authorization(
                 .map(|_| TokenLimit {
                     token: Address::ZERO,
                     limit: U256::ZERO,
+                    period: None,
                 })
                 .collect(),
         )
@@ -263,6 +264,7 @@ fn create_signed_key_authorization(
         key_id: Address::random(), // Random key being authorized
         expiry: None,              // Never expires
         limits,
+        allowed_calls: None,
     };
 
     // Sign the key authorization
@@ -590,6 +592,7 @@ fn create_key_authorization(
         key_id: access_key_addr,
         expiry,
         limits: spending_limits,
+        allowed_calls: None,
     };
 
     // Root key signs the authorization
@@ -693,6 +696,7 @@ fn create_default_token_limit() -> Vec<tempo_primitives::transaction::TokenLimit
     vec![TokenLimit {
         token: DEFAULT_FEE_TOKEN_POST_ALLEGRETTO,
         limit: U256::from(100u64) * U256::from(10).pow(U256::from(18)),
+        period: None,
     }]
 }
 
@@ -3277,6 +3281,7 @@ async fn test_aa_access_key() -> eyre::Result<()> {
     let spending_limits = vec![TokenLimit {
         token: DEFAULT_FEE_TOKEN_POST_ALLEGRETTO,
         limit: spending_limit_amount,
+        period: None,
     }];
 
     println!("\nCreating key authorization:");
@@ -3294,6 +3299,7 @@ async fn test_aa_access_key() -> eyre::Result<()> {
         key_id: access_key_addr,
         expiry: None, // Never expires
         limits: Some(spending_limits.clone()),
+        allowed_calls: None,
     }
     .signature_hash();
 
@@ -3307,6 +3313,7 @@ async fn test_aa_access_key() -> eyre::Result<()> {
         key_id: access_key_addr, // Address derived from P256 public key
         expiry: None,            // Never expires
         limits: Some(spending_limits),
+        allowed_calls: None,
     }
     .into_signed(PrimitiveSignature::Secp256k1(root_auth_signature));
 
@@ -3751,6 +3758,7 @@ async fn test_aa_keychain_negative_cases() -> eyre::Result<()> {
         Some(vec![TokenLimit {
             token: DEFAULT_FEE_TOKEN_POST_ALLEGRETTO,
             limit: U256::from(10u64) * U256::from(10).pow(U256::from(18)),
+            period: None,
         }]),
     )?;
 
@@ -3875,6 +3883,7 @@ async fn test_aa_keychain_negative_cases() -> eyre::Result<()> {
         Some(vec![TokenLimit {
             token: DEFAULT_FEE_TOKEN_POST_ALLEGRETTO,
             limit: U256::from(10u64) * U256::from(10).pow(U256::from(18)),
+            period: None,
         }]),
     )?;
 
@@ -4029,6 +4038,7 @@ async fn test_transaction_key_authorization_and_spending_limits() -> eyre::Resul
         Some(vec![TokenLimit {
             token: DEFAULT_FEE_TOKEN_POST_ALLEGRETTO,
             limit: spending_limit,
+            period: None,
         }]),
     )?;
 
@@ -4937,6 +4947,7 @@ async fn test_aa_keychain_rpc_validation() -> eyre::Result<()> {
     let spending_limits = vec![TokenLimit {
         token: DEFAULT_FEE_TOKEN_POST_ALLEGRETTO,
         limit: U256::from(10u64) * U256::from(10).pow(U256::from(18)), // 10 tokens
+        period: None,
     }];
 
     let mock_p256_sig =
@@ -5180,6 +5191,7 @@ async fn test_aa_keychain_rpc_validation() -> eyre::Result<()> {
         key_id: addr_3,
         expiry: None, // Never expires
         limits: Some(spending_limits.clone()),
+        allowed_calls: None,
     }
     .signature_hash();
 
@@ -5196,6 +5208,7 @@ async fn test_aa_keychain_rpc_validation() -> eyre::Result<()> {
         key_id: addr_3,
         expiry: None, // Never expires
         limits: Some(spending_limits.clone()),
+        allowed_calls: None,
     }
     .into_signed(PrimitiveSignature::P256(P256SignatureWithPreHash {
         r: B256::from_slice(&wrong_sig_bytes[0..32]),
@@ -5385,6 +5398,7 @@ async fn test_aa_key_authorization_chain_id_validation() -> eyre::Result<()> {
     let spending_limits = vec![TokenLimit {
         token: DEFAULT_FEE_TOKEN_POST_ALLEGRETTO,
         limit: U256::from(10u64) * U256::from(10).pow(U256::from(18)),
+        period: None,
     }];
 
     // Test 1: Wrong chain_id should be rejected
@@ -1,13 +1,15 @@
 use alloy::{
     primitives::{Address, FixedBytes, U256},
-    providers::ProviderBuilder,
+    providers::{Provider, ProviderBuilder},
     signers::local::MnemonicBuilder,
     sol_types::SolEvent,
 };
+use alloy_eips::Decodable2718;
 use futures::future::try_join_all;
 use tempo_chainspec::spec::TEMPO_BASE_FEE;
 use tempo_contracts::precompiles::{ITIP20, ITIP403Registry, TIP20Error};
 use tempo_precompiles::TIP403_REGISTRY_ADDRESS;
+use tempo_primitives::TempoTxEnvelope;
 
 use crate::utils::{
     TestNodeBuilder, await_receipts, setup_test_token, setup_test_token_pre_allegretto,
@@ -401,6 +403,71 @@ async fn test_tip20_transfer_with_memo() -> eyre::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_tip20_transfer_batch() -> eyre::Result<()> {
+    reth_tracing::init_test_tracing();
+
+    let setup = TestNodeBuilder::new()
+        .vivace_activated()
+        .build_http_only()
+        .await?;
+    let http_url = setup.http_url;
+
+    let wallet = MnemonicBuilder::from_phrase(crate::utils::TEST_MNEMONIC).build()?;
+    let caller = wallet.address();
+    let provider = ProviderBuilder::new().wallet(wallet).connect_http(http_url);
+
+    let token = setup_test_token(provider.clone(), caller).await?;
+
+    let recipients = (0..100).map(|_| Address::random()).collect::<Vec<_>>();
+    let amount = U256::from(1u32);
+    let total = amount * U256::from(recipients.len());
+
+    token
+        .mint(caller, total)
+        .gas_price(TEMPO_BASE_FEE as u128)
+        .gas(300_000)
+        .send()
+        .await?
+        .get_receipt()
+        .await?;
+
+    // Storage accesses are metered as usual, the batch only saves the per-call overhead
+    let single_gas = token
+        .transferWithMemo(Address::random(), amount, FixedBytes::ZERO)
+        .estimate_gas()
+        .await?;
+
+    let receipt = token
+        .transferBatch(
+            recipients.clone(),
+            vec![amount; recipients.len()],
+            vec![FixedBytes::ZERO; recipients.len()],
+        )
+        .gas_price(TEMPO_BASE_FEE as u128)
+        .gas(1 << 24)
+        .send()
+        .await?
+        .get_receipt()
+        .await?;
+    assert!(receipt.status());
+    assert!(receipt.gas_used < single_gas * recipients.len() as u64);
+
+    // The batch goes to a TIP20 token, so it is classified as a payment
+    let raw = provider
+        .get_raw_transaction_by_hash(receipt.transaction_hash)
+        .await?
+        .expect("transaction exists");
+    assert!(TempoTxEnvelope::decode_2718_exact(&raw[..])?.is_payment());
+
+    for recipient in recipients {
+        assert_eq!(token.balanceOf(recipient).call().await?, amount);
+    }
+    assert_eq!(token.balanceOf(caller).call().await?, U256::ZERO);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_tip20_blacklist() -> eyre::Result<()> {
     reth_tracing::init_test_tracing();
@@ -762,7 +829,12 @@ async fn test_tip20_rewards() -> eyre::Result<()> {
     assert_eq!(bob_balance_after, U256::ZERO);
     assert_eq!(contract_balance, reward_amount);
 
-    bob_token.claimRewards().send().await?.get_receipt().await?;
+    bob_token
+        .claimRewards_0()
+        .send()
+        .await?
+        .get_receipt()
+        .await?;
     let bob_balance_after_claim = token.balanceOf(bob).call().await?;
     assert_eq!(bob_balance_after_claim, reward_amount);
 
@@ -7,6 +7,7 @@ use alloy::{
         SignerSync,
         local::{MnemonicBuilder, PrivateKeySigner},
     },
+    sol_types::SolCall,
 };
 use alloy_eips::{BlockId, Encodable2718};
 use alloy_network::{AnyReceiptEnvelope, EthereumWallet, TxSignerSync};
@@ -17,6 +18,7 @@ use tempo_contracts::precompiles::{
     IFeeManager, ITIP20,
     ITIPFeeAMM::{self},
 };
+use tempo_node::rpc::{TempoTransactionRequest, fee::FeeEstimate};
 use tempo_precompiles::{
     DEFAULT_FEE_TOKEN_PRE_ALLEGRETTO, PATH_USD_ADDRESS, TIP_FEE_MANAGER_ADDRESS,
     tip20::token_id_to_address,
@@ -401,3 +403,57 @@ async fn test_fee_payer_tx() -> eyre::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_estimate_fee() -> eyre::Result<()> {
+    reth_tracing::init_test_tracing();
+
+    let setup = TestNodeBuilder::new()
+        .allegro_moderato_activated()
+        .build_http_only()
+        .await?;
+    let http_url = setup.http_url;
+
+    let wallet = MnemonicBuilder::from_phrase(crate::utils::TEST_MNEMONIC).build()?;
+    let user_address = wallet.address();
+    let provider = ProviderBuilder::new().wallet(wallet).connect_http(http_url);
+
+    let fee_manager = IFeeManager::new(TIP_FEE_MANAGER_ADDRESS, provider.clone());
+    let user_token = fee_manager.userTokens(user_address).call().await?;
+
+    let max_fee_per_gas = provider.get_gas_price().await? * 2;
+    let request = TempoTransactionRequest {
+        inner: TransactionRequest::default()
+            .from(user_address)
+            .to(user_token)
+            .input(
+                ITIP20::transferCall {
+                    to: Address::random(),
+                    amount: U256::from(1),
+                }
+                .abi_encode()
+                .into(),
+            )
+            .max_fee_per_gas(max_fee_per_gas),
+        ..Default::default()
+    };
+
+    let estimate: FeeEstimate = provider
+        .raw_request("tempo_estimateFee".into(), (request, None::<BlockId>))
+        .await?;
+
+    assert!(!estimate.gas.is_zero());
+    assert_eq!(estimate.fee_token, user_token);
+    assert_eq!(
+        estimate.fee,
+        calc_gas_balance_spending(estimate.gas.to(), max_fee_per_gas)
+    );
+    if estimate.validator_token == estimate.fee_token {
+        assert_eq!(estimate.validator_fee, estimate.fee);
+    } else {
+        assert!(estimate.validator_fee < estimate.fee);
+    }
+    assert!(estimate.has_enough_liquidity.is_some());
+
+    Ok(())
+}
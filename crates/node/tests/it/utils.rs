@@ -168,6 +168,7 @@ pub(crate) struct TestNodeBuilder {
     moderato_time: Option<u64>,
     allegretto_time: Option<u64>,
     allegro_moderato_time: Option<u64>,
+    vivace_time: Option<u64>,
     node_count: usize,
     is_dev: bool,
     external_rpc: Option<Url>,
@@ -185,6 +186,7 @@ impl TestNodeBuilder {
             allegretto_time: None,
             moderato_time: None,
             allegro_moderato_time: None,
+            vivace_time: None,
         }
     }
 
@@ -230,6 +232,12 @@ impl TestNodeBuilder {
         self
     }
 
+    /// Set Vivace hardfork activation time
+    pub(crate) fn with_vivace_time(mut self, time: u64) -> Self {
+        self.vivace_time = Some(time);
+        self
+    }
+
     /// Set Moderato hardfork activation time to 0.
     pub(crate) fn moderato_activated(self) -> Self {
         self.with_moderato_time(0)
@@ -245,6 +253,11 @@ impl TestNodeBuilder {
         self.allegretto_activated().with_allegro_moderato_time(0)
     }
 
+    /// Set Vivace hardfork activation time to 0
+    pub(crate) fn vivace_activated(self) -> Self {
+        self.allegro_moderato_activated().with_vivace_time(0)
+    }
+
     /// Build a single node with direct access (NodeHelperType)
     pub(crate) async fn build_with_node_access(self) -> eyre::Result<SingleNodeSetup> {
         if self.node_count != 1 {
@@ -373,6 +386,10 @@ impl TestNodeBuilder {
             genesis["config"]["allegroModeratoTime"] = serde_json::json!(allegro_moderato_time);
         }
 
+        if let Some(vivace_time) = &self.vivace_time {
+            genesis["config"]["vivaceTime"] = serde_json::json!(vivace_time);
+        }
+
         Ok(TempoChainSpec::from_genesis(serde_json::from_value(
             genesis,
         )?))
@@ -1,5 +1,7 @@
 use super::{AccountKeychain, IAccountKeychain};
-use crate::{Precompile, fill_precompile_output, input_cost, mutate_void, unknown_selector, view};
+use crate::{
+    Precompile, fill_precompile_output, input_cost, mutate, mutate_void, unknown_selector, view,
+};
 use alloy::{primitives::Address, sol_types::SolCall};
 use revm::precompile::{PrecompileError, PrecompileResult};
 
@@ -26,6 +28,21 @@ impl Precompile for AccountKeychain {
                 )
             }
 
+            IAccountKeychain::authorizeKeyWithPeriodicLimitsCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                mutate_void::<IAccountKeychain::authorizeKeyWithPeriodicLimitsCall>(
+                    calldata,
+                    msg_sender,
+                    |sender, call| self.authorize_key_with_periodic_limits(sender, call),
+                )
+            }
+
             IAccountKeychain::revokeKeyCall::SELECTOR => {
                 mutate_void::<IAccountKeychain::revokeKeyCall>(
                     calldata,
@@ -42,6 +59,64 @@ impl Precompile for AccountKeychain {
                 )
             }
 
+            IAccountKeychain::setAllowedCallsCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                mutate_void::<IAccountKeychain::setAllowedCallsCall>(
+                    calldata,
+                    msg_sender,
+                    |sender, call| self.set_allowed_calls(sender, call),
+                )
+            }
+
+            IAccountKeychain::removeAllowedCallsCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                mutate_void::<IAccountKeychain::removeAllowedCallsCall>(
+                    calldata,
+                    msg_sender,
+                    |sender, call| self.remove_allowed_calls(sender, call),
+                )
+            }
+
+            IAccountKeychain::createMultisigCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                mutate::<IAccountKeychain::createMultisigCall>(calldata, msg_sender, |_, call| {
+                    self.create_multisig(call)
+                })
+            }
+
+            IAccountKeychain::setMultisigCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                mutate_void::<IAccountKeychain::setMultisigCall>(
+                    calldata,
+                    msg_sender,
+                    |sender, call| self.set_multisig(sender, call),
+                )
+            }
+
             IAccountKeychain::getKeyCall::SELECTOR => {
                 view::<IAccountKeychain::getKeyCall>(calldata, |call| self.get_key(call))
             }
@@ -52,6 +127,43 @@ impl Precompile for AccountKeychain {
                 })
             }
 
+            IAccountKeychain::getSpendingLimitCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                view::<IAccountKeychain::getSpendingLimitCall>(calldata, |call| {
+                    self.get_spending_limit(call)
+                })
+            }
+
+            IAccountKeychain::isCallAllowedCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                view::<IAccountKeychain::isCallAllowedCall>(calldata, |call| {
+                    self.is_call_allowed(call)
+                })
+            }
+
+            IAccountKeychain::getMultisigCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                view::<IAccountKeychain::getMultisigCall>(calldata, |call| self.get_multisig(call))
+            }
+
             IAccountKeychain::getTransactionKeyCall::SELECTOR => {
                 view::<IAccountKeychain::getTransactionKeyCall>(calldata, |call| {
                     self.get_transaction_key(call, msg_sender)
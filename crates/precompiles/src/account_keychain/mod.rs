@@ -4,8 +4,11 @@ use tempo_contracts::precompiles::{AccountKeychainError, AccountKeychainEvent};
 pub use tempo_contracts::precompiles::{
     IAccountKeychain,
     IAccountKeychain::{
-        KeyInfo, SignatureType, TokenLimit, authorizeKeyCall, getKeyCall, getRemainingLimitCall,
-        getTransactionKeyCall, revokeKeyCall, updateSpendingLimitCall,
+        CallScope, KeyInfo, Multisig, PeriodicTokenLimit, SignatureType, SpendingLimit, TokenLimit,
+        authorizeKeyCall, authorizeKeyWithPeriodicLimitsCall, createMultisigCall, getKeyCall,
+        getMultisigCall, getRemainingLimitCall, getSpendingLimitCall, getTransactionKeyCall,
+        isCallAllowedCall, removeAllowedCallsCall, revokeKeyCall, setAllowedCallsCall,
+        setMultisigCall, updateSpendingLimitCall,
     },
 };
 
@@ -14,8 +17,12 @@ use crate::{
     error::Result,
     storage::{Handler, Mapping},
 };
-use alloy::primitives::{Address, B256, U256};
+use alloy::{
+    primitives::{Address, B256, FixedBytes, TxKind, U256, keccak256},
+    sol_types::SolValue,
+};
 use tempo_precompiles_macros::{Storable, contract};
+use tempo_primitives::transaction::{call_selector, tt_signature::MAX_MULTISIG_SIGNATURES};
 
 /// Key information stored in the precompile
 ///
@@ -24,6 +31,7 @@ use tempo_precompiles_macros::{Storable, contract};
 /// - bytes 1-8: expiry (u64, little-endian)
 /// - byte 9: enforce_limits (bool)
 /// - byte 10: is_revoked (bool)
+/// - byte 11: restrict_calls (bool)
 #[derive(Debug, Clone, Default, PartialEq, Eq, Storable)]
 pub struct AuthorizedKey {
     /// Signature type: 0 = secp256k1, 1 = P256, 2 = WebAuthn
@@ -35,6 +43,8 @@ pub struct AuthorizedKey {
     /// Whether this key has been revoked. Once revoked, a key cannot be re-authorized
     /// with the same key_id. This prevents replay attacks.
     pub is_revoked: bool,
+    /// Whether this key can only make the calls in its call scope
+    pub restrict_calls: bool,
 }
 
 // TODO(rusowsky): remove this and create a read-only wrapper that is callable from read-only ctx with db access
@@ -52,6 +62,40 @@ impl AuthorizedKey {
     }
 }
 
+/// Periodic spending limit of a key for a token
+///
+/// Only stored for limits that reset periodically. The amount left in the current period is
+/// tracked in `spending_limits`, like lifetime limits.
+///
+/// Storage layout:
+/// - slot 0: limit (U256)
+/// - slot 1: period (u64), period_end (u64)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Storable)]
+pub struct SpendingPeriod {
+    /// Amount that can be spent per period
+    pub limit: U256,
+    /// Length of the period in seconds, 0 for a lifetime limit
+    pub period: u64,
+    /// Block timestamp at which the current period ends
+    pub period_end: u64,
+}
+
+impl SpendingPeriod {
+    /// Returns the end of the period containing `timestamp`, if the current period has elapsed.
+    ///
+    /// Periods are consecutive, so elapsed periods in which nothing was spent are skipped.
+    pub fn next_period_end(&self, timestamp: u64) -> Option<u64> {
+        if self.period == 0 || timestamp < self.period_end {
+            return None;
+        }
+        let elapsed_periods = (timestamp - self.period_end) / self.period + 1;
+        Some(
+            self.period_end
+                .saturating_add(elapsed_periods.saturating_mul(self.period)),
+        )
+    }
+}
+
 /// Account Keychain contract for managing authorized keys
 #[contract(addr = ACCOUNT_KEYCHAIN_ADDRESS)]
 pub struct AccountKeychain {
@@ -60,6 +104,14 @@ pub struct AccountKeychain {
     // spendingLimits[(account, keyId)][token] -> amount
     // Using a hash of account and keyId as the key to avoid triple nesting
     spending_limits: Mapping<B256, Mapping<Address, U256>>,
+    // spendingPeriods[(account, keyId)][token] -> SpendingPeriod, only set for periodic limits
+    spending_periods: Mapping<B256, Mapping<Address, SpendingPeriod>>,
+    // allowedCalls[(account, keyId)][target][selector] -> bool, only checked for restricted keys
+    allowed_calls: Mapping<B256, Mapping<Address, Mapping<FixedBytes<4>, bool>>>,
+    // multisigSigners[account] -> signers of the account
+    multisig_signers: Mapping<Address, Vec<Address>>,
+    // multisigThresholds[account] -> number of signers required, 0 if the account has no signer set
+    multisig_thresholds: Mapping<Address, u8>,
 
     // WARNING(rusowsky): transient storage slots must always be placed at the very end until the `contract`
     // macro is refactored and has 2 independent layouts (persistent and transient).
@@ -73,7 +125,6 @@ pub struct AccountKeychain {
 impl AccountKeychain {
     /// Create a hash key for spending limits mapping from account and keyId
     fn spending_limit_key(account: Address, key_id: Address) -> B256 {
-        use alloy::primitives::keccak256;
         let mut data = [0u8; 40];
         data[..20].copy_from_slice(account.as_slice());
         data[20..].copy_from_slice(key_id.as_slice());
@@ -88,6 +139,35 @@ impl AccountKeychain {
     /// Authorize a new key for an account
     /// This can only be called by the account itself (using main key)
     pub fn authorize_key(&mut self, msg_sender: Address, call: authorizeKeyCall) -> Result<()> {
+        let limits = call
+            .limits
+            .into_iter()
+            .map(|limit| PeriodicTokenLimit {
+                token: limit.token,
+                amount: limit.amount,
+                period: 0,
+            })
+            .collect();
+
+        self.authorize_key_with_periodic_limits(
+            msg_sender,
+            authorizeKeyWithPeriodicLimitsCall {
+                keyId: call.keyId,
+                signatureType: call.signatureType,
+                expiry: call.expiry,
+                enforceLimits: call.enforceLimits,
+                limits,
+            },
+        )
+    }
+
+    /// Authorize a new key for an account, with spending limits that may reset periodically
+    /// This can only be called by the account itself (using main key)
+    pub fn authorize_key_with_periodic_limits(
+        &mut self,
+        msg_sender: Address,
+        call: authorizeKeyWithPeriodicLimitsCall,
+    ) -> Result<()> {
         // Check that the transaction key for this transaction is zero (main key)
         let transaction_key = self.transaction_key.t_read()?;
 
@@ -126,6 +206,7 @@ impl AccountKeychain {
             expiry: call.expiry,
             enforce_limits: call.enforceLimits,
             is_revoked: false,
+            restrict_calls: false,
         };
 
         self.keys.at(msg_sender).at(call.keyId).write(new_key)?;
@@ -133,11 +214,24 @@ impl AccountKeychain {
         // Set initial spending limits (only if enforce_limits is true)
         if call.enforceLimits {
             let limit_key = Self::spending_limit_key(msg_sender, call.keyId);
+            let current_timestamp = self.storage.timestamp().saturating_to::<u64>();
             for limit in call.limits {
                 self.spending_limits
                     .at(limit_key)
                     .at(limit.token)
                     .write(limit.amount)?;
+
+                // The first period starts when the key is authorized
+                if limit.period > 0 {
+                    self.spending_periods
+                        .at(limit_key)
+                        .at(limit.token)
+                        .write(SpendingPeriod {
+                            limit: limit.amount,
+                            period: limit.period,
+                            period_end: current_timestamp.saturating_add(limit.period),
+                        })?;
+                }
             }
         }
 
@@ -247,6 +341,19 @@ impl AccountKeychain {
             .at(call.token)
             .write(call.newLimit)?;
 
+        // Periodic limits keep their period, which restarts with the new limit
+        if self.storage.spec().is_vivace() {
+            let mut period = self.spending_periods.at(limit_key).at(call.token).read()?;
+            if period.period > 0 {
+                period.limit = call.newLimit;
+                period.period_end = current_timestamp.saturating_add(period.period);
+                self.spending_periods
+                    .at(limit_key)
+                    .at(call.token)
+                    .write(period)?;
+            }
+        }
+
         // Emit event
         if !self.storage.spec().is_allegro_moderato() {
             self.emit_event(AccountKeychainEvent::SpendingLimitUpdated_1(
@@ -269,6 +376,234 @@ impl AccountKeychain {
         }
     }
 
+    /// Restrict a key to a set of calls
+    ///
+    /// The calls are added to the call scope of the key. A key without a call scope can call
+    /// any contract, so restricting it with an empty list prevents it from making any call.
+    pub fn set_allowed_calls(
+        &mut self,
+        msg_sender: Address,
+        call: setAllowedCallsCall,
+    ) -> Result<()> {
+        self.update_call_scope(msg_sender, call.keyId, call.calls, true)
+    }
+
+    /// Remove calls from the call scope of a key
+    ///
+    /// A restricted key stays restricted to its remaining calls. Removing calls from a key without
+    /// a call scope leaves it able to call any contract.
+    pub fn remove_allowed_calls(
+        &mut self,
+        msg_sender: Address,
+        call: removeAllowedCallsCall,
+    ) -> Result<()> {
+        self.update_call_scope(msg_sender, call.keyId, call.calls, false)
+    }
+
+    fn update_call_scope(
+        &mut self,
+        msg_sender: Address,
+        key_id: Address,
+        calls: Vec<CallScope>,
+        allowed: bool,
+    ) -> Result<()> {
+        let transaction_key = self.transaction_key.t_read()?;
+
+        if transaction_key != Address::ZERO {
+            return Err(AccountKeychainError::unauthorized_caller().into());
+        }
+
+        // Verify key exists, hasn't been revoked, and hasn't expired
+        let mut key = self.load_active_key(msg_sender, key_id)?;
+
+        let current_timestamp = self.storage.timestamp().saturating_to::<u64>();
+        if current_timestamp >= key.expiry {
+            return Err(AccountKeychainError::key_expired().into());
+        }
+
+        // Once restricted, the key can only make the calls in its call scope
+        if allowed && !key.restrict_calls {
+            key.restrict_calls = true;
+            self.keys.at(msg_sender).at(key_id).write(key)?;
+        }
+
+        let scope_key = Self::spending_limit_key(msg_sender, key_id);
+        for call in calls {
+            self.allowed_calls
+                .at(scope_key)
+                .at(call.target)
+                .at(call.selector)
+                .write(allowed)?;
+
+            self.emit_event(AccountKeychainEvent::CallScopeUpdated(
+                IAccountKeychain::CallScopeUpdated {
+                    account: msg_sender,
+                    publicKey: key_id,
+                    target: call.target,
+                    selector: call.selector,
+                    allowed,
+                },
+            ))?;
+        }
+
+        Ok(())
+    }
+
+    /// Check whether a key is allowed to call a function on a contract
+    ///
+    /// Keys without a call scope are allowed to make any call.
+    pub fn is_call_allowed(&self, call: isCallAllowedCall) -> Result<bool> {
+        let key = self.keys.at(call.account).at(call.keyId).read()?;
+        if !key.restrict_calls {
+            return Ok(true);
+        }
+
+        let scope_key = Self::spending_limit_key(call.account, call.keyId);
+        self.allowed_calls
+            .at(scope_key)
+            .at(call.target)
+            .at(call.selector)
+            .read()
+    }
+
+    /// Validate that a key is allowed to make a call of a transaction
+    ///
+    /// Keys with a call scope can never create contracts.
+    pub fn validate_call_scope(
+        &self,
+        account: Address,
+        key_id: Address,
+        to: TxKind,
+        input: &[u8],
+    ) -> Result<()> {
+        let allowed = match to {
+            TxKind::Call(target) => self.is_call_allowed(isCallAllowedCall {
+                account,
+                keyId: key_id,
+                target,
+                selector: call_selector(input),
+            })?,
+            TxKind::Create => !self.keys.at(account).at(key_id).read()?.restrict_calls,
+        };
+
+        if !allowed {
+            return Err(AccountKeychainError::call_not_allowed().into());
+        }
+
+        Ok(())
+    }
+
+    /// Create an account controlled by a signer set
+    ///
+    /// The account address is derived from the signer set and salt, so nobody holds a root key
+    /// for it. Transactions for the account must be signed by a threshold of its signers.
+    pub fn create_multisig(&mut self, call: createMultisigCall) -> Result<Address> {
+        Self::validate_signer_set(&call.signers, call.threshold)?;
+
+        let account = Address::from_word(keccak256(
+            (call.signers.clone(), call.threshold, call.salt).abi_encode(),
+        ));
+
+        if self.multisig_thresholds.at(account).read()? != 0 {
+            return Err(AccountKeychainError::multisig_already_exists().into());
+        }
+
+        self.write_multisig(account, call.signers, call.threshold)?;
+
+        Ok(account)
+    }
+
+    /// Set the signer set of an account
+    ///
+    /// This can only be called by the account itself (using main key or a multisig signature).
+    /// An empty signer set with a zero threshold removes the signer set.
+    pub fn set_multisig(&mut self, msg_sender: Address, call: setMultisigCall) -> Result<()> {
+        let transaction_key = self.transaction_key.t_read()?;
+
+        if transaction_key != Address::ZERO {
+            return Err(AccountKeychainError::unauthorized_caller().into());
+        }
+
+        if !(call.signers.is_empty() && call.threshold == 0) {
+            Self::validate_signer_set(&call.signers, call.threshold)?;
+        }
+
+        self.write_multisig(msg_sender, call.signers, call.threshold)
+    }
+
+    /// Get the signer set of an account
+    pub fn get_multisig(&self, call: getMultisigCall) -> Result<Multisig> {
+        Ok(Multisig {
+            signers: self.multisig_signers.at(call.account).read()?,
+            threshold: self.multisig_thresholds.at(call.account).read()?,
+        })
+    }
+
+    /// Validate that the signers of a multisig signature meet the threshold of an account
+    ///
+    /// Every signer must be in the signer set of the account, and each signer is only counted once.
+    pub fn validate_multisig(&self, account: Address, signers: &[Address]) -> Result<()> {
+        let threshold = self.multisig_thresholds.at(account).read()?;
+        if threshold == 0 {
+            return Err(AccountKeychainError::multisig_not_found().into());
+        }
+
+        let signer_set = self.multisig_signers.at(account).read()?;
+        for (i, signer) in signers.iter().enumerate() {
+            if !signer_set.contains(signer) {
+                return Err(AccountKeychainError::multisig_signer_not_found().into());
+            }
+
+            if signers[..i].contains(signer) {
+                return Err(AccountKeychainError::invalid_multisig().into());
+            }
+        }
+
+        if signers.len() < threshold as usize {
+            return Err(AccountKeychainError::multisig_threshold_not_met().into());
+        }
+
+        Ok(())
+    }
+
+    /// Validate a signer set: non-empty, without zero or duplicate signers, and a threshold
+    /// between 1 and the number of signers.
+    fn validate_signer_set(signers: &[Address], threshold: u8) -> Result<()> {
+        if signers.is_empty()
+            || signers.len() > MAX_MULTISIG_SIGNATURES
+            || threshold == 0
+            || threshold as usize > signers.len()
+        {
+            return Err(AccountKeychainError::invalid_multisig().into());
+        }
+
+        for (i, signer) in signers.iter().enumerate() {
+            if signer.is_zero() || signers[..i].contains(signer) {
+                return Err(AccountKeychainError::invalid_multisig().into());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_multisig(
+        &mut self,
+        account: Address,
+        signers: Vec<Address>,
+        threshold: u8,
+    ) -> Result<()> {
+        self.multisig_signers.at(account).write(signers.clone())?;
+        self.multisig_thresholds.at(account).write(threshold)?;
+
+        self.emit_event(AccountKeychainEvent::MultisigUpdated(
+            IAccountKeychain::MultisigUpdated {
+                account,
+                signers,
+                threshold,
+            },
+        ))
+    }
+
     /// Get key information
     pub fn get_key(&self, call: getKeyCall) -> Result<KeyInfo> {
         let key = self.keys.at(call.account).at(call.keyId).read()?;
@@ -302,9 +637,55 @@ impl AccountKeychain {
     }
 
     /// Get remaining spending limit
+    ///
+    /// For periodic limits, this is the amount left in the current period, which is the full
+    /// limit once the last period has elapsed.
     pub fn get_remaining_limit(&self, call: getRemainingLimitCall) -> Result<U256> {
         let limit_key = Self::spending_limit_key(call.account, call.keyId);
-        self.spending_limits.at(limit_key).at(call.token).read()
+        let remaining = self.spending_limits.at(limit_key).at(call.token).read()?;
+
+        if self.storage.spec().is_vivace() {
+            let period = self.spending_periods.at(limit_key).at(call.token).read()?;
+            let current_timestamp = self.storage.timestamp().saturating_to::<u64>();
+            if period.next_period_end(current_timestamp).is_some() {
+                return Ok(period.limit);
+            }
+        }
+
+        Ok(remaining)
+    }
+
+    /// Get the spending limit of a key for a token, including its current period
+    ///
+    /// Only the remaining amount is tracked for lifetime limits, so `limit` equals `remaining`
+    /// and `period` and `periodEnd` are zero.
+    pub fn get_spending_limit(&self, call: getSpendingLimitCall) -> Result<SpendingLimit> {
+        let limit_key = Self::spending_limit_key(call.account, call.keyId);
+        let remaining = self.get_remaining_limit(getRemainingLimitCall {
+            account: call.account,
+            keyId: call.keyId,
+            token: call.token,
+        })?;
+
+        let period = self.spending_periods.at(limit_key).at(call.token).read()?;
+        if period.period == 0 {
+            return Ok(SpendingLimit {
+                limit: remaining,
+                remaining,
+                period: 0,
+                periodEnd: 0,
+            });
+        }
+
+        let current_timestamp = self.storage.timestamp().saturating_to::<u64>();
+        Ok(SpendingLimit {
+            limit: period.limit,
+            remaining,
+            period: period.period,
+            periodEnd: period
+                .next_period_end(current_timestamp)
+                .unwrap_or(period.period_end),
+        })
     }
 
     /// Get the transaction key used in the current transaction
@@ -402,7 +783,11 @@ impl AccountKeychain {
 
         // Check and update spending limit
         let limit_key = Self::spending_limit_key(account, key_id);
-        let remaining = self.spending_limits.at(limit_key).at(token).read()?;
+        let remaining = if self.storage.spec().is_vivace() {
+            self.roll_spending_period(limit_key, token)?
+        } else {
+            self.spending_limits.at(limit_key).at(token).read()?
+        };
 
         if amount > remaining {
             return Err(AccountKeychainError::spending_limit_exceeded().into());
@@ -415,6 +800,29 @@ impl AccountKeychain {
             .write(remaining - amount)
     }
 
+    /// Starts a new spending period if the current one has elapsed, restoring the full limit.
+    ///
+    /// Returns the amount left in the current period.
+    fn roll_spending_period(&mut self, limit_key: B256, token: Address) -> Result<U256> {
+        let remaining = self.spending_limits.at(limit_key).at(token).read()?;
+
+        let mut period = self.spending_periods.at(limit_key).at(token).read()?;
+        let current_timestamp = self.storage.timestamp().saturating_to::<u64>();
+        let Some(period_end) = period.next_period_end(current_timestamp) else {
+            return Ok(remaining);
+        };
+
+        period.period_end = period_end;
+        let limit = period.limit;
+        self.spending_periods
+            .at(limit_key)
+            .at(token)
+            .write(period)?;
+        self.spending_limits.at(limit_key).at(token).write(limit)?;
+
+        Ok(limit)
+    }
+
     /// Authorize a token transfer with access key spending limits
     ///
     /// This method checks if the transaction is using an access key, and if so,
@@ -876,4 +1284,342 @@ mod tests {
             Ok(())
         })
     }
+
+    #[test]
+    fn test_periodic_spending_limit() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        let account = Address::random();
+        let access_key = Address::random();
+        let token = Address::random();
+        let day = 86400;
+
+        StorageCtx::enter(&mut storage, || {
+            let mut keychain = AccountKeychain::new();
+            keychain.initialize()?;
+            StorageCtx.set_timestamp(U256::from(1000));
+
+            keychain.set_transaction_key(Address::ZERO)?;
+            keychain.set_tx_origin(account)?;
+            keychain.authorize_key_with_periodic_limits(
+                account,
+                authorizeKeyWithPeriodicLimitsCall {
+                    keyId: access_key,
+                    signatureType: SignatureType::Secp256k1,
+                    expiry: u64::MAX,
+                    enforceLimits: true,
+                    limits: vec![PeriodicTokenLimit {
+                        token,
+                        amount: U256::from(100),
+                        period: day,
+                    }],
+                },
+            )?;
+
+            keychain.set_transaction_key(access_key)?;
+            keychain.authorize_transfer(account, token, U256::from(60))?;
+            assert!(
+                keychain
+                    .authorize_transfer(account, token, U256::from(60))
+                    .is_err(),
+                "Should not exceed the limit within a period"
+            );
+
+            let get_spending_limit = getSpendingLimitCall {
+                account,
+                keyId: access_key,
+                token,
+            };
+            assert_eq!(
+                keychain.get_spending_limit(get_spending_limit.clone())?,
+                SpendingLimit {
+                    limit: U256::from(100),
+                    remaining: U256::from(40),
+                    period: day,
+                    periodEnd: 1000 + day,
+                }
+            );
+
+            // Skip two periods, the limit is restored in the period containing the timestamp
+            StorageCtx.set_timestamp(U256::from(1000 + 2 * day + 5));
+            assert_eq!(
+                keychain.get_spending_limit(get_spending_limit.clone())?,
+                SpendingLimit {
+                    limit: U256::from(100),
+                    remaining: U256::from(100),
+                    period: day,
+                    periodEnd: 1000 + 3 * day,
+                }
+            );
+
+            keychain.authorize_transfer(account, token, U256::from(100))?;
+            assert_eq!(
+                keychain.get_remaining_limit(getRemainingLimitCall {
+                    account,
+                    keyId: access_key,
+                    token,
+                })?,
+                U256::ZERO
+            );
+            assert!(
+                keychain
+                    .authorize_transfer(account, token, U256::from(1))
+                    .is_err()
+            );
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_lifetime_spending_limit_has_no_period() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        let account = Address::random();
+        let access_key = Address::random();
+        let token = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut keychain = AccountKeychain::new();
+            keychain.initialize()?;
+
+            keychain.set_transaction_key(Address::ZERO)?;
+            keychain.authorize_key(
+                account,
+                authorizeKeyCall {
+                    keyId: access_key,
+                    signatureType: SignatureType::Secp256k1,
+                    expiry: u64::MAX,
+                    enforceLimits: true,
+                    limits: vec![TokenLimit {
+                        token,
+                        amount: U256::from(100),
+                    }],
+                },
+            )?;
+
+            assert_eq!(
+                keychain.get_spending_limit(getSpendingLimitCall {
+                    account,
+                    keyId: access_key,
+                    token,
+                })?,
+                SpendingLimit {
+                    limit: U256::from(100),
+                    remaining: U256::from(100),
+                    period: 0,
+                    periodEnd: 0,
+                }
+            );
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_call_scope() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        let account = Address::random();
+        let access_key = Address::random();
+        let target = Address::random();
+        let selector = FixedBytes::new([0xaa, 0xbb, 0xcc, 0xdd]);
+
+        StorageCtx::enter(&mut storage, || {
+            let mut keychain = AccountKeychain::new();
+            keychain.initialize()?;
+
+            keychain.set_transaction_key(Address::ZERO)?;
+            keychain.authorize_key(
+                account,
+                authorizeKeyCall {
+                    keyId: access_key,
+                    signatureType: SignatureType::Secp256k1,
+                    expiry: u64::MAX,
+                    enforceLimits: false,
+                    limits: vec![],
+                },
+            )?;
+
+            // Keys without a call scope can make any call
+            keychain.validate_call_scope(account, access_key, TxKind::Create, &[])?;
+            keychain.validate_call_scope(
+                account,
+                access_key,
+                TxKind::Call(Address::random()),
+                &[0x01],
+            )?;
+
+            // Removing calls does not restrict a key without a call scope
+            keychain.remove_allowed_calls(
+                account,
+                removeAllowedCallsCall {
+                    keyId: access_key,
+                    calls: vec![CallScope { target, selector }],
+                },
+            )?;
+            keychain.validate_call_scope(account, access_key, TxKind::Create, &[])?;
+
+            keychain.set_allowed_calls(
+                account,
+                setAllowedCallsCall {
+                    keyId: access_key,
+                    calls: vec![CallScope { target, selector }],
+                },
+            )?;
+
+            let input = [selector.as_slice(), &[0u8; 32]].concat();
+            keychain.validate_call_scope(account, access_key, TxKind::Call(target), &input)?;
+
+            for (to, input) in [
+                (TxKind::Create, &input[..]),
+                (TxKind::Call(Address::random()), &input[..]),
+                (TxKind::Call(target), &[0x12, 0x34, 0x56, 0x78][..]),
+                (TxKind::Call(target), &[][..]),
+            ] {
+                let result = keychain.validate_call_scope(account, access_key, to, input);
+                assert!(matches!(
+                    result,
+                    Err(TempoPrecompileError::AccountKeychainError(
+                        AccountKeychainError::CallNotAllowed(_)
+                    ))
+                ));
+            }
+
+            keychain.remove_allowed_calls(
+                account,
+                removeAllowedCallsCall {
+                    keyId: access_key,
+                    calls: vec![CallScope { target, selector }],
+                },
+            )?;
+            assert!(!keychain.is_call_allowed(isCallAllowedCall {
+                account,
+                keyId: access_key,
+                target,
+                selector,
+            })?);
+
+            // Access keys cannot change call scopes
+            keychain.set_transaction_key(access_key)?;
+            let result = keychain.set_allowed_calls(
+                account,
+                setAllowedCallsCall {
+                    keyId: access_key,
+                    calls: vec![CallScope { target, selector }],
+                },
+            );
+            assert_unauthorized_error(result.unwrap_err());
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_multisig() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        let signers = vec![Address::random(), Address::random(), Address::random()];
+
+        StorageCtx::enter(&mut storage, || {
+            let mut keychain = AccountKeychain::new();
+            keychain.initialize()?;
+
+            let create_call = createMultisigCall {
+                signers: signers.clone(),
+                threshold: 2,
+                salt: B256::ZERO,
+            };
+            let account = keychain.create_multisig(create_call.clone())?;
+            assert_eq!(
+                keychain.get_multisig(getMultisigCall { account })?,
+                Multisig {
+                    signers: signers.clone(),
+                    threshold: 2,
+                }
+            );
+
+            // The account is derived from the signer set and salt
+            assert!(matches!(
+                keychain.create_multisig(create_call),
+                Err(TempoPrecompileError::AccountKeychainError(
+                    AccountKeychainError::MultisigAlreadyExists(_)
+                ))
+            ));
+
+            keychain.validate_multisig(account, &signers[..2])?;
+            keychain.validate_multisig(account, &[signers[2], signers[0]])?;
+            assert!(matches!(
+                keychain.validate_multisig(account, &signers[..1]),
+                Err(TempoPrecompileError::AccountKeychainError(
+                    AccountKeychainError::MultisigThresholdNotMet(_)
+                ))
+            ));
+            assert!(matches!(
+                keychain.validate_multisig(account, &[signers[0], Address::random()]),
+                Err(TempoPrecompileError::AccountKeychainError(
+                    AccountKeychainError::MultisigSignerNotFound(_)
+                ))
+            ));
+            assert!(matches!(
+                keychain.validate_multisig(account, &[signers[0], signers[0]]),
+                Err(TempoPrecompileError::AccountKeychainError(
+                    AccountKeychainError::InvalidMultisig(_)
+                ))
+            ));
+            assert!(matches!(
+                keychain.validate_multisig(Address::random(), &signers),
+                Err(TempoPrecompileError::AccountKeychainError(
+                    AccountKeychainError::MultisigNotFound(_)
+                ))
+            ));
+
+            // The account can update its signer set with a multisig signature (main key)
+            keychain.set_transaction_key(Address::ZERO)?;
+            keychain.set_multisig(
+                account,
+                setMultisigCall {
+                    signers: signers[..2].to_vec(),
+                    threshold: 1,
+                },
+            )?;
+            keychain.validate_multisig(account, &signers[1..2])?;
+            assert!(keychain.validate_multisig(account, &signers[2..]).is_err());
+
+            // Invalid signer sets are rejected
+            for (signers, threshold) in [
+                (vec![signers[0]], 2),
+                (vec![signers[0]], 0),
+                (vec![signers[0], signers[0]], 1),
+                (vec![Address::ZERO], 1),
+                (vec![], 1),
+            ] {
+                assert!(matches!(
+                    keychain.set_multisig(account, setMultisigCall { signers, threshold }),
+                    Err(TempoPrecompileError::AccountKeychainError(
+                        AccountKeychainError::InvalidMultisig(_)
+                    ))
+                ));
+            }
+
+            // The signer set can be removed
+            keychain.set_multisig(
+                account,
+                setMultisigCall {
+                    signers: vec![],
+                    threshold: 0,
+                },
+            )?;
+            assert!(keychain.validate_multisig(account, &signers).is_err());
+
+            // Access keys cannot update the signer set
+            keychain.set_transaction_key(Address::random())?;
+            let result = keychain.set_multisig(
+                account,
+                setMultisigCall {
+                    signers: signers.clone(),
+                    threshold: 1,
+                },
+            );
+            assert_unauthorized_error(result.unwrap_err());
+
+            Ok(())
+        })
+    }
 }
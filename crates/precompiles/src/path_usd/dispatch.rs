@@ -179,8 +179,8 @@ impl Precompile for PathUSD {
                     Err(TIP20Error::rewards_disabled().into())
                 })
             }
-            ITIP20::claimRewardsCall::SELECTOR => {
-                mutate::<ITIP20::claimRewardsCall>(calldata, msg_sender, |_, _| {
+            ITIP20::claimRewards_0Call::SELECTOR => {
+                mutate::<ITIP20::claimRewards_0Call>(calldata, msg_sender, |_, _| {
                     Err(TIP20Error::rewards_disabled().into())
                 })
             }
@@ -269,6 +269,69 @@ mod tests {
     fn path_usd_test_selector_coverage_post_allegretto() -> eyre::Result<()> {
         let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Allegretto);
 
+        StorageCtx::enter(&mut storage, || {
+            initialize_path_usd(Address::random())?;
+
+            let mut path_usd = PathUSD::new();
+            let itip20_unsupported =
+                check_selector_coverage(&mut path_usd, ITIP20Calls::SELECTORS, "ITIP20", |s| {
+                    ITIP20Calls::name_by_selector(s)
+                });
+
+            let roles_unsupported = check_selector_coverage(
+                &mut path_usd,
+                IRolesAuthCalls::SELECTORS,
+                "IRolesAuth",
+                IRolesAuthCalls::name_by_selector,
+            );
+
+            // In pre-Vivace, post-Vivace functions should be unsupported
+            let mut unsupported: Vec<&str> = itip20_unsupported
+                .iter()
+                .chain(&roles_unsupported)
+                .map(|(_, name)| *name)
+                .collect();
+            unsupported.sort();
+            assert_eq!(
+                unsupported,
+                vec![
+                    "DOMAIN_SEPARATOR",
+                    "FORCE_TRANSFER_ROLE",
+                    "FREEZE_ROLE",
+                    "adminTimelockDelay",
+                    "authorizationState",
+                    "cancelAction",
+                    "cancelAuthorization",
+                    "claimRewards",
+                    "executeAction",
+                    "forceTransfer",
+                    "freeze",
+                    "isFrozen",
+                    "nonces",
+                    "permit",
+                    "permitWithSignature",
+                    "queueAction",
+                    "queuedActionEta",
+                    "receiveWithAuthorization",
+                    "receiveWithAuthorizationWithMemo",
+                    "rewardTokens",
+                    "setAdminTimelockDelay",
+                    "startTokenReward",
+                    "tokenRewardInfo",
+                    "transferBatch",
+                    "transferWithAuthorization",
+                    "transferWithAuthorizationWithMemo",
+                    "unfreeze",
+                ]
+            );
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn path_usd_test_selector_coverage_post_vivace() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+
         StorageCtx::enter(&mut storage, || {
             initialize_path_usd(Address::random())?;
 
@@ -363,7 +426,7 @@ mod tests {
             let mut token = PathUSD::new();
             token.initialize(sender)?;
 
-            let calldata = ITIP20::claimRewardsCall {}.abi_encode();
+            let calldata = ITIP20::claimRewards_0Call {}.abi_encode();
 
             let output = token.call(&calldata, sender)?;
             assert!(output.reverted);
@@ -42,6 +42,53 @@ impl Precompile for StablecoinExchange {
                     )
                 })
             }
+            IStablecoinExchange::placeWithExpiryCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                mutate::<IStablecoinExchange::placeWithExpiryCall>(
+                    calldata,
+                    msg_sender,
+                    |s, call| {
+                        self.place_with_expiry(
+                            s,
+                            call.token,
+                            call.amount,
+                            call.isBid,
+                            call.tick,
+                            call.expiresAt,
+                        )
+                    },
+                )
+            }
+            IStablecoinExchange::placeWithOptionsCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                mutate::<IStablecoinExchange::placeWithOptionsCall>(
+                    calldata,
+                    msg_sender,
+                    |s, call| {
+                        self.place_with_options(
+                            s,
+                            call.token,
+                            call.amount,
+                            call.isBid,
+                            call.tick,
+                            call.timeInForce,
+                        )
+                        .map(Into::into)
+                    },
+                )
+            }
 
             IStablecoinExchange::balanceOfCall::SELECTOR => {
                 view::<IStablecoinExchange::balanceOfCall>(calldata, |call| {
@@ -55,6 +102,19 @@ impl Precompile for StablecoinExchange {
                 })
             }
 
+            IStablecoinExchange::getOrderExpiryCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                view::<IStablecoinExchange::getOrderExpiryCall>(calldata, |call| {
+                    self.get_order(call.orderId).map(|order| order.expires_at())
+                })
+            }
+
             IStablecoinExchange::getTickLevelCall::SELECTOR => {
                 view::<IStablecoinExchange::getTickLevelCall>(calldata, |call| {
                     let level = self.get_price_level(call.base, call.tick, call.isBid)?;
@@ -74,6 +134,19 @@ impl Precompile for StablecoinExchange {
                 })
             }
 
+            IStablecoinExchange::observeCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                view::<IStablecoinExchange::observeCall>(calldata, |call| {
+                    self.observe(call.bookKey, &call.secondsAgos)
+                })
+            }
+
             IStablecoinExchange::nextOrderIdCall::SELECTOR => {
                 if !self.storage.spec().is_allegro_moderato() {
                     return unknown_selector(
@@ -127,6 +200,18 @@ impl Precompile for StablecoinExchange {
                     self.cancel(s, call.orderId)
                 })
             }
+            IStablecoinExchange::purgeExpiredCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                mutate::<IStablecoinExchange::purgeExpiredCall>(calldata, msg_sender, |_s, call| {
+                    self.purge_expired(call.bookKey, call.maxOrders)
+                })
+            }
             IStablecoinExchange::swapExactAmountInCall::SELECTOR => {
                 mutate::<IStablecoinExchange::swapExactAmountInCall>(
                     calldata,
@@ -167,6 +252,72 @@ impl Precompile for StablecoinExchange {
                     self.quote_swap_exact_amount_out(call.tokenIn, call.tokenOut, call.amountOut)
                 })
             }
+            IStablecoinExchange::swapExactAmountInPathCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                mutate::<IStablecoinExchange::swapExactAmountInPathCall>(
+                    calldata,
+                    msg_sender,
+                    |s, call| {
+                        self.swap_exact_amount_in_path(
+                            s,
+                            &call.path,
+                            call.amountIn,
+                            call.minAmountOut,
+                        )
+                    },
+                )
+            }
+            IStablecoinExchange::swapExactAmountOutPathCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                mutate::<IStablecoinExchange::swapExactAmountOutPathCall>(
+                    calldata,
+                    msg_sender,
+                    |s, call| {
+                        self.swap_exact_amount_out_path(
+                            s,
+                            &call.path,
+                            call.amountOut,
+                            call.maxAmountIn,
+                        )
+                    },
+                )
+            }
+            IStablecoinExchange::quoteSwapExactAmountInPathCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                view::<IStablecoinExchange::quoteSwapExactAmountInPathCall>(calldata, |call| {
+                    self.quote_swap_exact_amount_in_path(&call.path, call.amountIn)
+                })
+            }
+            IStablecoinExchange::quoteSwapExactAmountOutPathCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                view::<IStablecoinExchange::quoteSwapExactAmountOutPathCall>(calldata, |call| {
+                    self.quote_swap_exact_amount_out_path(&call.path, call.amountOut)
+                })
+            }
             IStablecoinExchange::executeBlockCall::SELECTOR => {
                 mutate_void::<IStablecoinExchange::executeBlockCall>(
                     calldata,
@@ -766,10 +917,25 @@ mod tests {
                 IStablecoinExchangeCalls::name_by_selector,
             );
 
-            // In pre-AllegroModerato, nextOrderId should be unsupported
-            let expected_unsupported: Vec<&str> =
+            // In pre-AllegroModerato, nextOrderId and post-Vivace functions should be unsupported
+            let mut expected_unsupported: Vec<&str> =
                 unsupported.iter().map(|(_, name)| *name).collect();
-            assert_eq!(expected_unsupported, vec!["nextOrderId"]);
+            expected_unsupported.sort();
+            assert_eq!(
+                expected_unsupported,
+                vec![
+                    "getOrderExpiry",
+                    "nextOrderId",
+                    "observe",
+                    "placeWithExpiry",
+                    "placeWithOptions",
+                    "purgeExpired",
+                    "quoteSwapExactAmountInPath",
+                    "quoteSwapExactAmountOutPath",
+                    "swapExactAmountInPath",
+                    "swapExactAmountOutPath"
+                ]
+            );
 
             Ok(())
         })
@@ -788,7 +954,45 @@ mod tests {
                 IStablecoinExchangeCalls::name_by_selector,
             );
 
-            // In post-AllegroModerato, activeOrderId and pendingOrderId should be unsupported
+            // In post-AllegroModerato, activeOrderId, pendingOrderId and post-Vivace functions
+            // should be unsupported
+            let mut expected_unsupported: Vec<&str> =
+                unsupported.iter().map(|(_, name)| *name).collect();
+            expected_unsupported.sort();
+            assert_eq!(
+                expected_unsupported,
+                vec![
+                    "activeOrderId",
+                    "getOrderExpiry",
+                    "observe",
+                    "pendingOrderId",
+                    "placeWithExpiry",
+                    "placeWithOptions",
+                    "purgeExpired",
+                    "quoteSwapExactAmountInPath",
+                    "quoteSwapExactAmountOutPath",
+                    "swapExactAmountInPath",
+                    "swapExactAmountOutPath"
+                ]
+            );
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn stablecoin_exchange_test_selector_coverage_post_vivace() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        StorageCtx::enter(&mut storage, || {
+            let mut exchange = StablecoinExchange::new();
+
+            let unsupported = check_selector_coverage(
+                &mut exchange,
+                IStablecoinExchangeCalls::SELECTORS,
+                "IStablecoinExchange",
+                IStablecoinExchangeCalls::name_by_selector,
+            );
+
             let mut expected_unsupported: Vec<&str> =
                 unsupported.iter().map(|(_, name)| *name).collect();
             expected_unsupported.sort();
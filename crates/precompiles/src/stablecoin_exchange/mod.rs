@@ -1,9 +1,11 @@
 //! Stablecoin DEX types and utilities.
 pub mod dispatch;
 pub mod error;
+pub mod oracle;
 pub mod order;
 pub mod orderbook;
 
+pub use oracle::{ORACLE_CARDINALITY, Observation, OracleState};
 pub use order::Order;
 pub use orderbook::{MAX_TICK, MIN_TICK, Orderbook, PRICE_SCALE, TickLevel, tick_to_price};
 use tempo_contracts::precompiles::PATH_USD_ADDRESS;
@@ -54,6 +56,8 @@ pub struct StablecoinExchange {
     active_order_id: u128,
     pending_order_id: u128,
     book_keys: Vec<B256>,
+    oracle_states: Mapping<B256, OracleState>,
+    observations: Mapping<B256, Mapping<u16, Observation>>,
 }
 
 impl StablecoinExchange {
@@ -334,18 +338,166 @@ impl StablecoinExchange {
         // Find and validate the trade route (book keys + direction for each hop)
         let route = self.find_trade_path(token_in, token_out)?;
 
+        let amounts = self.execute_swap_exact_in(
+            sender,
+            token_in,
+            token_out,
+            &route,
+            amount_in,
+            min_amount_out,
+        )?;
+
+        Ok(amounts[route.len()])
+    }
+
+    pub fn swap_exact_amount_out(
+        &mut self,
+        sender: Address,
+        token_in: Address,
+        token_out: Address,
+        amount_out: u128,
+        max_amount_in: u128,
+    ) -> Result<u128> {
+        // Find and validate the trade route (book keys + direction for each hop)
+        let route = self.find_trade_path(token_in, token_out)?;
+
+        let amounts = self.execute_swap_exact_out(
+            sender,
+            token_in,
+            token_out,
+            &route,
+            amount_out,
+            max_amount_in,
+        )?;
+
+        Ok(amounts[0])
+    }
+
+    /// Swap an exact input amount along an explicit path of adjacent pairs
+    ///
+    /// Unlike [`Self::swap_exact_amount_in`], the route is chosen by the caller instead of being
+    /// derived from the quote token hierarchy, e.g. to go through an intermediate stablecoin
+    /// rather than PathUSD.
+    ///
+    /// # Returns
+    /// The amount of each token in `path` executed by the swap, starting with `amount_in`
+    pub fn swap_exact_amount_in_path(
+        &mut self,
+        sender: Address,
+        path: &[Address],
+        amount_in: u128,
+        min_amount_out: u128,
+    ) -> Result<Vec<u128>> {
+        let route = self.validate_path(path)?;
+        let (token_in, token_out) = (path[0], path[route.len()]);
+
+        let amounts = self.execute_swap_exact_in(
+            sender,
+            token_in,
+            token_out,
+            &route,
+            amount_in,
+            min_amount_out,
+        )?;
+        self.emit_swap(sender, path, &amounts)?;
+
+        Ok(amounts)
+    }
+
+    /// Swap for an exact output amount along an explicit path of adjacent pairs
+    ///
+    /// # Returns
+    /// The amount of each token in `path` executed by the swap, ending with `amount_out`
+    pub fn swap_exact_amount_out_path(
+        &mut self,
+        sender: Address,
+        path: &[Address],
+        amount_out: u128,
+        max_amount_in: u128,
+    ) -> Result<Vec<u128>> {
+        let route = self.validate_path(path)?;
+        let (token_in, token_out) = (path[0], path[route.len()]);
+
+        let amounts = self.execute_swap_exact_out(
+            sender,
+            token_in,
+            token_out,
+            &route,
+            amount_out,
+            max_amount_in,
+        )?;
+        self.emit_swap(sender, path, &amounts)?;
+
+        Ok(amounts)
+    }
+
+    /// Quote an exact input swap along an explicit path, reporting the amount of each hop
+    pub fn quote_swap_exact_amount_in_path(
+        &self,
+        path: &[Address],
+        amount_in: u128,
+    ) -> Result<Vec<u128>> {
+        let route = self.validate_path(path)?;
+
+        let mut amounts = Vec::with_capacity(path.len());
+        amounts.push(amount_in);
+        let mut current_amount = amount_in;
+        for (book_key, base_for_quote) in route {
+            current_amount = self.quote_exact_in(book_key, current_amount, base_for_quote)?;
+            amounts.push(current_amount);
+        }
+
+        Ok(amounts)
+    }
+
+    /// Quote an exact output swap along an explicit path, reporting the amount of each hop
+    pub fn quote_swap_exact_amount_out_path(
+        &self,
+        path: &[Address],
+        amount_out: u128,
+    ) -> Result<Vec<u128>> {
+        let route = self.validate_path(path)?;
+
+        let mut amounts = Vec::with_capacity(path.len());
+        amounts.push(amount_out);
+        let mut current_amount = amount_out;
+        for (book_key, base_for_quote) in route.iter().rev() {
+            current_amount = self.quote_exact_out(*book_key, current_amount, *base_for_quote)?;
+            amounts.push(current_amount);
+        }
+        amounts.reverse();
+
+        Ok(amounts)
+    }
+
+    /// Execute an exact input swap along a validated route
+    ///
+    /// # Returns
+    /// The amount of each token along the route, starting with `amount_in`
+    fn execute_swap_exact_in(
+        &mut self,
+        sender: Address,
+        token_in: Address,
+        token_out: Address,
+        route: &[(B256, bool)],
+        amount_in: u128,
+        min_amount_out: u128,
+    ) -> Result<Vec<u128>> {
         // Deduct input tokens from sender (only once, at the start)
         self.decrement_balance_or_transfer_from(sender, token_in, amount_in)?;
 
         // Execute swaps for each hop - intermediate balances are transitory
+        let mut amounts = Vec::with_capacity(route.len() + 1);
+        amounts.push(amount_in);
         let mut amount = amount_in;
-        for (book_key, base_for_quote) in route {
+        for &(book_key, base_for_quote) in route {
             // Fill orders for this hop - no min check on intermediate hops
             amount = if self.storage.spec().is_moderato() {
                 self.fill_orders_exact_in_post_moderato(book_key, base_for_quote, amount, sender)?
             } else {
                 self.fill_orders_exact_in_pre_moderato(book_key, base_for_quote, amount, 0, sender)?
             };
+            amounts.push(amount);
         }
 
         // Check final output meets minimum requirement
@@ -355,21 +507,25 @@ impl StablecoinExchange {
 
         self.transfer(token_out, sender, amount)?;
 
-        Ok(amount)
+        Ok(amounts)
     }
 
-    pub fn swap_exact_amount_out(
+    /// Execute an exact output swap along a validated route
+    ///
+    /// # Returns
+    /// The amount of each token along the route, ending with `amount_out`
+    fn execute_swap_exact_out(
         &mut self,
         sender: Address,
         token_in: Address,
         token_out: Address,
+        route: &[(B256, bool)],
         amount_out: u128,
         max_amount_in: u128,
-    ) -> Result<u128> {
-        // Find and validate the trade route (book keys + direction for each hop)
-        let route = self.find_trade_path(token_in, token_out)?;
-
+    ) -> Result<Vec<u128>> {
         // Work backwards from output to calculate input needed - intermediate amounts are TRANSITORY
+        let mut amounts = Vec::with_capacity(route.len() + 1);
+        amounts.push(amount_out);
         let mut amount = amount_out;
         for (book_key, base_for_quote) in route.iter().rev() {
             amount = if self.storage.spec().is_moderato() {
@@ -388,6 +544,7 @@ impl StablecoinExchange {
                     sender,
                 )?
             };
+            amounts.push(amount);
         }
 
         if amount > max_amount_in {
@@ -400,7 +557,19 @@ impl StablecoinExchange {
         // Transfer only final output ONCE at end
         self.transfer(token_out, sender, amount_out)?;
 
-        Ok(amount)
+        amounts.reverse();
+        Ok(amounts)
+    }
+
+    /// Emit a single Swap event covering every hop of a path swap
+    fn emit_swap(&mut self, sender: Address, path: &[Address], amounts: &[u128]) -> Result<()> {
+        self.emit_event(StablecoinExchangeEvents::Swap(IStablecoinExchange::Swap {
+            sender,
+            tokenIn: path[0],
+            tokenOut: path[path.len() - 1],
+            path: path.to_vec(),
+            amounts: amounts.to_vec(),
+        }))
     }
 
     /// Generate deterministic key for token pair
@@ -502,6 +671,38 @@ impl StablecoinExchange {
         amount: u128,
         is_bid: bool,
         tick: i16,
+    ) -> Result<u128> {
+        self.place_order(sender, token, amount, is_bid, tick, 0)
+    }
+
+    /// Place a limit order that expires at `expires_at`
+    ///
+    /// Expired orders are skipped and removed during matching or via [`Self::purge_expired`], and
+    /// their escrow is refunded to the maker's exchange balance.
+    pub fn place_with_expiry(
+        &mut self,
+        sender: Address,
+        token: Address,
+        amount: u128,
+        is_bid: bool,
+        tick: i16,
+        expires_at: u64,
+    ) -> Result<u128> {
+        if expires_at <= self.storage.timestamp().saturating_to::<u64>() {
+            return Err(StablecoinExchangeError::invalid_expiry().into());
+        }
+
+        self.place_order(sender, token, amount, is_bid, tick, expires_at)
+    }
+
+    fn place_order(
+        &mut self,
+        sender: Address,
+        token: Address,
+        amount: u128,
+        is_bid: bool,
+        tick: i16,
+        expires_at: u64,
     ) -> Result<u128> {
         let quote_token = TIP20Token::from_address(token)?.quote_token()?;
 
@@ -555,7 +756,8 @@ impl StablecoinExchange {
             Order::new_bid(order_id, sender, book_key, amount, tick)
         } else {
             Order::new_ask(order_id, sender, book_key, amount, tick)
-        };
+        }
+        .with_expiry(expires_at);
 
         // Post Allegro Moderato, commit the order to the book immediately rather than storing in a pending state until end of block execution
         if self.storage.spec().is_allegro_moderato() {
@@ -598,12 +800,14 @@ impl StablecoinExchange {
 
             if order.is_bid() {
                 if order.tick() > orderbook.best_bid_tick {
+                    self.update_oracle(order.book_key())?;
                     self.books
                         .at(order.book_key())
                         .best_bid_tick
                         .write(order.tick())?;
                 }
             } else if order.tick() < orderbook.best_ask_tick {
+                self.update_oracle(order.book_key())?;
                 self.books
                     .at(order.book_key())
                     .best_ask_tick
@@ -738,6 +942,182 @@ impl StablecoinExchange {
         Ok(order_id)
     }
 
+    /// Place an order with a time-in-force
+    ///
+    /// * `GOOD_TILL_CANCELLED` - Same as [`Self::place`]
+    /// * `POST_ONLY` - Same as [`Self::place`], but reverts if the order would cross the book
+    /// * `IMMEDIATE_OR_CANCEL` - Fills as much as possible at `tick` or better and cancels the rest
+    /// * `FILL_OR_KILL` - Fills the full amount at `tick` or better, or reverts
+    ///
+    /// Immediate orders never rest on the book. The taker pays from their exchange balance or
+    /// wallet and receives the proceeds in their wallet, like a swap.
+    ///
+    /// # Returns
+    /// The order ID of a resting order (zero for immediate orders) and the filled amount
+    pub fn place_with_options(
+        &mut self,
+        sender: Address,
+        token: Address,
+        amount: u128,
+        is_bid: bool,
+        tick: i16,
+        time_in_force: IStablecoinExchange::TimeInForce,
+    ) -> Result<(u128, u128)> {
+        use IStablecoinExchange::TimeInForce;
+
+        let fill_or_kill = match time_in_force {
+            TimeInForce::GOOD_TILL_CANCELLED => {
+                return Ok((self.place(sender, token, amount, is_bid, tick)?, 0));
+            }
+            TimeInForce::POST_ONLY => {
+                let quote_token = TIP20Token::from_address(token)?.quote_token()?;
+                let book = self.books.at(compute_book_key(token, quote_token)).read()?;
+                let crosses = if is_bid {
+                    tick >= book.best_ask_tick
+                } else {
+                    tick <= book.best_bid_tick
+                };
+                if !book.base.is_zero() && crosses {
+                    return Err(StablecoinExchangeError::order_would_cross(tick).into());
+                }
+                return Ok((self.place(sender, token, amount, is_bid, tick)?, 0));
+            }
+            TimeInForce::IMMEDIATE_OR_CANCEL => false,
+            TimeInForce::FILL_OR_KILL => true,
+            TimeInForce::__Invalid => {
+                return Err(StablecoinExchangeError::invalid_time_in_force().into());
+            }
+        };
+
+        let quote_token = TIP20Token::from_address(token)?.quote_token()?;
+        let book_key = compute_book_key(token, quote_token);
+
+        let book = self.books.at(book_key).read()?;
+        self.validate_or_create_pair(&book, token)?;
+
+        if !(MIN_TICK..=MAX_TICK).contains(&tick) {
+            return Err(StablecoinExchangeError::tick_out_of_bounds(tick).into());
+        }
+        if tick % TICK_SPACING != 0 {
+            return Err(StablecoinExchangeError::invalid_tick().into());
+        }
+        if amount < MIN_ORDER_AMOUNT {
+            return Err(StablecoinExchangeError::below_minimum_order_size(amount).into());
+        }
+
+        // A bid is matched against resting asks and vice versa
+        let (base_filled, quote_filled) =
+            self.fill_orders_to_limit_tick(book_key, !is_bid, tick, amount, sender)?;
+
+        if fill_or_kill && base_filled < amount {
+            return Err(
+                StablecoinExchangeError::fill_or_kill_not_filled(amount, base_filled).into(),
+            );
+        }
+
+        if base_filled > 0 {
+            let (token_in, amount_in, token_out, amount_out) = if is_bid {
+                (quote_token, quote_filled, token, base_filled)
+            } else {
+                (token, base_filled, quote_token, quote_filled)
+            };
+            self.decrement_balance_or_transfer_from(sender, token_in, amount_in)?;
+            self.transfer(token_out, sender, amount_out)?;
+        }
+
+        self.emit_event(StablecoinExchangeEvents::ImmediateOrderExecuted(
+            IStablecoinExchange::ImmediateOrderExecuted {
+                taker: sender,
+                token,
+                isBid: is_bid,
+                tick,
+                timeInForce: time_in_force,
+                amountFilled: base_filled,
+                amountCancelled: amount - base_filled,
+            },
+        ))?;
+
+        Ok((0, base_filled))
+    }
+
+    /// Fills up to `amount` base tokens of resting orders on one side of the book, stopping at
+    /// the first order priced worse than `limit_tick`.
+    ///
+    /// Returns the filled base amount and the quote amount credited to or debited from makers.
+    fn fill_orders_to_limit_tick(
+        &mut self,
+        book_key: B256,
+        bid: bool,
+        limit_tick: i16,
+        mut amount: u128,
+        taker: Address,
+    ) -> Result<(u128, u128)> {
+        let crosses = |tick: i16| {
+            if bid {
+                tick >= limit_tick
+            } else {
+                tick <= limit_tick
+            }
+        };
+
+        let orderbook = self.books.at(book_key).read()?;
+        let best_tick = if bid {
+            orderbook.best_bid_tick
+        } else {
+            orderbook.best_ask_tick
+        };
+        // Empty sides have a best tick of `i16::MIN`/`i16::MAX`, which never crosses
+        if !crosses(best_tick) {
+            return Ok((0, 0));
+        }
+
+        let level = self
+            .books
+            .at(book_key)
+            .get_tick_level_handler(best_tick, bid)
+            .read()?;
+        let order = self.orders.at(level.head).read()?;
+        let Some((mut level, mut order)) =
+            self.skip_expired_orders(book_key, bid, Some((level, order)))?
+        else {
+            return Ok((0, 0));
+        };
+
+        let mut base_filled: u128 = 0;
+        let mut quote_filled: u128 = 0;
+        while amount > 0 && crosses(order.tick()) {
+            let fill_amount = amount.min(order.remaining());
+            let quote_amount = fill_amount
+                .checked_mul(tick_to_price(order.tick()) as u128)
+                .and_then(|v| v.checked_div(orderbook::PRICE_SCALE as u128))
+                .ok_or(TempoPrecompileError::under_overflow())?;
+
+            base_filled = base_filled
+                .checked_add(fill_amount)
+                .ok_or(TempoPrecompileError::under_overflow())?;
+            quote_filled = quote_filled
+                .checked_add(quote_amount)
+                .ok_or(TempoPrecompileError::under_overflow())?;
+            amount -= fill_amount;
+
+            if fill_amount < order.remaining() {
+                self.partial_fill_order(&mut order, &mut level, fill_amount, taker)?;
+                break;
+            }
+
+            let (_, next_order_info) = self.fill_order(book_key, &mut order, level, taker)?;
+            match self.skip_expired_orders(book_key, bid, next_order_info)? {
+                Some((new_level, new_order)) => {
+                    level = new_level;
+                    order = new_order;
+                }
+                None => break,
+            }
+        }
+
+        Ok((base_filled, quote_filled))
+    }
+
     /// Process all pending orders into the active orderbook
     ///
     /// Only callable by the protocol via system transaction (sender must be Address::ZERO)
@@ -796,12 +1176,14 @@ impl StablecoinExchange {
 
             if order.is_bid() {
                 if order.tick() > orderbook.best_bid_tick {
+                    self.update_oracle(order.book_key())?;
                     self.books
                         .at(order.book_key())
                         .best_bid_tick
                         .write(order.tick())?;
                 }
             } else if order.tick() < orderbook.best_ask_tick {
+                self.update_oracle(order.book_key())?;
                 self.books
                     .at(order.book_key())
                     .best_ask_tick
@@ -950,6 +1332,7 @@ impl StablecoinExchange {
 
             if self.storage.spec().is_allegretto() {
                 // Update best_tick when tick is exhausted
+                self.update_oracle(book_key)?;
                 if order.is_bid() {
                     let new_best = if has_liquidity { tick } else { i16::MIN };
                     self.books.at(book_key).best_bid_tick.write(new_best)?;
@@ -1001,8 +1384,7 @@ impl StablecoinExchange {
         mut amount_out: u128,
         taker: Address,
     ) -> Result<u128> {
-        let mut level = self.get_best_price_level(book_key, bid)?;
-        let mut order = self.orders.at(level.head).read()?;
+        let (mut level, mut order) = self.get_best_live_order(book_key, bid)?;
 
         let mut total_amount_in: u128 = 0;
 
@@ -1059,7 +1441,9 @@ impl StablecoinExchange {
                     amount_out = 0;
                 }
 
-                if let Some((new_level, new_order)) = next_order_info {
+                if let Some((new_level, new_order)) =
+                    self.skip_expired_orders(book_key, bid, next_order_info)?
+                {
                     level = new_level;
                     order = new_order;
                 } else {
@@ -1146,8 +1530,7 @@ impl StablecoinExchange {
         mut amount_in: u128,
         taker: Address,
     ) -> Result<u128> {
-        let mut level = self.get_best_price_level(book_key, bid)?;
-        let mut order = self.orders.at(level.head).read()?;
+        let (mut level, mut order) = self.get_best_live_order(book_key, bid)?;
 
         let mut total_amount_out: u128 = 0;
 
@@ -1206,7 +1589,9 @@ impl StablecoinExchange {
                     }
                 }
 
-                if let Some((new_level, new_order)) = next_order_info {
+                if let Some((new_level, new_order)) =
+                    self.skip_expired_orders(book_key, bid, next_order_info)?
+                {
                     level = new_level;
                     order = new_order;
                 } else {
@@ -1358,6 +1743,30 @@ impl StablecoinExchange {
 
     /// Cancel an active order (already in the orderbook)
     fn cancel_active_order(&mut self, order: Order) -> Result<()> {
+        let order_id = order.order_id();
+        self.remove_active_order(order)?;
+
+        // Emit OrderCancelled event
+        self.emit_event(StablecoinExchangeEvents::OrderCancelled(
+            IStablecoinExchange::OrderCancelled { orderId: order_id },
+        ))
+    }
+
+    /// Remove an expired order from the orderbook
+    fn expire_order(&mut self, order: Order) -> Result<()> {
+        let (order_id, maker) = (order.order_id(), order.maker());
+        self.remove_active_order(order)?;
+
+        self.emit_event(StablecoinExchangeEvents::OrderExpired(
+            IStablecoinExchange::OrderExpired {
+                orderId: order_id,
+                maker,
+            },
+        ))
+    }
+
+    /// Unlink an active order from the orderbook and refund its escrow to the maker
+    fn remove_active_order(&mut self, order: Order) -> Result<()> {
         let mut book_handler = self.books.at(order.book_key());
         let mut level_handler = book_handler.get_tick_level_handler(order.tick(), order.is_bid());
         let mut level = level_handler.read()?;
@@ -1399,6 +1808,7 @@ impl StablecoinExchange {
                     let (next_tick, has_liquidity) =
                         book_handler.next_initialized_tick(order.tick(), order.is_bid());
 
+                    self.update_oracle(order.book_key())?;
                     if order.is_bid() {
                         let new_best = if has_liquidity { next_tick } else { i16::MIN };
                         self.books
@@ -1436,14 +1846,97 @@ impl StablecoinExchange {
         }
 
         // Clear the order from storage
-        self.orders.at(order.order_id()).delete()?;
+        self.orders.at(order.order_id()).delete()
+    }
 
-        // Emit OrderCancelled event
-        self.emit_event(StablecoinExchangeEvents::OrderCancelled(
-            IStablecoinExchange::OrderCancelled {
-                orderId: order.order_id(),
-            },
-        ))
+    /// Removes up to `max_orders` expired orders from a book
+    ///
+    /// Both sides of the book are scanned from the best tick outwards, and every visited order
+    /// counts towards `max_orders`, so that keepers can bound the gas spent. Expired orders are
+    /// also removed lazily during matching, but until then they are still included in quotes.
+    ///
+    /// # Returns
+    /// The number of removed orders
+    pub fn purge_expired(&mut self, book_key: B256, max_orders: u64) -> Result<u64> {
+        let orderbook = self.books.at(book_key).read()?;
+        if orderbook.base.is_zero() {
+            return Err(StablecoinExchangeError::pair_does_not_exist().into());
+        }
+
+        let now = self.storage.timestamp().saturating_to::<u64>();
+        let mut visited = 0;
+        let mut purged = 0;
+        for (is_bid, best_tick, empty_tick) in [
+            (true, orderbook.best_bid_tick, i16::MIN),
+            (false, orderbook.best_ask_tick, i16::MAX),
+        ] {
+            let mut tick = best_tick;
+            let mut has_liquidity = tick != empty_tick;
+            while has_liquidity && visited < max_orders {
+                let mut order_id = self
+                    .books
+                    .at(book_key)
+                    .get_tick_level_handler(tick, is_bid)
+                    .read()?
+                    .head;
+                while order_id != 0 && visited < max_orders {
+                    let order = self.orders.at(order_id).read()?;
+                    order_id = order.next();
+                    visited += 1;
+
+                    if order.is_expired(now) {
+                        self.expire_order(order)?;
+                        purged += 1;
+                    }
+                }
+
+                (tick, has_liquidity) = self.books.at(book_key).next_initialized_tick(tick, is_bid);
+            }
+        }
+
+        Ok(purged)
+    }
+
+    /// Returns the best order on one side of a book that has not expired, removing expired orders
+    /// in front of it. Starts from `next` if given, otherwise from the best tick of the book.
+    fn skip_expired_orders(
+        &mut self,
+        book_key: B256,
+        bid: bool,
+        mut next: Option<(TickLevel, Order)>,
+    ) -> Result<Option<(TickLevel, Order)>> {
+        let now = self.storage.timestamp().saturating_to::<u64>();
+        while let Some((_, order)) = next.take_if(|(_, order)| order.is_expired(now)) {
+            self.expire_order(order)?;
+
+            let orderbook = self.books.at(book_key).read()?;
+            let (best_tick, empty_tick) = if bid {
+                (orderbook.best_bid_tick, i16::MIN)
+            } else {
+                (orderbook.best_ask_tick, i16::MAX)
+            };
+            if best_tick != empty_tick {
+                let level = self
+                    .books
+                    .at(book_key)
+                    .get_tick_level_handler(best_tick, bid)
+                    .read()?;
+                let order = self.orders.at(level.head).read()?;
+                next = Some((level, order));
+            }
+        }
+
+        Ok(next)
+    }
+
+    /// Returns the best price level and its head order on one side of a book, skipping expired
+    /// orders.
+    fn get_best_live_order(&mut self, book_key: B256, bid: bool) -> Result<(TickLevel, Order)> {
+        let level = self.get_best_price_level(book_key, bid)?;
+        let order = self.orders.at(level.head).read()?;
+
+        self.skip_expired_orders(book_key, bid, Some((level, order)))?
+            .ok_or(StablecoinExchangeError::insufficient_liquidity().into())
     }
 
     /// Withdraw tokens from exchange balance
@@ -1620,6 +2113,28 @@ impl StablecoinExchange {
         self.validate_and_build_route(&trade_path)
     }
 
+    /// Validates a caller supplied swap path and returns book keys with direction info
+    ///
+    /// The path must contain at least two distinct TIP20 tokens, and every adjacent pair must have
+    /// an orderbook.
+    fn validate_path(&self, path: &[Address]) -> Result<Vec<(B256, bool)>> {
+        if path.len() < 2 {
+            return Err(StablecoinExchangeError::invalid_path().into());
+        }
+
+        let mut seen = std::collections::HashSet::with_capacity(path.len());
+        for token in path {
+            if !is_tip20_prefix(*token) {
+                return Err(StablecoinExchangeError::invalid_token().into());
+            }
+            if !seen.insert(*token) {
+                return Err(StablecoinExchangeError::invalid_path().into());
+            }
+        }
+
+        self.validate_and_build_route(path)
+    }
+
     /// Validates that all pairs in the path exist and returns book keys with direction info
     fn validate_and_build_route(&self, path: &[Address]) -> Result<Vec<(B256, bool)>> {
         let mut route = Vec::new();
@@ -2836,6 +3351,47 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_oracle_ignores_self_fill() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        StorageCtx::enter(&mut storage, || {
+            let mut exchange = StablecoinExchange::new();
+            exchange.initialize()?;
+
+            let alice = Address::random();
+            let admin = Address::random();
+            let (base_token, quote_token) =
+                setup_test_tokens(admin, alice, exchange.address, 1_000_000_000u128)?;
+            let book_key = exchange.create_pair(base_token)?;
+
+            // Quotes around a mid tick of 0
+            StorageCtx.set_timestamp(U256::from(1000));
+            exchange.place(alice, base_token, MIN_ORDER_AMOUNT, true, -10)?;
+            let ask_id = exchange.place(alice, base_token, MIN_ORDER_AMOUNT, false, 10)?;
+
+            // Within a single block, alice replaces her ask with one at the maximum tick, fills it
+            // herself and restores the ask
+            StorageCtx.set_timestamp(U256::from(1010));
+            exchange.cancel(alice, ask_id)?;
+            exchange.place(alice, base_token, MIN_ORDER_AMOUNT, false, MAX_TICK)?;
+            exchange.swap_exact_amount_out(
+                alice,
+                quote_token,
+                base_token,
+                MIN_ORDER_AMOUNT,
+                u128::MAX,
+            )?;
+            exchange.place(alice, base_token, MIN_ORDER_AMOUNT, false, 10)?;
+
+            // The extreme fill never persisted across a block, so the TWAP is unaffected
+            StorageCtx.set_timestamp(U256::from(1100));
+            let cumulatives = exchange.observe(book_key, &[100, 0])?;
+            assert_eq!((cumulatives[1] - cumulatives[0]) / 100, 0);
+
+            Ok(())
+        })
+    }
+
     #[test]
     fn test_flip_order_execution() -> eyre::Result<()> {
         let mut storage = HashMapStorageProvider::new(1);
@@ -3323,6 +3879,108 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_swap_path() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        StorageCtx::enter(&mut storage, || {
+            let mut exchange = StablecoinExchange::new();
+            exchange.initialize()?;
+
+            let admin = Address::random();
+            let alice = Address::random();
+            let bob = Address::random();
+
+            let amount = MIN_ORDER_AMOUNT;
+            let amount_x10 = U256::from(MIN_ORDER_AMOUNT * 10);
+
+            // Setup: PathUSD <- USDC, PathUSD <- EURC
+            let path_usd = TIP20Setup::path_usd(admin)
+                .with_issuer(admin)
+                .with_mint(alice, amount_x10)
+                .with_approval(alice, exchange.address, amount_x10)
+                .apply()?;
+            let usdc = TIP20Setup::create("USDC", "USDC", admin)
+                .with_issuer(admin)
+                .with_mint(bob, amount_x10)
+                .with_approval(bob, exchange.address, amount_x10)
+                .apply()?;
+            let eurc = TIP20Setup::create("EURC", "EURC", admin)
+                .with_issuer(admin)
+                .with_mint(alice, amount_x10)
+                .with_approval(alice, exchange.address, amount_x10)
+                .apply()?;
+
+            exchange.place(alice, usdc.address(), amount * 5, true, 0)?;
+            exchange.place(alice, eurc.address(), amount * 5, false, 0)?;
+
+            let path = [usdc.address(), path_usd.address(), eurc.address()];
+
+            // Invalid paths are rejected before touching any balances
+            for invalid in [&path[..1], &[usdc.address(), usdc.address()][..]] {
+                assert_eq!(
+                    exchange.quote_swap_exact_amount_in_path(invalid, amount),
+                    Err(StablecoinExchangeError::invalid_path().into())
+                );
+            }
+            assert_eq!(
+                exchange.swap_exact_amount_in_path(
+                    bob,
+                    &[usdc.address(), eurc.address()],
+                    amount,
+                    0
+                ),
+                Err(StablecoinExchangeError::pair_does_not_exist().into())
+            );
+
+            // Quotes and swaps report the amount executed by each hop
+            let quoted = exchange.quote_swap_exact_amount_in_path(&path, amount)?;
+            assert_eq!(quoted, vec![amount, amount, amount]);
+            assert_eq!(
+                quoted[2],
+                exchange.quote_swap_exact_amount_in(usdc.address(), eurc.address(), amount)?
+            );
+
+            let amounts = exchange.swap_exact_amount_in_path(bob, &path, amount, amount)?;
+            assert_eq!(amounts, quoted);
+            assert_eq!(
+                exchange.emitted_events().last(),
+                Some(
+                    &StablecoinExchangeEvents::Swap(IStablecoinExchange::Swap {
+                        sender: bob,
+                        tokenIn: usdc.address(),
+                        tokenOut: eurc.address(),
+                        path: path.to_vec(),
+                        amounts: amounts.clone(),
+                    })
+                    .into_log_data()
+                )
+            );
+
+            let quoted = exchange.quote_swap_exact_amount_out_path(&path, amount)?;
+            assert_eq!(quoted, vec![amount, amount, amount]);
+            let amounts = exchange.swap_exact_amount_out_path(bob, &path, amount, amount)?;
+            assert_eq!(amounts, quoted);
+
+            assert_eq!(
+                eurc.balance_of(ITIP20::balanceOfCall { account: bob })?,
+                U256::from(2 * amount)
+            );
+            assert_eq!(
+                usdc.balance_of(ITIP20::balanceOfCall { account: bob })?,
+                amount_x10 - U256::from(2 * amount)
+            );
+            assert_eq!(exchange.balance_of(bob, path_usd.address())?, 0);
+
+            // Slippage limits apply to the final hop
+            assert_eq!(
+                exchange.swap_exact_amount_in_path(bob, &path, amount, amount + 1),
+                Err(StablecoinExchangeError::insufficient_output().into())
+            );
+
+            Ok(())
+        })
+    }
+
     #[test]
     fn test_swap_exact_out_multi_hop_transitory_balances() -> eyre::Result<()> {
         let mut storage = HashMapStorageProvider::new(1);
@@ -4796,4 +5454,198 @@ mod tests {
             Ok(())
         })
     }
+
+    #[test]
+    fn test_place_with_options() -> eyre::Result<()> {
+        use IStablecoinExchange::TimeInForce;
+
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        StorageCtx::enter(&mut storage, || {
+            let mut exchange = StablecoinExchange::new();
+            exchange.initialize()?;
+
+            let alice = Address::random();
+            let bob = Address::random();
+            let admin = Address::random();
+            let amount = MIN_ORDER_AMOUNT;
+
+            let (base_token, quote_token) =
+                setup_test_tokens(admin, alice, exchange.address, 1_000_000_000)?;
+            exchange.set_balance(bob, base_token, 3 * amount)?;
+
+            exchange.place(alice, base_token, amount, true, 10)?;
+            exchange.place(alice, base_token, amount, true, 0)?;
+
+            // Post-only orders revert if they would cross the book
+            let result = exchange.place_with_options(
+                bob,
+                base_token,
+                amount,
+                false,
+                10,
+                TimeInForce::POST_ONLY,
+            );
+            assert_eq!(
+                result,
+                Err(StablecoinExchangeError::order_would_cross(10).into())
+            );
+
+            // Fill-or-kill orders revert if they cannot be filled completely at the limit tick
+            let result = exchange.place_with_options(
+                bob,
+                base_token,
+                amount,
+                false,
+                20,
+                TimeInForce::FILL_OR_KILL,
+            );
+            assert_eq!(
+                result,
+                Err(StablecoinExchangeError::fill_or_kill_not_filled(amount, 0).into())
+            );
+
+            // Immediate-or-cancel orders only fill at the limit tick or better
+            let (order_id, filled) = exchange.place_with_options(
+                bob,
+                base_token,
+                amount + amount / 2,
+                false,
+                10,
+                TimeInForce::IMMEDIATE_OR_CANCEL,
+            )?;
+            assert_eq!((order_id, filled), (0, amount));
+
+            let quote_amount =
+                amount * orderbook::tick_to_price(10) as u128 / orderbook::PRICE_SCALE as u128;
+            let bob_quote_balance = TIP20Token::from_address(quote_token)?
+                .balance_of(ITIP20::balanceOfCall { account: bob })?;
+            assert_eq!(bob_quote_balance, U256::from(quote_amount));
+            assert_eq!(exchange.balance_of(bob, base_token)?, 2 * amount);
+            assert_eq!(exchange.balance_of(alice, base_token)?, amount);
+            assert_eq!(
+                exchange.emitted_events().last(),
+                Some(
+                    &StablecoinExchangeEvents::ImmediateOrderExecuted(
+                        IStablecoinExchange::ImmediateOrderExecuted {
+                            taker: bob,
+                            token: base_token,
+                            isBid: false,
+                            tick: 10,
+                            timeInForce: TimeInForce::IMMEDIATE_OR_CANCEL,
+                            amountFilled: amount,
+                            amountCancelled: amount / 2,
+                        }
+                    )
+                    .into_log_data()
+                )
+            );
+
+            let (order_id, filled) = exchange.place_with_options(
+                bob,
+                base_token,
+                amount,
+                false,
+                0,
+                TimeInForce::FILL_OR_KILL,
+            )?;
+            assert_eq!((order_id, filled), (0, amount));
+
+            // Once the bids are consumed, the post-only order can rest on the book
+            let (order_id, filled) = exchange.place_with_options(
+                bob,
+                base_token,
+                amount,
+                false,
+                10,
+                TimeInForce::POST_ONLY,
+            )?;
+            assert_ne!(order_id, 0);
+            assert_eq!(filled, 0);
+            assert_eq!(exchange.get_order(order_id)?.remaining(), amount);
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_order_expiry() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        StorageCtx::enter(&mut storage, || {
+            let mut exchange = StablecoinExchange::new();
+            exchange.initialize()?;
+
+            let alice = Address::random();
+            let bob = Address::random();
+            let admin = Address::random();
+            let amount = MIN_ORDER_AMOUNT;
+
+            let (base_token, quote_token) =
+                setup_test_tokens(admin, alice, exchange.address, 1_000_000_000)?;
+            let book_key = compute_book_key(base_token, quote_token);
+            exchange.set_balance(bob, base_token, amount)?;
+
+            StorageCtx.set_timestamp(U256::from(1000));
+
+            // Expiry must be in the future
+            let result = exchange.place_with_expiry(alice, base_token, amount, true, 10, 1000);
+            assert_eq!(
+                result,
+                Err(StablecoinExchangeError::invalid_expiry().into())
+            );
+
+            let expiring_id =
+                exchange.place_with_expiry(alice, base_token, amount, true, 10, 2000)?;
+            exchange.place(alice, base_token, amount, true, 0)?;
+            assert_eq!(exchange.get_order(expiring_id)?.expires_at(), 2000);
+
+            // Once expired, the best bid is skipped during matching and its escrow is refunded
+            StorageCtx.set_timestamp(U256::from(2000));
+            let amount_out =
+                exchange.swap_exact_amount_in(bob, base_token, quote_token, amount, 0)?;
+            assert_eq!(amount_out, amount);
+            assert_eq!(
+                exchange.get_order(expiring_id),
+                Err(StablecoinExchangeError::order_does_not_exist().into())
+            );
+            assert_eq!(
+                exchange.balance_of(alice, quote_token)?,
+                calculate_quote_amount_ceil(amount, 10).unwrap()
+            );
+            assert!(
+                exchange.emitted_events().contains(
+                    &StablecoinExchangeEvents::OrderExpired(IStablecoinExchange::OrderExpired {
+                        orderId: expiring_id,
+                        maker: alice,
+                    })
+                    .into_log_data()
+                )
+            );
+
+            // Expired orders can also be purged without matching against them
+            let first_id =
+                exchange.place_with_expiry(alice, base_token, amount, false, 20, 3000)?;
+            let second_id =
+                exchange.place_with_expiry(alice, base_token, amount, false, 30, 3000)?;
+            assert_eq!(exchange.purge_expired(book_key, 10)?, 0);
+
+            StorageCtx.set_timestamp(U256::from(3000));
+            assert_eq!(exchange.purge_expired(book_key, 1)?, 1);
+            assert!(exchange.get_order(first_id).is_err());
+            assert!(exchange.get_order(second_id).is_ok());
+
+            assert_eq!(exchange.purge_expired(book_key, 10)?, 1);
+            assert!(exchange.get_order(second_id).is_err());
+            // Alice holds the base from the filled bid plus both refunded asks
+            assert_eq!(exchange.balance_of(alice, base_token)?, 3 * amount);
+            assert_eq!(exchange.books(book_key)?.best_ask_tick, i16::MAX);
+
+            let result = exchange.purge_expired(B256::random(), 10);
+            assert_eq!(
+                result,
+                Err(StablecoinExchangeError::pair_does_not_exist().into())
+            );
+
+            Ok(())
+        })
+    }
 }
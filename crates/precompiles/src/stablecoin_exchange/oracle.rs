@@ -0,0 +1,319 @@
+//! Time-weighted price oracle for stablecoin DEX books.
+//!
+//! Each book keeps a cumulative sum of its mid tick over time, sampled into a fixed-size ring
+//! buffer of observations, similar to Uniswap v3's oracle. The mid tick is sampled before the
+//! first change to the book's best ticks in a block, so only prices that persist across a block
+//! boundary are accumulated and trades within a block cannot move the oracle.
+
+use crate::{
+    error::{Result, TempoPrecompileError},
+    stablecoin_exchange::StablecoinExchange,
+    storage::Handler,
+};
+use alloy::primitives::B256;
+use tempo_contracts::precompiles::StablecoinExchangeError;
+use tempo_precompiles_macros::Storable;
+
+/// Number of observations kept per book before the oldest one is overwritten
+pub const ORACLE_CARDINALITY: u16 = 1024;
+
+/// Cumulative tick of a book at a point in time
+#[derive(Debug, Storable, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Observation {
+    /// Block timestamp of the observation
+    pub timestamp: u64,
+    /// Sum of the book's tick over every second up to `timestamp`
+    pub tick_cumulative: i64,
+}
+
+impl Observation {
+    /// Extends the observation to `timestamp`, assuming `tick` was in effect since it was taken
+    fn transform(&self, timestamp: u64, tick: i16) -> Result<Self> {
+        let elapsed = timestamp
+            .checked_sub(self.timestamp)
+            .and_then(|elapsed| i64::try_from(elapsed).ok())
+            .ok_or(TempoPrecompileError::under_overflow())?;
+        let tick_cumulative = i64::from(tick)
+            .checked_mul(elapsed)
+            .and_then(|delta| self.tick_cumulative.checked_add(delta))
+            .ok_or(TempoPrecompileError::under_overflow())?;
+
+        Ok(Self {
+            timestamp,
+            tick_cumulative,
+        })
+    }
+}
+
+/// Ring buffer position and latest tick of a book's oracle
+#[derive(Debug, Storable, Default, Clone, Copy, PartialEq, Eq)]
+pub struct OracleState {
+    /// Index of the most recent observation
+    pub index: u16,
+    /// Number of populated observations, up to [`ORACLE_CARDINALITY`]
+    pub cardinality: u16,
+    /// Mid tick of the book when the most recent observation was taken, used while the book is
+    /// empty
+    pub tick: i16,
+}
+
+impl StablecoinExchange {
+    /// Returns the tick cumulative of a book as of each of `seconds_agos` before now
+    ///
+    /// Points between two observations are interpolated, and points after the latest observation
+    /// are extrapolated using the current mid tick of the book.
+    pub fn observe(&self, book_key: B256, seconds_agos: &[u32]) -> Result<Vec<i64>> {
+        let mut state = self.oracle_states.at(book_key).read()?;
+        if state.cardinality == 0 {
+            return Err(StablecoinExchangeError::oracle_not_initialized().into());
+        }
+
+        // The current mid tick has been in effect since the block of the latest observation
+        if let Some(mid) = self.mid_tick(book_key)? {
+            state.tick = mid;
+        }
+
+        let now = self.storage.timestamp().saturating_to::<u64>();
+        seconds_agos
+            .iter()
+            .map(|&seconds_ago| self.observe_single(book_key, state, now, seconds_ago))
+            .collect()
+    }
+
+    fn observe_single(
+        &self,
+        book_key: B256,
+        state: OracleState,
+        now: u64,
+        seconds_ago: u32,
+    ) -> Result<i64> {
+        let too_old = || StablecoinExchangeError::observation_too_old(seconds_ago);
+        let target = now.checked_sub(seconds_ago as u64).ok_or_else(too_old)?;
+
+        let observations = self.observations.at(book_key);
+        let latest = observations.at(state.index).read()?;
+        if target >= latest.timestamp {
+            return Ok(latest.transform(target, state.tick)?.tick_cumulative);
+        }
+
+        // Once the buffer is full, the oldest observation is the one after the latest
+        let oldest_index = if state.cardinality < ORACLE_CARDINALITY {
+            0
+        } else {
+            (state.index + 1) % ORACLE_CARDINALITY
+        };
+        let at = |position: u16| {
+            observations
+                .at((oldest_index + position) % ORACLE_CARDINALITY)
+                .read()
+        };
+
+        if target < at(0)?.timestamp {
+            return Err(too_old().into());
+        }
+
+        // Binary search for the observations surrounding `target`, keeping
+        // `at(low).timestamp <= target < at(high).timestamp`
+        let (mut low, mut high) = (0, state.cardinality - 1);
+        while high - low > 1 {
+            let mid = low + (high - low) / 2;
+            if at(mid)?.timestamp <= target {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+
+        let (before, after) = (at(low)?, at(high)?);
+        if before.timestamp == target {
+            return Ok(before.tick_cumulative);
+        }
+
+        // A single tick was in effect between the two observations, so the slope is exact
+        let elapsed = (after.timestamp - before.timestamp) as i64;
+        let slope = (after.tick_cumulative - before.tick_cumulative) / elapsed;
+        Ok(before.tick_cumulative + slope * (target - before.timestamp) as i64)
+    }
+
+    /// Samples the book's mid tick into its oracle, before its best ticks change
+    ///
+    /// Only the first change in a block writes an observation, accumulating the mid tick that was
+    /// in effect since the previous one. No-op before Vivace.
+    pub(crate) fn update_oracle(&mut self, book_key: B256) -> Result<()> {
+        if !self.storage.spec().is_vivace() {
+            return Ok(());
+        }
+
+        let now = self.storage.timestamp().saturating_to::<u64>();
+        let mut state = self.oracle_states.at(book_key).read()?;
+
+        if state.cardinality == 0 {
+            // An empty book has no price to start the oracle with
+            let Some(mid) = self.mid_tick(book_key)? else {
+                return Ok(());
+            };
+            self.observations.at(book_key).at(0).write(Observation {
+                timestamp: now,
+                tick_cumulative: 0,
+            })?;
+            state.cardinality = 1;
+            state.tick = mid;
+        } else {
+            let latest = self.observations.at(book_key).at(state.index).read()?;
+            if latest.timestamp == now {
+                return Ok(());
+            }
+
+            state.tick = self.mid_tick(book_key)?.unwrap_or(state.tick);
+            let observation = latest.transform(now, state.tick)?;
+            state.index = (state.index + 1) % ORACLE_CARDINALITY;
+            state.cardinality = state.cardinality.max(state.index + 1);
+            self.observations
+                .at(book_key)
+                .at(state.index)
+                .write(observation)?;
+        }
+
+        self.oracle_states.at(book_key).write(state)
+    }
+
+    /// Returns the mid tick between the best bid and ask of a book
+    ///
+    /// If only one side has orders its best tick is returned, and `None` if the book is empty.
+    fn mid_tick(&self, book_key: B256) -> Result<Option<i16>> {
+        let book = self.books.at(book_key);
+        let best_bid_tick = book.best_bid_tick.read()?;
+        let best_ask_tick = book.best_ask_tick.read()?;
+
+        Ok(
+            match (best_bid_tick != i16::MIN, best_ask_tick != i16::MAX) {
+                (true, true) => Some(((best_bid_tick as i32 + best_ask_tick as i32) / 2) as i16),
+                (true, false) => Some(best_bid_tick),
+                (false, true) => Some(best_ask_tick),
+                (false, false) => None,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{StorageCtx, hashmap::HashMapStorageProvider};
+    use alloy::primitives::U256;
+    use tempo_chainspec::hardfork::TempoHardfork;
+
+    /// Moves the best ticks of a book, updating its oracle first as the exchange does
+    fn set_best_ticks(
+        exchange: &mut StablecoinExchange,
+        book_key: B256,
+        best_bid_tick: i16,
+        best_ask_tick: i16,
+    ) -> eyre::Result<()> {
+        exchange.update_oracle(book_key)?;
+        let mut book = exchange.books.at(book_key);
+        book.best_bid_tick.write(best_bid_tick)?;
+        book.best_ask_tick.write(best_ask_tick)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_observe() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        StorageCtx::enter(&mut storage, || {
+            let mut exchange = StablecoinExchange::new();
+            let book_key = B256::random();
+
+            assert_eq!(
+                exchange.observe(book_key, &[0]),
+                Err(StablecoinExchangeError::oracle_not_initialized().into())
+            );
+
+            // The oracle starts once the book has a price
+            StorageCtx.set_timestamp(U256::from(1000));
+            let mut book = exchange.books.at(book_key);
+            book.best_bid_tick.write(i16::MIN)?;
+            book.best_ask_tick.write(i16::MAX)?;
+            set_best_ticks(&mut exchange, book_key, 0, 20)?;
+            assert_eq!(
+                exchange.observe(book_key, &[0]),
+                Err(StablecoinExchangeError::oracle_not_initialized().into())
+            );
+
+            // Only the mid tick at the end of the block is in effect afterwards
+            set_best_ticks(&mut exchange, book_key, 10, 30)?;
+            set_best_ticks(&mut exchange, book_key, 1990, 2000)?;
+            set_best_ticks(&mut exchange, book_key, 10, 30)?;
+
+            StorageCtx.set_timestamp(U256::from(1010));
+            set_best_ticks(&mut exchange, book_key, -40, -20)?;
+
+            StorageCtx.set_timestamp(U256::from(1020));
+            let cumulatives = exchange.observe(book_key, &[20, 15, 10, 5, 0])?;
+            assert_eq!(cumulatives, vec![0, 100, 200, 50, -100]);
+
+            // The TWAP over the last 20 seconds is the average of 20 and -30
+            assert_eq!((cumulatives[4] - cumulatives[0]) / 20, -5);
+
+            assert_eq!(
+                exchange.observe(book_key, &[21]),
+                Err(StablecoinExchangeError::observation_too_old(21).into())
+            );
+            assert_eq!(
+                exchange.observe(book_key, &[u32::MAX]),
+                Err(StablecoinExchangeError::observation_too_old(u32::MAX).into())
+            );
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_observe_ring_buffer_wraps() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        StorageCtx::enter(&mut storage, || {
+            let mut exchange = StablecoinExchange::new();
+            let book_key = B256::random();
+
+            let start = 1000u64;
+            let fills = ORACLE_CARDINALITY as u64 + 10;
+            for i in 0..fills {
+                StorageCtx.set_timestamp(U256::from(start + i));
+                set_best_ticks(&mut exchange, book_key, 0, 2)?;
+            }
+
+            let state = exchange.oracle_states.at(book_key).read()?;
+            assert_eq!(state.cardinality, ORACLE_CARDINALITY);
+            assert_eq!(state.index, 9);
+
+            // Only the most recent ORACLE_CARDINALITY observations are retained
+            let oldest = ORACLE_CARDINALITY as u32 - 1;
+            let cumulatives = exchange.observe(book_key, &[oldest, 1, 0])?;
+            assert_eq!(cumulatives, vec![10, fills as i64 - 2, fills as i64 - 1]);
+            assert_eq!(
+                exchange.observe(book_key, &[oldest + 1]),
+                Err(StablecoinExchangeError::observation_too_old(oldest + 1).into())
+            );
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_update_oracle_pre_vivace() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::AllegroModerato);
+        StorageCtx::enter(&mut storage, || {
+            let mut exchange = StablecoinExchange::new();
+            let book_key = B256::random();
+
+            set_best_ticks(&mut exchange, book_key, 0, 20)?;
+            set_best_ticks(&mut exchange, book_key, 0, 20)?;
+            assert_eq!(
+                exchange.oracle_states.at(book_key).read()?,
+                OracleState::default()
+            );
+
+            Ok(())
+        })
+    }
+}
@@ -22,6 +22,8 @@ use tempo_precompiles_macros::Storable;
 /// 2. Orders can be filled (fully or partially) by swaps
 /// 3. Flip orders automatically create a new order on the opposite side when fully filled
 /// 4. Orders can be cancelled, removing them from the book and refunding escrow
+/// 5. Orders with an expiry are removed once expired, either during matching or via
+///    `purgeExpired`, refunding escrow
 ///
 /// # Price-Time Priority
 /// Orders are sorted by price (tick), then by insertion time.
@@ -57,6 +59,8 @@ pub struct Order {
     /// For bid flips: flip_tick must be > tick
     /// For ask flips: flip_tick must be < tick
     pub flip_tick: i16,
+    /// Timestamp at which the order expires (0 if the order never expires)
+    pub expires_at: u64,
 }
 
 impl Order {
@@ -84,9 +88,16 @@ impl Order {
             next: 0,
             is_flip,
             flip_tick,
+            expires_at: 0,
         }
     }
 
+    /// Sets the timestamp at which the order expires.
+    pub fn with_expiry(mut self, expires_at: u64) -> Self {
+        self.expires_at = expires_at;
+        self
+    }
+
     /// Creates a new bid order
     pub fn new_bid(
         order_id: u128,
@@ -199,6 +210,16 @@ impl Order {
         self.flip_tick
     }
 
+    /// Returns the timestamp at which the order expires (0 if the order never expires).
+    pub fn expires_at(&self) -> u64 {
+        self.expires_at
+    }
+
+    /// Returns true if the order has an expiry that is at or before `timestamp`.
+    pub fn is_expired(&self, timestamp: u64) -> bool {
+        self.expires_at != 0 && timestamp >= self.expires_at
+    }
+
     /// Returns the previous order ID in the doubly linked list (0 if head).
     pub fn prev(&self) -> u128 {
         self.prev
@@ -276,6 +297,7 @@ impl Order {
             next: 0,
             is_flip: true,        // Keep as flip order
             flip_tick: self.tick, // Old tick becomes new flip_tick
+            expires_at: self.expires_at,
         })
     }
 }
@@ -559,6 +581,18 @@ mod tests {
         assert_eq!(flipped.next(), 0);
     }
 
+    #[test]
+    fn test_order_expiry() {
+        let order = Order::new_bid(1, TEST_MAKER, TEST_BOOK_KEY, 1000, 5);
+        assert_eq!(order.expires_at(), 0);
+        assert!(!order.is_expired(u64::MAX));
+
+        let order = order.with_expiry(100);
+        assert_eq!(order.expires_at(), 100);
+        assert!(!order.is_expired(99));
+        assert!(order.is_expired(100));
+    }
+
     #[test]
     fn test_store_order() -> eyre::Result<()> {
         let mut storage = HashMapStorageProvider::new(1);
@@ -64,6 +64,58 @@ impl Precompile for TIP20Token {
             ITIP20::BURN_BLOCKED_ROLECall::SELECTOR => {
                 view::<ITIP20::BURN_BLOCKED_ROLECall>(calldata, |_| Ok(Self::burn_blocked_role()))
             }
+            ITIP20::FORCE_TRANSFER_ROLECall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                view::<ITIP20::FORCE_TRANSFER_ROLECall>(calldata, |_| {
+                    Ok(Self::force_transfer_role())
+                })
+            }
+            ITIP20::FREEZE_ROLECall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                view::<ITIP20::FREEZE_ROLECall>(calldata, |_| Ok(Self::freeze_role()))
+            }
+            ITIP20::adminTimelockDelayCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                view::<ITIP20::adminTimelockDelayCall>(calldata, |_| self.admin_timelock_delay())
+            }
+            ITIP20::queuedActionEtaCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                view::<ITIP20::queuedActionEtaCall>(calldata, |call| self.queued_action_eta(call))
+            }
+            ITIP20::isFrozenCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                view::<ITIP20::isFrozenCall>(calldata, |call| self.is_frozen(call))
+            }
 
             // State changing functions
             ITIP20::transferFromCall::SELECTOR => {
@@ -137,6 +189,130 @@ impl Precompile for TIP20Token {
                 })
             }
 
+            ITIP20::noncesCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                view::<ITIP20::noncesCall>(calldata, |call| self.nonces(call))
+            }
+            ITIP20::DOMAIN_SEPARATORCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                view::<ITIP20::DOMAIN_SEPARATORCall>(calldata, |_call| self.domain_separator())
+            }
+            ITIP20::permitCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                mutate_void::<ITIP20::permitCall>(calldata, msg_sender, |_, call| self.permit(call))
+            }
+            ITIP20::permitWithSignatureCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                mutate_void::<ITIP20::permitWithSignatureCall>(calldata, msg_sender, |_, call| {
+                    self.permit_with_signature(call)
+                })
+            }
+
+            ITIP20::authorizationStateCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                view::<ITIP20::authorizationStateCall>(calldata, |call| {
+                    self.authorization_state(call)
+                })
+            }
+            ITIP20::transferWithAuthorizationCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                mutate_void::<ITIP20::transferWithAuthorizationCall>(
+                    calldata,
+                    msg_sender,
+                    |_, call| self.transfer_with_authorization(call),
+                )
+            }
+            ITIP20::receiveWithAuthorizationCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                mutate_void::<ITIP20::receiveWithAuthorizationCall>(
+                    calldata,
+                    msg_sender,
+                    |s, call| self.receive_with_authorization(s, call),
+                )
+            }
+            ITIP20::transferWithAuthorizationWithMemoCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                mutate_void::<ITIP20::transferWithAuthorizationWithMemoCall>(
+                    calldata,
+                    msg_sender,
+                    |_, call| self.transfer_with_authorization_with_memo(call),
+                )
+            }
+            ITIP20::receiveWithAuthorizationWithMemoCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                mutate_void::<ITIP20::receiveWithAuthorizationWithMemoCall>(
+                    calldata,
+                    msg_sender,
+                    |s, call| self.receive_with_authorization_with_memo(s, call),
+                )
+            }
+            ITIP20::cancelAuthorizationCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                mutate_void::<ITIP20::cancelAuthorizationCall>(calldata, msg_sender, |_, call| {
+                    self.cancel_authorization(call)
+                })
+            }
+
             ITIP20::mintCall::SELECTOR => {
                 mutate_void::<ITIP20::mintCall>(calldata, msg_sender, |s, call| self.mint(s, call))
             }
@@ -158,6 +334,90 @@ impl Precompile for TIP20Token {
                     self.burn_blocked(s, call)
                 })
             }
+            ITIP20::forceTransferCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                mutate_void::<ITIP20::forceTransferCall>(calldata, msg_sender, |s, call| {
+                    self.force_transfer(s, call)
+                })
+            }
+            ITIP20::freezeCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                mutate_void::<ITIP20::freezeCall>(calldata, msg_sender, |s, call| {
+                    self.freeze(s, call)
+                })
+            }
+            ITIP20::unfreezeCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                mutate_void::<ITIP20::unfreezeCall>(calldata, msg_sender, |s, call| {
+                    self.unfreeze(s, call)
+                })
+            }
+            ITIP20::setAdminTimelockDelayCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                mutate_void::<ITIP20::setAdminTimelockDelayCall>(calldata, msg_sender, |s, call| {
+                    self.set_admin_timelock_delay(s, call)
+                })
+            }
+            ITIP20::queueActionCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                mutate::<ITIP20::queueActionCall>(calldata, msg_sender, |s, call| {
+                    self.queue_action(s, call)
+                })
+            }
+            ITIP20::executeActionCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                mutate_void::<ITIP20::executeActionCall>(calldata, msg_sender, |s, call| {
+                    self.execute_action(s, call)
+                })
+            }
+            ITIP20::cancelActionCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                mutate_void::<ITIP20::cancelActionCall>(calldata, msg_sender, |s, call| {
+                    self.cancel_action(s, call)
+                })
+            }
             ITIP20::transferWithMemoCall::SELECTOR => {
                 mutate_void::<ITIP20::transferWithMemoCall>(calldata, msg_sender, |s, call| {
                     self.transfer_with_memo(s, call)
@@ -168,6 +428,18 @@ impl Precompile for TIP20Token {
                     self.transfer_from_with_memo(sender, call)
                 })
             }
+            ITIP20::transferBatchCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                mutate_void::<ITIP20::transferBatchCall>(calldata, msg_sender, |s, call| {
+                    self.transfer_batch(s, call)
+                })
+            }
             ITIP20::startRewardCall::SELECTOR => {
                 mutate::<ITIP20::startRewardCall>(calldata, msg_sender, |s, call| {
                     self.start_reward(s, call)
@@ -183,11 +455,35 @@ impl Precompile for TIP20Token {
                     self.cancel_reward(s, call)
                 })
             }
-            ITIP20::claimRewardsCall::SELECTOR => {
-                mutate::<ITIP20::claimRewardsCall>(calldata, msg_sender, |_, _| {
+            ITIP20::claimRewards_0Call::SELECTOR => {
+                mutate::<ITIP20::claimRewards_0Call>(calldata, msg_sender, |_, _| {
                     self.claim_rewards(msg_sender)
                 })
             }
+            ITIP20::startTokenRewardCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                mutate::<ITIP20::startTokenRewardCall>(calldata, msg_sender, |s, call| {
+                    self.start_token_reward(s, call)
+                })
+            }
+            ITIP20::claimRewards_1Call::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                mutate::<ITIP20::claimRewards_1Call>(calldata, msg_sender, |s, call| {
+                    self.claim_token_rewards(s, call.rewardToken)
+                })
+            }
 
             ITIP20::finalizeStreamsCall::SELECTOR => {
                 mutate_void::<ITIP20::finalizeStreamsCall>(calldata, msg_sender, |sender, call| {
@@ -219,6 +515,29 @@ impl Precompile for TIP20Token {
                         .map(|info| info.into())
                 })
             }
+            ITIP20::rewardTokensCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                view::<ITIP20::rewardTokensCall>(calldata, |_call| self.get_reward_tokens())
+            }
+            ITIP20::tokenRewardInfoCall::SELECTOR => {
+                if !self.storage.spec().is_vivace() {
+                    return unknown_selector(
+                        selector,
+                        self.storage.gas_used(),
+                        self.storage.spec(),
+                    );
+                }
+                view::<ITIP20::tokenRewardInfoCall>(calldata, |call| {
+                    self.get_token_reward_info(call.account, call.rewardToken)
+                        .map(|info| info.into())
+                })
+            }
 
             // RolesAuth functions
             IRolesAuth::hasRoleCall::SELECTOR => {
@@ -817,11 +1136,80 @@ mod tests {
 
     #[test]
     fn tip20_test_selector_coverage() {
-        use crate::test_util::{assert_full_coverage, check_selector_coverage};
+        use crate::test_util::check_selector_coverage;
         use tempo_contracts::precompiles::{IRolesAuth::IRolesAuthCalls, ITIP20::ITIP20Calls};
 
         let (mut storage, admin) = setup_storage();
 
+        StorageCtx::enter(&mut storage, || {
+            initialize_path_usd(admin).unwrap();
+            let mut token = TIP20Token::new(1);
+            token
+                .initialize("Test", "TST", "USD", PATH_USD_ADDRESS, admin, Address::ZERO)
+                .unwrap();
+
+            let itip20_unsupported =
+                check_selector_coverage(&mut token, ITIP20Calls::SELECTORS, "ITIP20", |s| {
+                    ITIP20Calls::name_by_selector(s)
+                });
+
+            let roles_unsupported = check_selector_coverage(
+                &mut token,
+                IRolesAuthCalls::SELECTORS,
+                "IRolesAuth",
+                IRolesAuthCalls::name_by_selector,
+            );
+
+            // In pre-Vivace, post-Vivace functions should be unsupported
+            let mut unsupported: Vec<&str> = itip20_unsupported
+                .iter()
+                .chain(&roles_unsupported)
+                .map(|(_, name)| *name)
+                .collect();
+            unsupported.sort();
+            assert_eq!(
+                unsupported,
+                vec![
+                    "DOMAIN_SEPARATOR",
+                    "FORCE_TRANSFER_ROLE",
+                    "FREEZE_ROLE",
+                    "adminTimelockDelay",
+                    "authorizationState",
+                    "cancelAction",
+                    "cancelAuthorization",
+                    "claimRewards",
+                    "executeAction",
+                    "forceTransfer",
+                    "freeze",
+                    "isFrozen",
+                    "nonces",
+                    "permit",
+                    "permitWithSignature",
+                    "queueAction",
+                    "queuedActionEta",
+                    "receiveWithAuthorization",
+                    "receiveWithAuthorizationWithMemo",
+                    "rewardTokens",
+                    "setAdminTimelockDelay",
+                    "startTokenReward",
+                    "tokenRewardInfo",
+                    "transferBatch",
+                    "transferWithAuthorization",
+                    "transferWithAuthorizationWithMemo",
+                    "unfreeze",
+                ]
+            );
+        })
+    }
+
+    #[test]
+    fn tip20_test_selector_coverage_post_vivace() {
+        use crate::test_util::{assert_full_coverage, check_selector_coverage};
+        use tempo_contracts::precompiles::{IRolesAuth::IRolesAuthCalls, ITIP20::ITIP20Calls};
+
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        let admin = Address::random();
+
         StorageCtx::enter(&mut storage, || {
             initialize_path_usd(admin).unwrap();
             let mut token = TIP20Token::new(1);
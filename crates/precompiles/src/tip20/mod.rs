@@ -13,7 +13,7 @@ use crate::{
     error::{Result, TempoPrecompileError},
     storage::{Handler, Mapping, StorageCtx},
     tip20::{
-        rewards::{RewardStream, UserRewardInfo},
+        rewards::{RewardStream, TokenRewardInfo, UserRewardInfo},
         roles::DEFAULT_ADMIN_ROLE,
     },
     tip20_factory::TIP20Factory,
@@ -21,10 +21,12 @@ use crate::{
 };
 use alloy::{
     hex,
-    primitives::{Address, B256, U256, keccak256, uint},
+    primitives::{Address, B256, Signature, U256, keccak256, uint},
+    sol_types::{SolInterface, SolValue},
 };
 use std::sync::LazyLock;
 use tempo_precompiles_macros::contract;
+use tempo_primitives::transaction::PrimitiveSignature;
 use tracing::trace;
 
 /// u128::MAX as U256
@@ -96,12 +98,67 @@ pub struct TIP20Token {
 
     // Fee recipient
     fee_recipient: Address,
+
+    // ERC-3009 authorizations
+    authorization_states: Mapping<Address, Mapping<B256, bool>>,
+
+    // Frozen accounts
+    frozen: Mapping<Address, bool>,
+
+    // Admin timelock
+    admin_timelock_delay: u64,
+    queued_actions: Mapping<B256, u64>,
+
+    // Multi-asset rewards
+    reward_tokens: Vec<Address>,
+    token_reward_per_token: Mapping<Address, U256>,
+    token_reward_info: Mapping<Address, Mapping<Address, TokenRewardInfo>>,
 }
 
 pub static PAUSE_ROLE: LazyLock<B256> = LazyLock::new(|| keccak256(b"PAUSE_ROLE"));
 pub static UNPAUSE_ROLE: LazyLock<B256> = LazyLock::new(|| keccak256(b"UNPAUSE_ROLE"));
 pub static ISSUER_ROLE: LazyLock<B256> = LazyLock::new(|| keccak256(b"ISSUER_ROLE"));
 pub static BURN_BLOCKED_ROLE: LazyLock<B256> = LazyLock::new(|| keccak256(b"BURN_BLOCKED_ROLE"));
+pub static FORCE_TRANSFER_ROLE: LazyLock<B256> =
+    LazyLock::new(|| keccak256(b"FORCE_TRANSFER_ROLE"));
+pub static FREEZE_ROLE: LazyLock<B256> = LazyLock::new(|| keccak256(b"FREEZE_ROLE"));
+
+pub static EIP712_DOMAIN_TYPEHASH: LazyLock<B256> = LazyLock::new(|| {
+    keccak256(b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)")
+});
+pub static PERMIT_TYPEHASH: LazyLock<B256> = LazyLock::new(|| {
+    keccak256(b"Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)")
+});
+pub static TRANSFER_WITH_AUTHORIZATION_TYPEHASH: LazyLock<B256> = LazyLock::new(|| {
+    keccak256(b"TransferWithAuthorization(address from,address to,uint256 value,uint256 validAfter,uint256 validBefore,bytes32 nonce)")
+});
+pub static RECEIVE_WITH_AUTHORIZATION_TYPEHASH: LazyLock<B256> = LazyLock::new(|| {
+    keccak256(b"ReceiveWithAuthorization(address from,address to,uint256 value,uint256 validAfter,uint256 validBefore,bytes32 nonce)")
+});
+pub static TRANSFER_WITH_AUTHORIZATION_WITH_MEMO_TYPEHASH: LazyLock<B256> = LazyLock::new(|| {
+    keccak256(b"TransferWithAuthorizationWithMemo(address from,address to,uint256 value,uint256 validAfter,uint256 validBefore,bytes32 nonce,bytes32 memo)")
+});
+pub static RECEIVE_WITH_AUTHORIZATION_WITH_MEMO_TYPEHASH: LazyLock<B256> = LazyLock::new(|| {
+    keccak256(b"ReceiveWithAuthorizationWithMemo(address from,address to,uint256 value,uint256 validAfter,uint256 validBefore,bytes32 nonce,bytes32 memo)")
+});
+pub static CANCEL_AUTHORIZATION_TYPEHASH: LazyLock<B256> =
+    LazyLock::new(|| keccak256(b"CancelAuthorization(address authorizer,bytes32 nonce)"));
+
+/// EIP-712 domain version of TIP-20 tokens
+const EIP712_VERSION: &str = "1";
+
+/// Gas for verifying a secp256k1 permit signature, same as the `ecrecover` precompile
+const ECRECOVER_GAS: u64 = 3_000;
+
+/// Gas for verifying a P256 or WebAuthn permit signature, same as the `P256VERIFY` precompile
+/// from EIP-7951
+const P256_VERIFY_GAS: u64 = 6_900;
+
+/// Maximum delay of the admin timelock (30 days)
+pub const MAX_ADMIN_TIMELOCK_DELAY: u64 = 30 * 24 * 60 * 60;
+
+/// Maximum number of recipients of a single `transferBatch` call
+pub const MAX_TRANSFER_BATCH_SIZE: usize = 1_000;
 
 /// Validates that a token has USD currency
 pub fn validate_usd_currency(token: Address, storage: StorageCtx) -> Result<()> {
@@ -117,6 +174,65 @@ pub fn validate_usd_currency(token: Address, storage: StorageCtx) -> Result<()>
     Ok(())
 }
 
+/// Builds a secp256k1 signature from its `v`, `r` and `s` components.
+///
+/// Accepts recovery values `v ∈ {0, 1, 27, 28}`.
+fn secp256k1_signature(v: u8, r: B256, s: B256) -> Result<PrimitiveSignature> {
+    let parity = match v {
+        27 | 28 => v == 28,
+        0 | 1 => v == 1,
+        _ => return Err(TIP20Error::invalid_signature().into()),
+    };
+    Ok(PrimitiveSignature::Secp256k1(
+        Signature::from_scalars_and_parity(r, s, parity),
+    ))
+}
+
+/// An ERC-3009 transfer authorization signed by `from`.
+#[derive(Debug, Clone, Copy)]
+struct TransferAuthorization {
+    from: Address,
+    to: Address,
+    value: U256,
+    valid_after: U256,
+    valid_before: U256,
+    nonce: B256,
+    memo: Option<B256>,
+}
+
+impl TransferAuthorization {
+    /// Returns the EIP-712 struct hash of the authorization for the given type.
+    fn struct_hash(&self, typehash: B256) -> B256 {
+        let Self {
+            from,
+            to,
+            value,
+            valid_after,
+            valid_before,
+            nonce,
+            memo,
+        } = *self;
+        match memo {
+            Some(memo) => keccak256(
+                (
+                    typehash,
+                    from,
+                    to,
+                    value,
+                    valid_after,
+                    valid_before,
+                    nonce,
+                    memo,
+                )
+                    .abi_encode(),
+            ),
+            None => keccak256(
+                (typehash, from, to, value, valid_after, valid_before, nonce).abi_encode(),
+            ),
+        }
+    }
+}
+
 impl TIP20Token {
     pub fn name(&self) -> Result<String> {
         self.name.read()
@@ -190,6 +306,23 @@ impl TIP20Token {
         *BURN_BLOCKED_ROLE
     }
 
+    /// Returns the FORCE_TRANSFER_ROLE constant
+    ///
+    /// This role identifier grants permission to move tokens out of any account, e.g. to
+    /// execute a court-ordered seizure.
+    /// The role is computed as `keccak256("FORCE_TRANSFER_ROLE")`.
+    pub fn force_transfer_role() -> B256 {
+        *FORCE_TRANSFER_ROLE
+    }
+
+    /// Returns the FREEZE_ROLE constant
+    ///
+    /// This role identifier grants permission to freeze and unfreeze individual accounts.
+    /// The role is computed as `keccak256("FREEZE_ROLE")`.
+    pub fn freeze_role() -> B256 {
+        *FREEZE_ROLE
+    }
+
     // View functions
     pub fn balance_of(&self, call: ITIP20::balanceOfCall) -> Result<U256> {
         self.balances.at(call.account).read()
@@ -199,14 +332,33 @@ impl TIP20Token {
         self.allowances.at(call.owner).at(call.spender).read()
     }
 
+    pub fn is_frozen(&self, call: ITIP20::isFrozenCall) -> Result<bool> {
+        self.frozen.at(call.account).read()
+    }
+
+    pub fn admin_timelock_delay(&self) -> Result<u64> {
+        self.admin_timelock_delay.read()
+    }
+
+    pub fn queued_action_eta(&self, call: ITIP20::queuedActionEtaCall) -> Result<u64> {
+        self.queued_actions.at(call.actionId).read()
+    }
+
     // Admin functions
     pub fn change_transfer_policy_id(
         &mut self,
         msg_sender: Address,
         call: ITIP20::changeTransferPolicyIdCall,
     ) -> Result<()> {
-        self.check_role(msg_sender, DEFAULT_ADMIN_ROLE)?;
+        self.check_timelocked_admin(msg_sender)?;
+        self._change_transfer_policy_id(msg_sender, call)
+    }
 
+    fn _change_transfer_policy_id(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP20::changeTransferPolicyIdCall,
+    ) -> Result<()> {
         // Validate that the policy exists (only after AllegroModerato hardfork)
         if self.storage.spec().is_allegro_moderato() {
             let registry = TIP403Registry::new();
@@ -232,7 +384,15 @@ impl TIP20Token {
         msg_sender: Address,
         call: ITIP20::setSupplyCapCall,
     ) -> Result<()> {
-        self.check_role(msg_sender, DEFAULT_ADMIN_ROLE)?;
+        self.check_timelocked_admin(msg_sender)?;
+        self._set_supply_cap(msg_sender, call)
+    }
+
+    fn _set_supply_cap(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP20::setSupplyCapCall,
+    ) -> Result<()> {
         if call.newSupplyCap < self.total_supply()? {
             return Err(TIP20Error::invalid_supply_cap().into());
         }
@@ -269,13 +429,55 @@ impl TIP20Token {
         }))
     }
 
+    /// Freezes an account, blocking it from sending or receiving this token.
+    ///
+    /// Unlike TIP-403 policies, which can be shared by many tokens, freezes only apply to this
+    /// token.
+    pub fn freeze(&mut self, msg_sender: Address, call: ITIP20::freezeCall) -> Result<()> {
+        self.check_role(msg_sender, *FREEZE_ROLE)?;
+
+        // Freezing `FeeManager` or `StablecoinExchange` would lock up funds of other users
+        if matches!(
+            call.account,
+            TIP_FEE_MANAGER_ADDRESS | STABLECOIN_EXCHANGE_ADDRESS
+        ) {
+            return Err(TIP20Error::protected_address().into());
+        }
+
+        self.frozen.at(call.account).write(true)?;
+
+        self.emit_event(TIP20Event::FreezeStateUpdate(ITIP20::FreezeStateUpdate {
+            updater: msg_sender,
+            account: call.account,
+            isFrozen: true,
+        }))
+    }
+
+    pub fn unfreeze(&mut self, msg_sender: Address, call: ITIP20::unfreezeCall) -> Result<()> {
+        self.check_role(msg_sender, *FREEZE_ROLE)?;
+        self.frozen.at(call.account).write(false)?;
+
+        self.emit_event(TIP20Event::FreezeStateUpdate(ITIP20::FreezeStateUpdate {
+            updater: msg_sender,
+            account: call.account,
+            isFrozen: false,
+        }))
+    }
+
     pub fn set_next_quote_token(
         &mut self,
         msg_sender: Address,
         call: ITIP20::setNextQuoteTokenCall,
     ) -> Result<()> {
-        self.check_role(msg_sender, DEFAULT_ADMIN_ROLE)?;
+        self.check_timelocked_admin(msg_sender)?;
+        self._set_next_quote_token(msg_sender, call)
+    }
 
+    fn _set_next_quote_token(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP20::setNextQuoteTokenCall,
+    ) -> Result<()> {
         // Verify the new quote token is a valid TIP20 token that has been deployed
         if self.storage.spec().is_allegro_moderato() {
             // Post-AllegroModerato: use factory's is_tip20 which checks both prefix and counter
@@ -314,6 +516,149 @@ impl TIP20Token {
         }))
     }
 
+    /// Sets the delay that timelocked admin actions have to wait between being queued and
+    /// executed. A delay of zero disables the timelock.
+    pub fn set_admin_timelock_delay(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP20::setAdminTimelockDelayCall,
+    ) -> Result<()> {
+        self.check_timelocked_admin(msg_sender)?;
+        self._set_admin_timelock_delay(msg_sender, call)
+    }
+
+    fn _set_admin_timelock_delay(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP20::setAdminTimelockDelayCall,
+    ) -> Result<()> {
+        if call.delay > MAX_ADMIN_TIMELOCK_DELAY {
+            return Err(TIP20Error::invalid_timelock_delay().into());
+        }
+
+        self.admin_timelock_delay.write(call.delay)?;
+
+        self.emit_event(TIP20Event::AdminTimelockDelayUpdate(
+            ITIP20::AdminTimelockDelayUpdate {
+                updater: msg_sender,
+                newDelay: call.delay,
+            },
+        ))
+    }
+
+    /// Queues an ABI-encoded call to a timelocked admin function, which can be executed once the
+    /// current admin timelock delay has passed. Later changes to the delay do not affect the eta
+    /// of already queued actions.
+    pub fn queue_action(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP20::queueActionCall,
+    ) -> Result<B256> {
+        self.check_role(msg_sender, DEFAULT_ADMIN_ROLE)?;
+        Self::decode_timelocked_action(&call.data)?;
+
+        let action_id = keccak256(&call.data);
+        if self.queued_actions.at(action_id).read()? != 0 {
+            return Err(TIP20Error::action_already_queued(action_id).into());
+        }
+
+        let eta = self
+            .storage
+            .timestamp()
+            .saturating_to::<u64>()
+            .saturating_add(self.admin_timelock_delay()?);
+        self.queued_actions.at(action_id).write(eta)?;
+
+        self.emit_event(TIP20Event::ActionQueued(ITIP20::ActionQueued {
+            actionId: action_id,
+            queuer: msg_sender,
+            data: call.data,
+            eta,
+        }))?;
+
+        Ok(action_id)
+    }
+
+    /// Executes a queued admin action whose eta has passed.
+    pub fn execute_action(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP20::executeActionCall,
+    ) -> Result<()> {
+        self.check_role(msg_sender, DEFAULT_ADMIN_ROLE)?;
+
+        let action_id = keccak256(&call.data);
+        let eta = self.queued_actions.at(action_id).read()?;
+        if eta == 0 {
+            return Err(TIP20Error::action_not_queued(action_id).into());
+        }
+        if self.storage.timestamp() < U256::from(eta) {
+            return Err(TIP20Error::action_not_ready(action_id, eta).into());
+        }
+
+        self.queued_actions.at(action_id).delete()?;
+        self.emit_event(TIP20Event::ActionExecuted(ITIP20::ActionExecuted {
+            actionId: action_id,
+            executor: msg_sender,
+        }))?;
+
+        match Self::decode_timelocked_action(&call.data)? {
+            ITIP20::ITIP20Calls::changeTransferPolicyId(call) => {
+                self._change_transfer_policy_id(msg_sender, call)
+            }
+            ITIP20::ITIP20Calls::setSupplyCap(call) => self._set_supply_cap(msg_sender, call),
+            ITIP20::ITIP20Calls::setNextQuoteToken(call) => {
+                self._set_next_quote_token(msg_sender, call)
+            }
+            ITIP20::ITIP20Calls::setAdminTimelockDelay(call) => {
+                self._set_admin_timelock_delay(msg_sender, call)
+            }
+            _ => Err(TIP20Error::invalid_timelock_action().into()),
+        }
+    }
+
+    pub fn cancel_action(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP20::cancelActionCall,
+    ) -> Result<()> {
+        self.check_role(msg_sender, DEFAULT_ADMIN_ROLE)?;
+
+        if self.queued_actions.at(call.actionId).read()? == 0 {
+            return Err(TIP20Error::action_not_queued(call.actionId).into());
+        }
+        self.queued_actions.at(call.actionId).delete()?;
+
+        self.emit_event(TIP20Event::ActionCancelled(ITIP20::ActionCancelled {
+            actionId: call.actionId,
+            canceller: msg_sender,
+        }))
+    }
+
+    /// Checks that `msg_sender` is the admin and that no admin timelock is configured, in which
+    /// case timelocked admin functions have to go through `queueAction`/`executeAction`.
+    fn check_timelocked_admin(&self, msg_sender: Address) -> Result<()> {
+        self.check_role(msg_sender, DEFAULT_ADMIN_ROLE)?;
+
+        if self.storage.spec().is_vivace() && self.admin_timelock_delay()? != 0 {
+            return Err(TIP20Error::timelock_required().into());
+        }
+        Ok(())
+    }
+
+    /// Decodes a call to one of the admin functions that are subject to the admin timelock.
+    fn decode_timelocked_action(data: &[u8]) -> Result<ITIP20::ITIP20Calls> {
+        match ITIP20::ITIP20Calls::abi_decode(data) {
+            Ok(
+                action @ (ITIP20::ITIP20Calls::changeTransferPolicyId(_)
+                | ITIP20::ITIP20Calls::setSupplyCap(_)
+                | ITIP20::ITIP20Calls::setNextQuoteToken(_)
+                | ITIP20::ITIP20Calls::setAdminTimelockDelay(_)),
+            ) => Ok(action),
+            _ => Err(TIP20Error::invalid_timelock_action().into()),
+        }
+    }
+
     pub fn complete_quote_token_update(
         &mut self,
         msg_sender: Address,
@@ -415,13 +760,15 @@ impl TIP20Token {
         if self.storage.spec().is_allegretto() {
             let transfer_policy_id = self.transfer_policy_id()?;
             let registry = TIP403Registry::new();
-            if !registry.is_authorized(ITIP403Registry::isAuthorizedCall {
+            if !registry.is_authorized_as(ITIP403Registry::isAuthorizedAsCall {
                 policyId: transfer_policy_id,
                 user: to,
+                role: ITIP403Registry::PolicyRole::MINT_RECIPIENT,
             })? {
                 return Err(TIP20Error::policy_forbids().into());
             }
         }
+        self.check_not_frozen(to)?;
 
         let new_supply = total_supply
             .checked_add(amount)
@@ -513,15 +860,16 @@ impl TIP20Token {
         // Check if the address is blocked from transferring
         let transfer_policy_id = self.transfer_policy_id()?;
         let registry = TIP403Registry::new();
-        if registry.is_authorized(ITIP403Registry::isAuthorizedCall {
+        if registry.is_authorized_as(ITIP403Registry::isAuthorizedAsCall {
             policyId: transfer_policy_id,
             user: call.from,
+            role: ITIP403Registry::PolicyRole::SENDER,
         })? {
             // Only allow burning from addresses that are blocked from transferring
             return Err(TIP20Error::policy_forbids().into());
         }
 
-        self._transfer(call.from, Address::ZERO, call.amount)?;
+        self._move_balance(call.from, Address::ZERO, call.amount)?;
 
         let total_supply = self.total_supply()?;
         let new_supply =
@@ -540,6 +888,51 @@ impl TIP20Token {
         }))
     }
 
+    /// Moves tokens out of an account without its consent, e.g. from a blocked account into a
+    /// custodial address.
+    ///
+    /// The sender is not checked against the transfer policy, but the recipient still has to be
+    /// authorized to receive.
+    pub fn force_transfer(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP20::forceTransferCall,
+    ) -> Result<()> {
+        self.check_role(msg_sender, *FORCE_TRANSFER_ROLE)?;
+
+        // Prevent moving funds out of `FeeManager` and `StablecoinExchange` to protect accounting invariants
+        if matches!(
+            call.from,
+            TIP_FEE_MANAGER_ADDRESS | STABLECOIN_EXCHANGE_ADDRESS
+        ) {
+            return Err(TIP20Error::protected_address().into());
+        }
+
+        self.check_recipient(call.to)?;
+
+        let transfer_policy_id = self.transfer_policy_id()?;
+        let registry = TIP403Registry::new();
+        if !registry.is_authorized_as(ITIP403Registry::isAuthorizedAsCall {
+            policyId: transfer_policy_id,
+            user: call.to,
+            role: ITIP403Registry::PolicyRole::RECIPIENT,
+        })? {
+            return Err(TIP20Error::policy_forbids().into());
+        }
+
+        // Frozen balances can be seized, but not into another frozen account
+        self.check_not_frozen(call.to)?;
+        self._move_balance(call.from, call.to, call.amount)?;
+
+        self.emit_event(TIP20Event::ForcedTransfer(ITIP20::ForcedTransfer {
+            operator: msg_sender,
+            from: call.from,
+            to: call.to,
+            amount: call.amount,
+            memo: call.memo,
+        }))
+    }
+
     fn _burn(&mut self, msg_sender: Address, amount: U256) -> Result<()> {
         self.check_role(msg_sender, *ISSUER_ROLE)?;
 
@@ -590,111 +983,431 @@ impl TIP20Token {
         Ok(true)
     }
 
-    pub fn transfer(&mut self, msg_sender: Address, call: ITIP20::transferCall) -> Result<bool> {
-        trace!(%msg_sender, ?call, "transferring TIP20");
-        self.check_not_paused()?;
-        self.check_recipient(call.to)?;
-        self.ensure_transfer_authorized(msg_sender, call.to)?;
+    /// Returns the current permit nonce of `owner`.
+    pub fn nonces(&self, call: ITIP20::noncesCall) -> Result<U256> {
+        self.nonces.at(call.owner).read()
+    }
 
-        // Only check access keys after Allegretto hardfork
-        if self.storage.spec().is_allegretto() {
-            // Check and update spending limits for access keys
-            let mut keychain = AccountKeychain::new();
-            keychain.authorize_transfer(msg_sender, self.address, call.amount)?;
+    /// Returns the EIP-712 domain separator used to sign permits for this token.
+    ///
+    /// The separator only depends on values that never change, so it is stored in the reserved
+    /// `domain_separator` slot when the token is initialized. Tokens created before Vivace have an
+    /// empty slot and compute it on every call instead.
+    pub fn domain_separator(&self) -> Result<B256> {
+        let domain_separator = self.domain_separator.read()?;
+        if !domain_separator.is_zero() {
+            return Ok(domain_separator);
         }
+        self.compute_domain_separator()
+    }
 
-        self._transfer(msg_sender, call.to, call.amount)?;
-        Ok(true)
+    /// Computes the EIP-712 domain separator from the token name, version `"1"`, the chain ID and
+    /// the token address.
+    fn compute_domain_separator(&self) -> Result<B256> {
+        Ok(keccak256(
+            (
+                *EIP712_DOMAIN_TYPEHASH,
+                keccak256(self.name()?),
+                keccak256(EIP712_VERSION),
+                U256::from(self.storage.chain_id()),
+                self.address,
+            )
+                .abi_encode(),
+        ))
     }
 
-    pub fn transfer_from(
-        &mut self,
-        msg_sender: Address,
-        call: ITIP20::transferFromCall,
-    ) -> Result<bool> {
-        self._transfer_from(msg_sender, call.from, call.to, call.amount)
+    /// EIP-2612 permit: approves `spender` on behalf of `owner` given a secp256k1 signature.
+    pub fn permit(&mut self, call: ITIP20::permitCall) -> Result<()> {
+        self._permit(
+            call.owner,
+            call.spender,
+            call.value,
+            call.deadline,
+            secp256k1_signature(call.v, call.r, call.s)?,
+        )
     }
 
-    /// Transfer from `from` to `to` address with memo attached
-    pub fn transfer_from_with_memo(
+    /// Same as [`Self::permit`], but accepts any encoded [`PrimitiveSignature`], so that P256 and
+    /// WebAuthn accounts can sign permits as well.
+    pub fn permit_with_signature(&mut self, call: ITIP20::permitWithSignatureCall) -> Result<()> {
+        let signature = PrimitiveSignature::from_bytes(&call.signature)
+            .map_err(|_| TIP20Error::invalid_signature())?;
+
+        self._permit(
+            call.owner,
+            call.spender,
+            call.value,
+            call.deadline,
+            signature,
+        )
+    }
+
+    fn _permit(
         &mut self,
-        msg_sender: Address,
-        call: ITIP20::transferFromWithMemoCall,
-    ) -> Result<bool> {
-        self._transfer_from(msg_sender, call.from, call.to, call.amount)?;
+        owner: Address,
+        spender: Address,
+        value: U256,
+        deadline: U256,
+        signature: PrimitiveSignature,
+    ) -> Result<()> {
+        if self.storage.timestamp() > deadline {
+            return Err(TIP20Error::permit_expired().into());
+        }
 
-        // Post-Moderato: call.from address in events, pre-Moderato uses msg_sender
-        let from = if self.storage.spec().is_moderato() {
-            call.from
-        } else {
-            msg_sender
-        };
+        let nonce = self.nonces.at(owner).read()?;
+        let digest = self.permit_digest(owner, spender, value, nonce, deadline)?;
+        self.verify_signer(owner, digest, &signature)?;
 
-        self.emit_event(TIP20Event::TransferWithMemo(ITIP20::TransferWithMemo {
-            from,
-            to: call.to,
-            amount: call.amount,
-            memo: call.memo,
-        }))?;
+        self.nonces.at(owner).write(nonce + U256::ONE)?;
+        self.set_allowance(owner, spender, value)?;
 
-        Ok(true)
+        self.emit_event(TIP20Event::Approval(ITIP20::Approval {
+            owner,
+            spender,
+            amount: value,
+        }))
     }
 
-    /// Transfer from `from` to `to` address without approval requirement
-    /// This function is not exposed via the public interface and should only be invoked by precompiles
-    pub fn system_transfer_from(
-        &mut self,
-        from: Address,
-        to: Address,
-        amount: U256,
-    ) -> Result<bool> {
-        self.check_not_paused()?;
-        self.check_recipient(to)?;
-        self.ensure_transfer_authorized(from, to)?;
-        self.check_spending_limit(from, amount)?;
-
-        self._transfer(from, to, amount)?;
+    /// Returns whether the ERC-3009 authorization `nonce` of `authorizer` has been used or
+    /// canceled.
+    pub fn authorization_state(&self, call: ITIP20::authorizationStateCall) -> Result<bool> {
+        self.authorization_states
+            .at(call.authorizer)
+            .at(call.nonce)
+            .read()
+    }
 
-        Ok(true)
+    /// ERC-3009: executes a transfer signed by `from`, submitted by anyone.
+    pub fn transfer_with_authorization(
+        &mut self,
+        call: ITIP20::transferWithAuthorizationCall,
+    ) -> Result<()> {
+        let authorization = TransferAuthorization {
+            from: call.from,
+            to: call.to,
+            value: call.value,
+            valid_after: call.validAfter,
+            valid_before: call.validBefore,
+            nonce: call.nonce,
+            memo: None,
+        };
+        self._transfer_with_authorization(
+            *TRANSFER_WITH_AUTHORIZATION_TYPEHASH,
+            authorization,
+            secp256k1_signature(call.v, call.r, call.s)?,
+        )
     }
 
-    fn _transfer_from(
+    /// ERC-3009: executes a transfer signed by `from`, which must be submitted by the payee.
+    ///
+    /// This prevents front-running of authorizations that are meant to be used by a contract.
+    pub fn receive_with_authorization(
         &mut self,
         msg_sender: Address,
-        from: Address,
-        to: Address,
-        amount: U256,
-    ) -> Result<bool> {
-        self.check_not_paused()?;
-        self.check_recipient(to)?;
-        self.ensure_transfer_authorized(from, to)?;
-
-        let allowed = self.get_allowance(from, msg_sender)?;
-        if amount > allowed {
-            return Err(TIP20Error::insufficient_allowance().into());
-        }
-
-        if allowed != U256::MAX {
-            let new_allowance = allowed
-                .checked_sub(amount)
-                .ok_or(TIP20Error::insufficient_allowance())?;
-            self.set_allowance(from, msg_sender, new_allowance)?;
+        call: ITIP20::receiveWithAuthorizationCall,
+    ) -> Result<()> {
+        if msg_sender != call.to {
+            return Err(TIP20Error::caller_not_payee().into());
         }
 
-        self._transfer(from, to, amount)?;
-
-        Ok(true)
+        let authorization = TransferAuthorization {
+            from: call.from,
+            to: call.to,
+            value: call.value,
+            valid_after: call.validAfter,
+            valid_before: call.validBefore,
+            nonce: call.nonce,
+            memo: None,
+        };
+        self._transfer_with_authorization(
+            *RECEIVE_WITH_AUTHORIZATION_TYPEHASH,
+            authorization,
+            secp256k1_signature(call.v, call.r, call.s)?,
+        )
     }
 
-    // TIP20 extension functions
-    pub fn transfer_with_memo(
+    /// Same as [`Self::transfer_with_authorization`], with a signed memo attached.
+    pub fn transfer_with_authorization_with_memo(
         &mut self,
-        msg_sender: Address,
-        call: ITIP20::transferWithMemoCall,
+        call: ITIP20::transferWithAuthorizationWithMemoCall,
     ) -> Result<()> {
-        self.check_not_paused()?;
-        self.check_recipient(call.to)?;
-        self.ensure_transfer_authorized(msg_sender, call.to)?;
+        let authorization = TransferAuthorization {
+            from: call.from,
+            to: call.to,
+            value: call.value,
+            valid_after: call.validAfter,
+            valid_before: call.validBefore,
+            nonce: call.nonce,
+            memo: Some(call.memo),
+        };
+        self._transfer_with_authorization(
+            *TRANSFER_WITH_AUTHORIZATION_WITH_MEMO_TYPEHASH,
+            authorization,
+            secp256k1_signature(call.v, call.r, call.s)?,
+        )
+    }
+
+    /// Same as [`Self::receive_with_authorization`], with a signed memo attached.
+    pub fn receive_with_authorization_with_memo(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP20::receiveWithAuthorizationWithMemoCall,
+    ) -> Result<()> {
+        if msg_sender != call.to {
+            return Err(TIP20Error::caller_not_payee().into());
+        }
+
+        let authorization = TransferAuthorization {
+            from: call.from,
+            to: call.to,
+            value: call.value,
+            valid_after: call.validAfter,
+            valid_before: call.validBefore,
+            nonce: call.nonce,
+            memo: Some(call.memo),
+        };
+        self._transfer_with_authorization(
+            *RECEIVE_WITH_AUTHORIZATION_WITH_MEMO_TYPEHASH,
+            authorization,
+            secp256k1_signature(call.v, call.r, call.s)?,
+        )
+    }
+
+    /// ERC-3009: cancels an authorization of `authorizer` that has not been used yet.
+    pub fn cancel_authorization(&mut self, call: ITIP20::cancelAuthorizationCall) -> Result<()> {
+        self.check_authorization_unused(call.authorizer, call.nonce)?;
+
+        let struct_hash =
+            keccak256((*CANCEL_AUTHORIZATION_TYPEHASH, call.authorizer, call.nonce).abi_encode());
+        let digest = self.hash_typed_data(struct_hash)?;
+        self.verify_signer(
+            call.authorizer,
+            digest,
+            &secp256k1_signature(call.v, call.r, call.s)?,
+        )?;
+
+        self.authorization_states
+            .at(call.authorizer)
+            .at(call.nonce)
+            .write(true)?;
+
+        self.emit_event(TIP20Event::AuthorizationCanceled(
+            ITIP20::AuthorizationCanceled {
+                authorizer: call.authorizer,
+                nonce: call.nonce,
+            },
+        ))
+    }
+
+    fn _transfer_with_authorization(
+        &mut self,
+        typehash: B256,
+        authorization: TransferAuthorization,
+        signature: PrimitiveSignature,
+    ) -> Result<()> {
+        let TransferAuthorization {
+            from, to, value, ..
+        } = authorization;
+
+        let timestamp = self.storage.timestamp();
+        if timestamp <= authorization.valid_after {
+            return Err(TIP20Error::authorization_not_yet_valid().into());
+        }
+        if timestamp >= authorization.valid_before {
+            return Err(TIP20Error::authorization_expired().into());
+        }
+        self.check_authorization_unused(from, authorization.nonce)?;
+
+        let digest = self.hash_typed_data(authorization.struct_hash(typehash))?;
+        self.verify_signer(from, digest, &signature)?;
+
+        self.authorization_states
+            .at(from)
+            .at(authorization.nonce)
+            .write(true)?;
+        self.emit_event(TIP20Event::AuthorizationUsed(ITIP20::AuthorizationUsed {
+            authorizer: from,
+            nonce: authorization.nonce,
+        }))?;
+
+        self.check_not_paused()?;
+        self.check_recipient(to)?;
+        self.ensure_transfer_authorized(from, to)?;
+
+        self._transfer(from, to, value)?;
+
+        if let Some(memo) = authorization.memo {
+            self.emit_event(TIP20Event::TransferWithMemo(ITIP20::TransferWithMemo {
+                from,
+                to,
+                amount: value,
+                memo,
+            }))?;
+        }
+        Ok(())
+    }
+
+    fn check_authorization_unused(&self, authorizer: Address, nonce: B256) -> Result<()> {
+        if self.authorization_states.at(authorizer).at(nonce).read()? {
+            return Err(TIP20Error::authorization_already_used().into());
+        }
+        Ok(())
+    }
+
+    /// Returns the EIP-712 hash of a struct signed for this token.
+    fn hash_typed_data(&self, struct_hash: B256) -> Result<B256> {
+        Ok(keccak256(
+            [
+                &[0x19, 0x01][..],
+                self.domain_separator()?.as_slice(),
+                struct_hash.as_slice(),
+            ]
+            .concat(),
+        ))
+    }
+
+    /// Charges the verification gas and checks that `signature` was produced by `signer` over
+    /// `digest`.
+    fn verify_signer(
+        &mut self,
+        signer: Address,
+        digest: B256,
+        signature: &PrimitiveSignature,
+    ) -> Result<()> {
+        self.storage.deduct_gas(match signature {
+            PrimitiveSignature::Secp256k1(_) => ECRECOVER_GAS,
+            PrimitiveSignature::P256(_) | PrimitiveSignature::WebAuthn(_) => P256_VERIFY_GAS,
+        })?;
+
+        // Recovery also rejects high-s secp256k1 signatures
+        let recovered = signature
+            .recover_signer(&digest)
+            .map_err(|_| TIP20Error::invalid_signature())?;
+        if recovered != signer {
+            return Err(TIP20Error::invalid_signature().into());
+        }
+        Ok(())
+    }
+
+    /// Returns the EIP-712 hash of a `Permit` that the owner has to sign.
+    fn permit_digest(
+        &self,
+        owner: Address,
+        spender: Address,
+        value: U256,
+        nonce: U256,
+        deadline: U256,
+    ) -> Result<B256> {
+        let struct_hash =
+            keccak256((*PERMIT_TYPEHASH, owner, spender, value, nonce, deadline).abi_encode());
+        self.hash_typed_data(struct_hash)
+    }
+
+    pub fn transfer(&mut self, msg_sender: Address, call: ITIP20::transferCall) -> Result<bool> {
+        trace!(%msg_sender, ?call, "transferring TIP20");
+        self.check_not_paused()?;
+        self.check_recipient(call.to)?;
+        self.ensure_transfer_authorized(msg_sender, call.to)?;
+
+        // Only check access keys after Allegretto hardfork
+        if self.storage.spec().is_allegretto() {
+            // Check and update spending limits for access keys
+            let mut keychain = AccountKeychain::new();
+            keychain.authorize_transfer(msg_sender, self.address, call.amount)?;
+        }
+
+        self._transfer(msg_sender, call.to, call.amount)?;
+        Ok(true)
+    }
+
+    pub fn transfer_from(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP20::transferFromCall,
+    ) -> Result<bool> {
+        self._transfer_from(msg_sender, call.from, call.to, call.amount)
+    }
+
+    /// Transfer from `from` to `to` address with memo attached
+    pub fn transfer_from_with_memo(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP20::transferFromWithMemoCall,
+    ) -> Result<bool> {
+        self._transfer_from(msg_sender, call.from, call.to, call.amount)?;
+
+        // Post-Moderato: call.from address in events, pre-Moderato uses msg_sender
+        let from = if self.storage.spec().is_moderato() {
+            call.from
+        } else {
+            msg_sender
+        };
+
+        self.emit_event(TIP20Event::TransferWithMemo(ITIP20::TransferWithMemo {
+            from,
+            to: call.to,
+            amount: call.amount,
+            memo: call.memo,
+        }))?;
+
+        Ok(true)
+    }
+
+    /// Transfer from `from` to `to` address without approval requirement
+    /// This function is not exposed via the public interface and should only be invoked by precompiles
+    pub fn system_transfer_from(
+        &mut self,
+        from: Address,
+        to: Address,
+        amount: U256,
+    ) -> Result<bool> {
+        self.check_not_paused()?;
+        self.check_recipient(to)?;
+        self.ensure_transfer_authorized(from, to)?;
+        self.check_spending_limit(from, amount)?;
+
+        self._transfer(from, to, amount)?;
+
+        Ok(true)
+    }
+
+    fn _transfer_from(
+        &mut self,
+        msg_sender: Address,
+        from: Address,
+        to: Address,
+        amount: U256,
+    ) -> Result<bool> {
+        self.check_not_paused()?;
+        self.check_recipient(to)?;
+        self.ensure_transfer_authorized(from, to)?;
+
+        let allowed = self.get_allowance(from, msg_sender)?;
+        if amount > allowed {
+            return Err(TIP20Error::insufficient_allowance().into());
+        }
+
+        if allowed != U256::MAX {
+            let new_allowance = allowed
+                .checked_sub(amount)
+                .ok_or(TIP20Error::insufficient_allowance())?;
+            self.set_allowance(from, msg_sender, new_allowance)?;
+        }
+
+        self._transfer(from, to, amount)?;
+
+        Ok(true)
+    }
+
+    // TIP20 extension functions
+    pub fn transfer_with_memo(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP20::transferWithMemoCall,
+    ) -> Result<()> {
+        self.check_not_paused()?;
+        self.check_recipient(call.to)?;
+        self.ensure_transfer_authorized(msg_sender, call.to)?;
         self.check_spending_limit(msg_sender, call.amount)?;
 
         self._transfer(msg_sender, call.to, call.amount)?;
@@ -706,6 +1419,115 @@ impl TIP20Token {
             memo: call.memo,
         }))
     }
+
+    /// Transfers to many recipients at once, e.g. for payroll.
+    ///
+    /// The sender is checked against the transfer policy and debited once for the whole batch,
+    /// while every recipient is checked and credited individually and gets its own
+    /// `TransferWithMemo` event. The sender's rewards are settled once, and the opted-in supply is
+    /// updated once with the net change of the batch.
+    pub fn transfer_batch(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP20::transferBatchCall,
+    ) -> Result<()> {
+        let ITIP20::transferBatchCall { to, amounts, memos } = call;
+        if to.is_empty()
+            || to.len() > MAX_TRANSFER_BATCH_SIZE
+            || to.len() != amounts.len()
+            || to.len() != memos.len()
+        {
+            return Err(TIP20Error::invalid_payload().into());
+        }
+
+        self.check_not_paused()?;
+        self.check_not_frozen(msg_sender)?;
+
+        let transfer_policy_id = self.transfer_policy_id()?;
+        let registry = TIP403Registry::new();
+        if !registry.is_authorized_as(ITIP403Registry::isAuthorizedAsCall {
+            policyId: transfer_policy_id,
+            user: msg_sender,
+            role: ITIP403Registry::PolicyRole::SENDER,
+        })? {
+            return Err(TIP20Error::policy_forbids().into());
+        }
+
+        let total = amounts
+            .iter()
+            .try_fold(U256::ZERO, |total, amount| total.checked_add(*amount))
+            .ok_or(TempoPrecompileError::under_overflow())?;
+        self.check_spending_limit(msg_sender, total)?;
+
+        let from_balance = self.get_balance(msg_sender)?;
+        if total > from_balance {
+            return Err(TIP20Error::insufficient_balance(from_balance, total, self.address).into());
+        }
+
+        // Accrue before balance changes
+        let timestamp = self.storage.timestamp();
+        self.accrue(timestamp)?;
+
+        // Settle the sender's rewards once for the whole batch, only recipients are settled below
+        let from_delegate = self.update_rewards(msg_sender)?;
+        let mut opted_in_added = U256::ZERO;
+        let mut opted_in_removed = U256::ZERO;
+
+        for ((to, amount), memo) in to.into_iter().zip(amounts).zip(memos) {
+            self.check_recipient(to)?;
+            self.check_not_frozen(to)?;
+            if !registry.is_authorized_as(ITIP403Registry::isAuthorizedAsCall {
+                policyId: transfer_policy_id,
+                user: to,
+                role: ITIP403Registry::PolicyRole::RECIPIENT,
+            })? {
+                return Err(TIP20Error::policy_forbids().into());
+            }
+
+            let to_delegate = self.update_rewards(to)?;
+            match (from_delegate.is_zero(), to_delegate.is_zero()) {
+                (false, true) => opted_in_removed += amount,
+                (true, false) => opted_in_added += amount,
+                _ => {}
+            }
+
+            let to_balance = self.get_balance(to)?;
+            let new_to_balance = to_balance
+                .checked_add(amount)
+                .ok_or(TempoPrecompileError::under_overflow())?;
+            self.set_balance(to, new_to_balance)?;
+
+            self.emit_event(TIP20Event::Transfer(ITIP20::Transfer {
+                from: msg_sender,
+                to,
+                amount,
+            }))?;
+            self.emit_event(TIP20Event::TransferWithMemo(ITIP20::TransferWithMemo {
+                from: msg_sender,
+                to,
+                amount,
+                memo,
+            }))?;
+        }
+
+        if opted_in_added != opted_in_removed {
+            let opted_in_supply = (U256::from(self.get_opted_in_supply()?) + opted_in_added)
+                .checked_sub(opted_in_removed)
+                .ok_or(TempoPrecompileError::under_overflow())?;
+            self.set_opted_in_supply(
+                opted_in_supply
+                    .try_into()
+                    .map_err(|_| TempoPrecompileError::under_overflow())?,
+            )?;
+        }
+
+        // Re-read the balance, as the sender may also be one of the recipients
+        let from_balance = self.get_balance(msg_sender)?;
+        let new_from_balance = from_balance
+            .checked_sub(total)
+            .ok_or(TempoPrecompileError::under_overflow())?;
+        self.set_balance(msg_sender, new_from_balance)
+    }
 }
 
 // Utility functions
@@ -772,6 +1594,10 @@ impl TIP20Token {
         if self.storage.spec().is_allegretto() {
             self.fee_recipient.write(fee_recipient)?;
         }
+        if self.storage.spec().is_vivace() {
+            let domain_separator = self.compute_domain_separator()?;
+            self.domain_separator.write(domain_separator)?;
+        }
 
         // Initialize roles system and grant admin role
         self.initialize_roles()?;
@@ -805,6 +1631,17 @@ impl TIP20Token {
         Ok(())
     }
 
+    /// Ensures the account is not frozen. Only active after the Vivace hardfork.
+    fn check_not_frozen(&self, account: Address) -> Result<()> {
+        if self.storage.spec().is_vivace()
+            && !account.is_zero()
+            && self.frozen.at(account).read()?
+        {
+            return Err(TIP20Error::account_frozen(account).into());
+        }
+        Ok(())
+    }
+
     /// Validates that the recipient is not:
     /// - the zero address (preventing accidental burns)
     /// - another TIP20 token
@@ -816,23 +1653,16 @@ impl TIP20Token {
     }
 
     /// Checks if the transfer is authorized.
+    ///
+    /// `from` is checked against the sender rule and `to` against the recipient rule of the
+    /// transfer policy, which are the same for non-directional policies.
     pub fn is_transfer_authorized(&self, from: Address, to: Address) -> Result<bool> {
         let transfer_policy_id = self.transfer_policy_id()?;
-        let registry = TIP403Registry::new();
-
-        // Check if 'from' address is authorized
-        let from_authorized = registry.is_authorized(ITIP403Registry::isAuthorizedCall {
-            policyId: transfer_policy_id,
-            user: from,
-        })?;
-
-        // Check if 'to' address is authorized
-        let to_authorized = registry.is_authorized(ITIP403Registry::isAuthorizedCall {
+        TIP403Registry::new().is_transfer_authorized(ITIP403Registry::isTransferAuthorizedCall {
             policyId: transfer_policy_id,
-            user: to,
-        })?;
-
-        Ok(from_authorized && to_authorized)
+            from,
+            to,
+        })
     }
 
     /// Ensures the transfer is authorized.
@@ -855,6 +1685,16 @@ impl TIP20Token {
     }
 
     fn _transfer(&mut self, from: Address, to: Address, amount: U256) -> Result<()> {
+        self.check_not_frozen(from)?;
+        self.check_not_frozen(to)?;
+
+        self._move_balance(from, to, amount)
+    }
+
+    /// Moves `amount` from `from` to `to`, updating rewards. Burns if `to` is the zero address.
+    ///
+    /// Does not check freezes, so that frozen balances can still be seized or burned.
+    fn _move_balance(&mut self, from: Address, to: Address, amount: U256) -> Result<()> {
         let from_balance = self.get_balance(from)?;
         if amount > from_balance {
             return Err(
@@ -898,6 +1738,7 @@ impl TIP20Token {
         }
 
         self.check_spending_limit(from, amount)?;
+        self.check_not_frozen(from)?;
 
         // Handle rewards (only after Moderato hardfork)
         if self.storage.spec().is_moderato() {
@@ -1006,7 +1847,10 @@ impl TIP20Token {
 
 #[cfg(test)]
 pub(crate) mod tests {
-    use alloy::primitives::{Address, FixedBytes, IntoLogData, U256};
+    use alloy::{
+        primitives::{Address, Bytes, FixedBytes, IntoLogData, U256},
+        sol_types::SolCall,
+    };
     use tempo_chainspec::hardfork::TempoHardfork;
     use tempo_contracts::precompiles::{DEFAULT_FEE_TOKEN_POST_ALLEGRETTO, ITIP20Factory};
 
@@ -2753,28 +3597,584 @@ pub(crate) mod tests {
     }
 
     #[test]
-    fn test_transfer_invalid_recipient_pre_allegro_moderato() -> eyre::Result<()> {
-        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Allegretto);
+    fn test_freeze() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
         let admin = Address::random();
+        let freezer = Address::random();
+        let alice = Address::random();
         let bob = Address::random();
         let amount = U256::from(1000);
 
         StorageCtx::enter(&mut storage, || {
             let mut token = TIP20Setup::create("Token", "TKN", admin)
                 .with_issuer(admin)
-                .with_mint(admin, amount + amount)
-                .with_approval(admin, bob, amount)
+                .with_role(freezer, *FREEZE_ROLE)
+                .with_role(admin, *FORCE_TRANSFER_ROLE)
+                .with_mint(alice, amount)
+                .with_mint(bob, amount)
                 .apply()?;
 
-            token.transfer(
-                admin,
-                ITIP20::transferCall {
-                    to: Address::ZERO,
-                    amount,
-                },
-            )?;
-
-            token.transfer_from(
+            // Only accounts with FREEZE_ROLE can freeze
+            let result = token.freeze(admin, ITIP20::freezeCall { account: alice });
+            assert!(matches!(
+                result,
+                Err(TempoPrecompileError::RolesAuthError(
+                    RolesAuthError::Unauthorized(_)
+                ))
+            ));
+
+            token.freeze(freezer, ITIP20::freezeCall { account: alice })?;
+            assert!(token.is_frozen(ITIP20::isFrozenCall { account: alice })?);
+            assert_eq!(
+                token.emitted_events().last(),
+                Some(
+                    &TIP20Event::FreezeStateUpdate(ITIP20::FreezeStateUpdate {
+                        updater: freezer,
+                        account: alice,
+                        isFrozen: true,
+                    })
+                    .into_log_data()
+                )
+            );
+
+            // Frozen accounts can neither send, receive, be minted to nor pay fees
+            fn frozen_err<T>(result: Result<T>) -> bool {
+                matches!(
+                    result,
+                    Err(TempoPrecompileError::TIP20(TIP20Error::AccountFrozen(_)))
+                )
+            }
+            assert!(frozen_err(token.transfer(
+                alice,
+                ITIP20::transferCall {
+                    to: bob,
+                    amount: U256::ONE
+                }
+            )));
+            assert!(frozen_err(token.transfer(
+                bob,
+                ITIP20::transferCall {
+                    to: alice,
+                    amount: U256::ONE
+                }
+            )));
+            assert!(frozen_err(
+                token.mint(admin, ITIP20::mintCall { to: alice, amount })
+            ));
+            assert!(frozen_err(token.transfer_fee_pre_tx(alice, U256::ONE)));
+
+            // Frozen balances can still be seized
+            token.force_transfer(
+                admin,
+                ITIP20::forceTransferCall {
+                    from: alice,
+                    to: bob,
+                    amount: U256::ONE,
+                    memo: B256::ZERO,
+                },
+            )?;
+            assert_eq!(token.get_balance(bob)?, amount + U256::ONE);
+
+            // Protocol accounts cannot be frozen
+            let result = token.freeze(
+                freezer,
+                ITIP20::freezeCall {
+                    account: TIP_FEE_MANAGER_ADDRESS,
+                },
+            );
+            assert!(matches!(
+                result,
+                Err(TempoPrecompileError::TIP20(TIP20Error::ProtectedAddress(_)))
+            ));
+
+            token.unfreeze(freezer, ITIP20::unfreezeCall { account: alice })?;
+            assert!(!token.is_frozen(ITIP20::isFrozenCall { account: alice })?);
+            token.transfer(
+                alice,
+                ITIP20::transferCall {
+                    to: bob,
+                    amount: U256::ONE,
+                },
+            )?;
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_force_transfer() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        let admin = Address::random();
+        let operator = Address::random();
+        let blocked = Address::random();
+        let custodian = Address::random();
+        let sanctioned = Address::random();
+        let amount = U256::from(1000);
+        let memo = FixedBytes::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("Token", "TKN", admin)
+                .with_issuer(admin)
+                .with_role(operator, *FORCE_TRANSFER_ROLE)
+                .with_mint(blocked, amount)
+                .apply()?;
+
+            let mut registry = TIP403Registry::new();
+            let policy_id = registry.create_policy_with_accounts(
+                admin,
+                ITIP403Registry::createPolicyWithAccountsCall {
+                    admin,
+                    policyType: ITIP403Registry::PolicyType::BLACKLIST,
+                    accounts: vec![blocked, sanctioned],
+                },
+            )?;
+            token.change_transfer_policy_id(
+                admin,
+                ITIP20::changeTransferPolicyIdCall {
+                    newPolicyId: policy_id,
+                },
+            )?;
+
+            // Only accounts with FORCE_TRANSFER_ROLE can force transfers
+            let result = token.force_transfer(
+                admin,
+                ITIP20::forceTransferCall {
+                    from: blocked,
+                    to: custodian,
+                    amount,
+                    memo,
+                },
+            );
+            assert!(matches!(
+                result,
+                Err(TempoPrecompileError::RolesAuthError(
+                    RolesAuthError::Unauthorized(_)
+                ))
+            ));
+
+            // The recipient must still be allowed to receive
+            let result = token.force_transfer(
+                operator,
+                ITIP20::forceTransferCall {
+                    from: blocked,
+                    to: sanctioned,
+                    amount,
+                    memo,
+                },
+            );
+            assert!(matches!(
+                result,
+                Err(TempoPrecompileError::TIP20(TIP20Error::PolicyForbids(_)))
+            ));
+
+            // Funds can be moved out of a blocked account
+            token.force_transfer(
+                operator,
+                ITIP20::forceTransferCall {
+                    from: blocked,
+                    to: custodian,
+                    amount,
+                    memo,
+                },
+            )?;
+            assert_eq!(token.get_balance(blocked)?, U256::ZERO);
+            assert_eq!(token.get_balance(custodian)?, amount);
+            assert_eq!(token.total_supply()?, amount);
+            assert_eq!(
+                token.emitted_events().last(),
+                Some(
+                    &TIP20Event::ForcedTransfer(ITIP20::ForcedTransfer {
+                        operator,
+                        from: blocked,
+                        to: custodian,
+                        amount,
+                        memo,
+                    })
+                    .into_log_data()
+                )
+            );
+
+            // Protocol accounts cannot be drained
+            let result = token.force_transfer(
+                operator,
+                ITIP20::forceTransferCall {
+                    from: TIP_FEE_MANAGER_ADDRESS,
+                    to: custodian,
+                    amount,
+                    memo,
+                },
+            );
+            assert!(matches!(
+                result,
+                Err(TempoPrecompileError::TIP20(TIP20Error::ProtectedAddress(_)))
+            ));
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_transfer_batch() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        let admin = Address::random();
+        let sender = Address::random();
+        let sanctioned = Address::random();
+        let recipients: Vec<Address> = (0..3).map(|_| Address::random()).collect();
+        let amounts: Vec<U256> = (1..=3).map(|i| U256::from(i * 100)).collect();
+        let memos: Vec<B256> = (0..3).map(|_| B256::random()).collect();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("Token", "TKN", admin)
+                .with_issuer(admin)
+                .with_mint(sender, U256::from(1000))
+                .apply()?;
+
+            // Mismatched lengths are rejected
+            let result = token.transfer_batch(
+                sender,
+                ITIP20::transferBatchCall {
+                    to: recipients.clone(),
+                    amounts: amounts[..2].to_vec(),
+                    memos: memos.clone(),
+                },
+            );
+            assert!(matches!(
+                result,
+                Err(TempoPrecompileError::TIP20(TIP20Error::InvalidPayload(_)))
+            ));
+
+            token.transfer_batch(
+                sender,
+                ITIP20::transferBatchCall {
+                    to: recipients.clone(),
+                    amounts: amounts.clone(),
+                    memos: memos.clone(),
+                },
+            )?;
+            assert_eq!(token.get_balance(sender)?, U256::from(400));
+            for (recipient, amount) in recipients.iter().zip(&amounts) {
+                assert_eq!(token.get_balance(*recipient)?, *amount);
+            }
+            assert_eq!(
+                token.emitted_events().last(),
+                Some(
+                    &TIP20Event::TransferWithMemo(ITIP20::TransferWithMemo {
+                        from: sender,
+                        to: recipients[2],
+                        amount: amounts[2],
+                        memo: memos[2],
+                    })
+                    .into_log_data()
+                )
+            );
+
+            // The batch cannot exceed the sender's balance
+            let result = token.transfer_batch(
+                sender,
+                ITIP20::transferBatchCall {
+                    to: recipients.clone(),
+                    amounts: amounts.clone(),
+                    memos: memos.clone(),
+                },
+            );
+            assert!(matches!(
+                result,
+                Err(TempoPrecompileError::TIP20(
+                    TIP20Error::InsufficientBalance(_)
+                ))
+            ));
+
+            // The opted-in supply only changes by what leaves the opted-in set
+            token.set_reward_recipient(
+                sender,
+                ITIP20::setRewardRecipientCall { recipient: sender },
+            )?;
+            token.set_reward_recipient(
+                recipients[0],
+                ITIP20::setRewardRecipientCall {
+                    recipient: recipients[0],
+                },
+            )?;
+            assert_eq!(token.get_opted_in_supply()?, 500);
+            token.transfer_batch(
+                sender,
+                ITIP20::transferBatchCall {
+                    to: vec![recipients[0], recipients[1]],
+                    amounts: vec![U256::from(10), U256::from(20)],
+                    memos: vec![B256::ZERO, B256::ZERO],
+                },
+            )?;
+            assert_eq!(token.get_opted_in_supply()?, 480);
+
+            // Every recipient is checked against the transfer policy
+            let mut registry = TIP403Registry::new();
+            let policy_id = registry.create_policy_with_accounts(
+                admin,
+                ITIP403Registry::createPolicyWithAccountsCall {
+                    admin,
+                    policyType: ITIP403Registry::PolicyType::BLACKLIST,
+                    accounts: vec![sanctioned],
+                },
+            )?;
+            token.change_transfer_policy_id(
+                admin,
+                ITIP20::changeTransferPolicyIdCall {
+                    newPolicyId: policy_id,
+                },
+            )?;
+            let result = token.transfer_batch(
+                sender,
+                ITIP20::transferBatchCall {
+                    to: vec![recipients[0], sanctioned],
+                    amounts: vec![U256::ONE, U256::ONE],
+                    memos: vec![B256::ZERO, B256::ZERO],
+                },
+            );
+            assert!(matches!(
+                result,
+                Err(TempoPrecompileError::TIP20(TIP20Error::PolicyForbids(_)))
+            ));
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_admin_timelock() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        let admin = Address::random();
+        let delay = 3600;
+
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("Token", "TKN", admin).apply()?;
+            StorageCtx.set_timestamp(U256::from(1000));
+
+            // Enabling the timelock takes effect immediately
+            token.set_admin_timelock_delay(admin, ITIP20::setAdminTimelockDelayCall { delay })?;
+            assert_eq!(token.admin_timelock_delay()?, delay);
+
+            // Timelocked admin functions can no longer be called directly
+            let set_cap = ITIP20::setSupplyCapCall {
+                newSupplyCap: U256::from(500),
+            };
+            let result = token.set_supply_cap(admin, set_cap.clone());
+            assert!(matches!(
+                result,
+                Err(TempoPrecompileError::TIP20(TIP20Error::TimelockRequired(_)))
+            ));
+
+            // Only timelocked admin functions can be queued
+            let result = token.queue_action(
+                admin,
+                ITIP20::queueActionCall {
+                    data: ITIP20::pauseCall {}.abi_encode().into(),
+                },
+            );
+            assert!(matches!(
+                result,
+                Err(TempoPrecompileError::TIP20(
+                    TIP20Error::InvalidTimelockAction(_)
+                ))
+            ));
+
+            let data: Bytes = set_cap.abi_encode().into();
+            let action_id =
+                token.queue_action(admin, ITIP20::queueActionCall { data: data.clone() })?;
+            let eta = 1000 + delay;
+            assert_eq!(
+                token.queued_action_eta(ITIP20::queuedActionEtaCall {
+                    actionId: action_id
+                })?,
+                eta
+            );
+            assert_eq!(
+                token.emitted_events().last(),
+                Some(
+                    &TIP20Event::ActionQueued(ITIP20::ActionQueued {
+                        actionId: action_id,
+                        queuer: admin,
+                        data: data.clone(),
+                        eta,
+                    })
+                    .into_log_data()
+                )
+            );
+
+            // The action cannot be executed before its eta
+            let result =
+                token.execute_action(admin, ITIP20::executeActionCall { data: data.clone() });
+            assert!(matches!(
+                result,
+                Err(TempoPrecompileError::TIP20(TIP20Error::ActionNotReady(_)))
+            ));
+
+            StorageCtx.set_timestamp(U256::from(eta));
+            token.execute_action(admin, ITIP20::executeActionCall { data: data.clone() })?;
+            assert_eq!(token.supply_cap()?, U256::from(500));
+            assert_eq!(
+                token.queued_action_eta(ITIP20::queuedActionEtaCall {
+                    actionId: action_id
+                })?,
+                0
+            );
+
+            // Executed actions cannot be replayed
+            let result = token.execute_action(admin, ITIP20::executeActionCall { data });
+            assert!(matches!(
+                result,
+                Err(TempoPrecompileError::TIP20(TIP20Error::ActionNotQueued(_)))
+            ));
+
+            // Queued actions can be cancelled
+            let data: Bytes = ITIP20::setAdminTimelockDelayCall { delay: 0 }
+                .abi_encode()
+                .into();
+            let action_id =
+                token.queue_action(admin, ITIP20::queueActionCall { data: data.clone() })?;
+            token.cancel_action(
+                admin,
+                ITIP20::cancelActionCall {
+                    actionId: action_id,
+                },
+            )?;
+            assert_eq!(
+                token.emitted_events().last(),
+                Some(
+                    &TIP20Event::ActionCancelled(ITIP20::ActionCancelled {
+                        actionId: action_id,
+                        canceller: admin,
+                    })
+                    .into_log_data()
+                )
+            );
+            StorageCtx.set_timestamp(U256::from(eta + delay));
+            let result = token.execute_action(admin, ITIP20::executeActionCall { data });
+            assert!(matches!(
+                result,
+                Err(TempoPrecompileError::TIP20(TIP20Error::ActionNotQueued(_)))
+            ));
+            assert_eq!(token.admin_timelock_delay()?, delay);
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_directional_transfer_policy() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        let admin = Address::random();
+        let alice = Address::random();
+        let bob = Address::random();
+        let sanctioned = Address::random();
+        let amount = U256::from(1000);
+
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("Token", "TKN", admin)
+                .with_issuer(admin)
+                .with_mint(alice, amount)
+                .apply()?;
+
+            let mut registry = TIP403Registry::new();
+            let kyc = registry.create_policy_with_accounts(
+                admin,
+                ITIP403Registry::createPolicyWithAccountsCall {
+                    admin,
+                    policyType: ITIP403Registry::PolicyType::WHITELIST,
+                    accounts: vec![alice],
+                },
+            )?;
+            let sanctions = registry.create_policy_with_accounts(
+                admin,
+                ITIP403Registry::createPolicyWithAccountsCall {
+                    admin,
+                    policyType: ITIP403Registry::PolicyType::BLACKLIST,
+                    accounts: vec![sanctioned],
+                },
+            )?;
+            let policy_id = registry.create_directional_policy(
+                admin,
+                ITIP403Registry::createDirectionalPolicyCall {
+                    admin,
+                    senderPolicyId: kyc,
+                    recipientPolicyId: sanctions,
+                    mintRecipientPolicyId: kyc,
+                },
+            )?;
+            token.change_transfer_policy_id(
+                admin,
+                ITIP20::changeTransferPolicyIdCall {
+                    newPolicyId: policy_id,
+                },
+            )?;
+
+            // KYC'd senders can pay anyone who is not sanctioned
+            token.transfer(
+                alice,
+                ITIP20::transferCall {
+                    to: bob,
+                    amount: U256::from(1),
+                },
+            )?;
+            let result = token.transfer(
+                alice,
+                ITIP20::transferCall {
+                    to: sanctioned,
+                    amount: U256::from(1),
+                },
+            );
+            assert!(matches!(
+                result,
+                Err(TempoPrecompileError::TIP20(TIP20Error::PolicyForbids(_)))
+            ));
+
+            // Bob is not KYC'd, so transfers from Bob are rejected
+            let result = token.transfer(
+                bob,
+                ITIP20::transferCall {
+                    to: alice,
+                    amount: U256::from(1),
+                },
+            );
+            assert!(matches!(
+                result,
+                Err(TempoPrecompileError::TIP20(TIP20Error::PolicyForbids(_)))
+            ));
+
+            // Mints follow the mint recipient rule
+            let result = token.mint(admin, ITIP20::mintCall { to: bob, amount });
+            assert!(matches!(
+                result,
+                Err(TempoPrecompileError::TIP20(TIP20Error::PolicyForbids(_)))
+            ));
+            token.mint(admin, ITIP20::mintCall { to: alice, amount })?;
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_transfer_invalid_recipient_pre_allegro_moderato() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Allegretto);
+        let admin = Address::random();
+        let bob = Address::random();
+        let amount = U256::from(1000);
+
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("Token", "TKN", admin)
+                .with_issuer(admin)
+                .with_mint(admin, amount + amount)
+                .with_approval(admin, bob, amount)
+                .apply()?;
+
+            token.transfer(
+                admin,
+                ITIP20::transferCall {
+                    to: Address::ZERO,
+                    amount,
+                },
+            )?;
+
+            token.transfer_from(
                 bob,
                 ITIP20::transferFromCall {
                     from: admin,
@@ -2823,4 +4223,423 @@ pub(crate) mod tests {
             Ok(())
         })
     }
+
+    /// Signs `digest` with `signer`, returning the `v`, `r` and `s` components.
+    fn sign_digest(
+        signer: &alloy_signer_local::PrivateKeySigner,
+        digest: B256,
+    ) -> eyre::Result<(u8, B256, B256)> {
+        use alloy_signer::SignerSync;
+
+        let signature = signer.sign_hash_sync(&digest)?;
+        Ok((
+            27 + signature.v() as u8,
+            signature.r().into(),
+            signature.s().into(),
+        ))
+    }
+
+    fn sign_permit(
+        token: &TIP20Token,
+        signer: &alloy_signer_local::PrivateKeySigner,
+        spender: Address,
+        value: U256,
+        deadline: U256,
+    ) -> eyre::Result<ITIP20::permitCall> {
+        let owner = signer.address();
+        let nonce = token.nonces(ITIP20::noncesCall { owner })?;
+        let digest = token.permit_digest(owner, spender, value, nonce, deadline)?;
+        let (v, r, s) = sign_digest(signer, digest)?;
+
+        Ok(ITIP20::permitCall {
+            owner,
+            spender,
+            value,
+            deadline,
+            v,
+            r,
+            s,
+        })
+    }
+
+    #[test]
+    fn test_permit() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        let admin = Address::random();
+        let signer = alloy_signer_local::PrivateKeySigner::random();
+        let owner = signer.address();
+        let spender = Address::random();
+        let value = U256::from(500);
+        let deadline = U256::from(1_000);
+
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("Token", "TKN", admin).apply()?;
+            StorageCtx.set_timestamp(U256::from(100));
+
+            let call = sign_permit(&token, &signer, spender, value, deadline)?;
+            token.permit(call.clone())?;
+
+            assert_eq!(
+                token.allowance(ITIP20::allowanceCall { owner, spender })?,
+                value
+            );
+            assert_eq!(token.nonces(ITIP20::noncesCall { owner })?, U256::ONE);
+            assert_eq!(
+                token.emitted_events().last().unwrap(),
+                &TIP20Event::Approval(ITIP20::Approval {
+                    owner,
+                    spender,
+                    amount: value,
+                })
+                .into_log_data()
+            );
+
+            // The nonce was consumed, so the same signature can't be replayed
+            assert!(matches!(
+                token.permit(call),
+                Err(TempoPrecompileError::TIP20(TIP20Error::InvalidSignature(_)))
+            ));
+
+            // Signed by someone else than the owner
+            let mut call = sign_permit(&token, &signer, spender, value, deadline)?;
+            call.owner = Address::random();
+            assert!(matches!(
+                token.permit(call),
+                Err(TempoPrecompileError::TIP20(TIP20Error::InvalidSignature(_)))
+            ));
+
+            let call = sign_permit(&token, &signer, spender, value, deadline)?;
+            StorageCtx.set_timestamp(deadline + U256::ONE);
+            assert!(matches!(
+                token.permit(call),
+                Err(TempoPrecompileError::TIP20(TIP20Error::PermitExpired(_)))
+            ));
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_domain_separator_stored_post_vivace() -> eyre::Result<()> {
+        let admin = Address::random();
+
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        StorageCtx::enter(&mut storage, || {
+            let token = TIP20Setup::create("Token", "TKN", admin).apply()?;
+            assert_eq!(
+                token.domain_separator.read()?,
+                token.compute_domain_separator()?
+            );
+            assert_eq!(token.domain_separator()?, token.compute_domain_separator()?);
+            Ok::<_, eyre::Report>(())
+        })?;
+
+        // Tokens created before Vivace leave the slot empty and compute the separator instead
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::AllegroModerato);
+        StorageCtx::enter(&mut storage, || {
+            let token = TIP20Setup::create("Token", "TKN", admin).apply()?;
+            assert!(token.domain_separator.read()?.is_zero());
+            assert_eq!(token.domain_separator()?, token.compute_domain_separator()?);
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_permit_with_p256_signature() -> eyre::Result<()> {
+        use p256::{
+            ecdsa::{SigningKey, signature::hazmat::PrehashSigner},
+            elliptic_curve::rand_core::OsRng,
+        };
+        use tempo_primitives::transaction::tt_signature::{
+            P256SignatureWithPreHash, derive_p256_address, normalize_p256_s,
+        };
+
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        let admin = Address::random();
+        let spender = Address::random();
+        let value = U256::from(500);
+        let deadline = U256::MAX;
+
+        let signing_key = SigningKey::random(&mut OsRng);
+        let point = signing_key.verifying_key().to_encoded_point(false);
+        let pub_key_x = B256::from_slice(point.x().unwrap());
+        let pub_key_y = B256::from_slice(point.y().unwrap());
+        let owner = derive_p256_address(&pub_key_x, &pub_key_y);
+
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("Token", "TKN", admin).apply()?;
+
+            let digest = token.permit_digest(owner, spender, value, U256::ZERO, deadline)?;
+            let signature: p256::ecdsa::Signature =
+                signing_key.sign_prehash(digest.as_slice()).unwrap();
+            let signature = PrimitiveSignature::P256(P256SignatureWithPreHash {
+                r: B256::from_slice(&signature.r().to_bytes()),
+                s: normalize_p256_s(&signature.s().to_bytes()),
+                pub_key_x,
+                pub_key_y,
+                pre_hash: false,
+            });
+
+            token.permit_with_signature(ITIP20::permitWithSignatureCall {
+                owner,
+                spender,
+                value,
+                deadline,
+                signature: signature.to_bytes(),
+            })?;
+
+            assert_eq!(
+                token.allowance(ITIP20::allowanceCall { owner, spender })?,
+                value
+            );
+            assert_eq!(token.nonces(ITIP20::noncesCall { owner })?, U256::ONE);
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_permit_pre_vivace() -> eyre::Result<()> {
+        use crate::Precompile;
+        use alloy::sol_types::SolCall;
+
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::AllegroModerato);
+        let admin = Address::random();
+        let signer = alloy_signer_local::PrivateKeySigner::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("Token", "TKN", admin).apply()?;
+
+            let call = sign_permit(&token, &signer, admin, U256::ONE, U256::MAX)?;
+            let result = token.call(&call.abi_encode(), admin)?;
+            assert!(result.reverted);
+
+            let call = ITIP20::DOMAIN_SEPARATORCall {};
+            let result = token.call(&call.abi_encode(), admin)?;
+            assert!(result.reverted);
+
+            Ok(())
+        })
+    }
+
+    fn sign_transfer_authorization(
+        token: &TIP20Token,
+        signer: &alloy_signer_local::PrivateKeySigner,
+        to: Address,
+        value: U256,
+        nonce: B256,
+    ) -> eyre::Result<ITIP20::transferWithAuthorizationCall> {
+        let authorization = TransferAuthorization {
+            from: signer.address(),
+            to,
+            value,
+            valid_after: U256::ZERO,
+            valid_before: U256::from(1_000),
+            nonce,
+            memo: None,
+        };
+        let digest = token
+            .hash_typed_data(authorization.struct_hash(*TRANSFER_WITH_AUTHORIZATION_TYPEHASH))?;
+        let (v, r, s) = sign_digest(signer, digest)?;
+
+        Ok(ITIP20::transferWithAuthorizationCall {
+            from: authorization.from,
+            to,
+            value,
+            validAfter: authorization.valid_after,
+            validBefore: authorization.valid_before,
+            nonce,
+            v,
+            r,
+            s,
+        })
+    }
+
+    #[test]
+    fn test_transfer_with_authorization() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        let admin = Address::random();
+        let signer = alloy_signer_local::PrivateKeySigner::random();
+        let from = signer.address();
+        let to = Address::random();
+        let amount = U256::from(100);
+        let nonce = B256::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("Token", "TKN", admin)
+                .with_issuer(admin)
+                .with_mint(from, U256::from(1_000))
+                .apply()?;
+            StorageCtx.set_timestamp(U256::from(100));
+
+            let call = sign_transfer_authorization(&token, &signer, to, amount, nonce)?;
+            token.transfer_with_authorization(call.clone())?;
+
+            assert_eq!(
+                token.balance_of(ITIP20::balanceOfCall { account: to })?,
+                amount
+            );
+            assert!(token.authorization_state(ITIP20::authorizationStateCall {
+                authorizer: from,
+                nonce,
+            })?);
+            assert_eq!(
+                token.emitted_events().last().unwrap(),
+                &TIP20Event::Transfer(ITIP20::Transfer { from, to, amount }).into_log_data()
+            );
+
+            // Authorizations can only be used once
+            assert!(matches!(
+                token.transfer_with_authorization(call),
+                Err(TempoPrecompileError::TIP20(
+                    TIP20Error::AuthorizationAlreadyUsed(_)
+                ))
+            ));
+
+            // The signature covers the recipient
+            let mut call =
+                sign_transfer_authorization(&token, &signer, to, amount, B256::random())?;
+            call.to = Address::random();
+            assert!(matches!(
+                token.transfer_with_authorization(call),
+                Err(TempoPrecompileError::TIP20(TIP20Error::InvalidSignature(_)))
+            ));
+
+            let call = sign_transfer_authorization(&token, &signer, to, amount, B256::random())?;
+            StorageCtx.set_timestamp(call.validBefore);
+            assert!(matches!(
+                token.transfer_with_authorization(call.clone()),
+                Err(TempoPrecompileError::TIP20(
+                    TIP20Error::AuthorizationExpired(_)
+                ))
+            ));
+            StorageCtx.set_timestamp(call.validAfter);
+            assert!(matches!(
+                token.transfer_with_authorization(call),
+                Err(TempoPrecompileError::TIP20(
+                    TIP20Error::AuthorizationNotYetValid(_)
+                ))
+            ));
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_receive_with_authorization_with_memo() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        let admin = Address::random();
+        let signer = alloy_signer_local::PrivateKeySigner::random();
+        let from = signer.address();
+        let to = Address::random();
+        let amount = U256::from(100);
+        let memo = B256::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("Token", "TKN", admin)
+                .with_issuer(admin)
+                .with_mint(from, amount)
+                .apply()?;
+            StorageCtx.set_timestamp(U256::from(100));
+
+            let authorization = TransferAuthorization {
+                from,
+                to,
+                value: amount,
+                valid_after: U256::ZERO,
+                valid_before: U256::MAX,
+                nonce: B256::random(),
+                memo: Some(memo),
+            };
+            let digest = token.hash_typed_data(
+                authorization.struct_hash(*RECEIVE_WITH_AUTHORIZATION_WITH_MEMO_TYPEHASH),
+            )?;
+            let (v, r, s) = sign_digest(&signer, digest)?;
+            let call = ITIP20::receiveWithAuthorizationWithMemoCall {
+                from,
+                to,
+                value: amount,
+                validAfter: authorization.valid_after,
+                validBefore: authorization.valid_before,
+                nonce: authorization.nonce,
+                memo,
+                v,
+                r,
+                s,
+            };
+
+            // Only the payee can submit the authorization
+            assert!(matches!(
+                token.receive_with_authorization_with_memo(Address::random(), call.clone()),
+                Err(TempoPrecompileError::TIP20(TIP20Error::CallerNotPayee(_)))
+            ));
+
+            token.receive_with_authorization_with_memo(to, call)?;
+            assert_eq!(
+                token.balance_of(ITIP20::balanceOfCall { account: to })?,
+                amount
+            );
+            assert_eq!(
+                token.emitted_events().last().unwrap(),
+                &TIP20Event::TransferWithMemo(ITIP20::TransferWithMemo {
+                    from,
+                    to,
+                    amount,
+                    memo,
+                })
+                .into_log_data()
+            );
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_cancel_authorization() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        let admin = Address::random();
+        let signer = alloy_signer_local::PrivateKeySigner::random();
+        let authorizer = signer.address();
+        let nonce = B256::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("Token", "TKN", admin)
+                .with_issuer(admin)
+                .with_mint(authorizer, U256::from(100))
+                .apply()?;
+            StorageCtx.set_timestamp(U256::from(100));
+
+            let transfer =
+                sign_transfer_authorization(&token, &signer, admin, U256::from(100), nonce)?;
+
+            let digest = token.hash_typed_data(keccak256(
+                (*CANCEL_AUTHORIZATION_TYPEHASH, authorizer, nonce).abi_encode(),
+            ))?;
+            let (v, r, s) = sign_digest(&signer, digest)?;
+            token.cancel_authorization(ITIP20::cancelAuthorizationCall {
+                authorizer,
+                nonce,
+                v,
+                r,
+                s,
+            })?;
+            assert_eq!(
+                token.emitted_events().last().unwrap(),
+                &TIP20Event::AuthorizationCanceled(ITIP20::AuthorizationCanceled {
+                    authorizer,
+                    nonce,
+                })
+                .into_log_data()
+            );
+
+            assert!(matches!(
+                token.transfer_with_authorization(transfer),
+                Err(TempoPrecompileError::TIP20(
+                    TIP20Error::AuthorizationAlreadyUsed(_)
+                ))
+            ));
+
+            Ok(())
+        })
+    }
 }
@@ -3,6 +3,7 @@ use crate::{
     error::{Result, TempoPrecompileError},
     storage::Handler,
     tip20::TIP20Token,
+    tip20_factory::TIP20Factory,
     tip20_rewards_registry::TIP20RewardsRegistry,
 };
 use alloy::primitives::{Address, U256, uint};
@@ -11,6 +12,9 @@ use tempo_precompiles_macros::Storable;
 
 pub const ACC_PRECISION: U256 = uint!(1000000000000000000_U256);
 
+/// Maximum number of foreign reward tokens a token can distribute
+pub const MAX_REWARD_TOKENS: usize = 8;
+
 impl TIP20Token {
     /// Starts a new reward stream for the token contract.
     ///
@@ -203,9 +207,150 @@ impl TIP20Token {
             self.user_reward_info.at(holder).write(info)?;
         }
 
+        self.update_token_rewards(holder, cached_delegate)?;
+
         Ok(cached_delegate)
     }
 
+    /// Updates the holder's accrued rewards in every foreign reward token.
+    ///
+    /// Mirrors [`Self::update_rewards`], crediting `delegate` with the holder's share of each
+    /// reward token distributed since the holder's last update. No-op before Vivace.
+    fn update_token_rewards(&mut self, holder: Address, delegate: Address) -> Result<()> {
+        if !self.storage.spec().is_vivace() {
+            return Ok(());
+        }
+
+        for reward_token in self.reward_tokens.read()? {
+            let mut info = self.token_reward_info.at(holder).at(reward_token).read()?;
+            let global_reward_per_token = self.token_reward_per_token.at(reward_token).read()?;
+            let reward_per_token_delta = global_reward_per_token
+                .checked_sub(info.reward_per_token)
+                .ok_or(TempoPrecompileError::under_overflow())?;
+
+            if reward_per_token_delta.is_zero() {
+                continue;
+            }
+
+            if delegate != Address::ZERO {
+                let reward = self
+                    .get_balance(holder)?
+                    .checked_mul(reward_per_token_delta)
+                    .and_then(|v| v.checked_div(ACC_PRECISION))
+                    .ok_or(TempoPrecompileError::under_overflow())?;
+
+                if delegate == holder {
+                    info.reward_balance = info
+                        .reward_balance
+                        .checked_add(reward)
+                        .ok_or(TempoPrecompileError::under_overflow())?;
+                } else {
+                    let mut delegate_info = self
+                        .token_reward_info
+                        .at(delegate)
+                        .at(reward_token)
+                        .read()?;
+                    delegate_info.reward_balance = delegate_info
+                        .reward_balance
+                        .checked_add(reward)
+                        .ok_or(TempoPrecompileError::under_overflow())?;
+                    self.token_reward_info
+                        .at(delegate)
+                        .at(reward_token)
+                        .write(delegate_info)?;
+                }
+            }
+            info.reward_per_token = global_reward_per_token;
+            self.token_reward_info
+                .at(holder)
+                .at(reward_token)
+                .write(info)?;
+        }
+
+        Ok(())
+    }
+
+    /// Distributes a reward paid in another TIP-20 token to opted-in holders.
+    ///
+    /// Rewards in this token itself are forwarded to [`Self::start_reward`]. Like same-token
+    /// rewards post-Moderato, foreign-token rewards are paid out immediately and `secs` must be
+    /// zero. The reward token is pulled from the funder and held by this token's address until
+    /// claimed with [`Self::claim_token_rewards`].
+    pub fn start_token_reward(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP20::startTokenRewardCall,
+    ) -> Result<u64> {
+        if call.rewardToken == self.address {
+            return self.start_reward(
+                msg_sender,
+                ITIP20::startRewardCall {
+                    amount: call.amount,
+                    secs: call.secs,
+                },
+            );
+        }
+
+        self.check_not_paused()?;
+
+        if call.amount == U256::ZERO {
+            return Err(TIP20Error::invalid_amount().into());
+        }
+
+        if call.secs != 0 {
+            return Err(TIP20Error::scheduled_rewards_disabled().into());
+        }
+
+        if !TIP20Factory::new().is_tip20(call.rewardToken)? {
+            return Err(TIP20Error::invalid_token().into());
+        }
+
+        let opted_in_supply = U256::from(self.get_opted_in_supply()?);
+        if opted_in_supply.is_zero() {
+            return Err(TIP20Error::no_opted_in_supply().into());
+        }
+
+        let reward_tokens = self.reward_tokens.read()?;
+        if !reward_tokens.contains(&call.rewardToken) {
+            if reward_tokens.len() >= MAX_REWARD_TOKENS {
+                return Err(TIP20Error::too_many_reward_tokens().into());
+            }
+            self.reward_tokens.push(call.rewardToken)?;
+        }
+
+        let token_address = self.address;
+        let mut reward_token = TIP20Token::from_address(call.rewardToken)?;
+        reward_token.check_not_paused()?;
+        reward_token.ensure_transfer_authorized(msg_sender, token_address)?;
+        reward_token.check_spending_limit(msg_sender, call.amount)?;
+        reward_token._transfer(msg_sender, token_address, call.amount)?;
+
+        let delta_rpt = call
+            .amount
+            .checked_mul(ACC_PRECISION)
+            .and_then(|v| v.checked_div(opted_in_supply))
+            .ok_or(TempoPrecompileError::under_overflow())?;
+        let new_rpt = self
+            .token_reward_per_token
+            .at(call.rewardToken)
+            .read()?
+            .checked_add(delta_rpt)
+            .ok_or(TempoPrecompileError::under_overflow())?;
+        self.token_reward_per_token
+            .at(call.rewardToken)
+            .write(new_rpt)?;
+
+        self.emit_event(TIP20Event::TokenRewardScheduled(
+            ITIP20::TokenRewardScheduled {
+                funder: msg_sender,
+                rewardToken: call.rewardToken,
+                amount: call.amount,
+            },
+        ))?;
+
+        Ok(0)
+    }
+
     /// Sets or changes the reward recipient for a token holder.
     ///
     /// This function allows a token holder to designate who should receive their
@@ -459,6 +604,57 @@ impl TIP20Token {
         Ok(max_amount)
     }
 
+    /// Claims accumulated rewards paid in `reward_token`.
+    ///
+    /// Claims in this token itself are forwarded to [`Self::claim_rewards`]. Like same-token
+    /// claims, the payout is capped at the reward token balance held by this token.
+    pub fn claim_token_rewards(
+        &mut self,
+        msg_sender: Address,
+        reward_token: Address,
+    ) -> Result<U256> {
+        if reward_token == self.address {
+            return self.claim_rewards(msg_sender);
+        }
+
+        self.check_not_paused()?;
+        self.ensure_transfer_authorized(msg_sender, msg_sender)?;
+
+        let timestamp = self.storage.timestamp();
+        self.accrue(timestamp)?;
+        self.update_rewards(msg_sender)?;
+
+        let mut info = self
+            .token_reward_info
+            .at(msg_sender)
+            .at(reward_token)
+            .read()?;
+        if info.reward_balance.is_zero() {
+            return Ok(U256::ZERO);
+        }
+
+        let token_address = self.address;
+        let mut reward = TIP20Token::from_address(reward_token)?;
+        let amount = info.reward_balance.min(reward.get_balance(token_address)?);
+
+        info.reward_balance = info
+            .reward_balance
+            .checked_sub(amount)
+            .ok_or(TempoPrecompileError::under_overflow())?;
+        self.token_reward_info
+            .at(msg_sender)
+            .at(reward_token)
+            .write(info)?;
+
+        if amount > U256::ZERO {
+            reward.check_not_paused()?;
+            reward.ensure_transfer_authorized(token_address, msg_sender)?;
+            reward._transfer(token_address, msg_sender, amount)?;
+        }
+
+        Ok(amount)
+    }
+
     /// Gets the next available stream ID (minimum 1).
     pub fn get_next_stream_id(&self) -> Result<u64> {
         self.next_stream_id.read().map(|id| id.max(1))
@@ -581,6 +777,30 @@ impl TIP20Token {
     pub fn get_user_reward_info(&self, account: Address) -> Result<UserRewardInfo> {
         self.user_reward_info.at(account).read()
     }
+
+    /// Returns the foreign reward tokens distributed by this token.
+    pub fn get_reward_tokens(&self) -> Result<Vec<Address>> {
+        self.reward_tokens.read()
+    }
+
+    /// Retrieves an account's reward information in `reward_token`.
+    ///
+    /// For this token itself, this is the same-token accrual tracked in [`UserRewardInfo`].
+    pub fn get_token_reward_info(
+        &self,
+        account: Address,
+        reward_token: Address,
+    ) -> Result<TokenRewardInfo> {
+        if reward_token == self.address {
+            let info = self.get_user_reward_info(account)?;
+            return Ok(TokenRewardInfo {
+                reward_per_token: info.reward_per_token,
+                reward_balance: info.reward_balance,
+            });
+        }
+
+        self.token_reward_info.at(account).at(reward_token).read()
+    }
 }
 
 #[derive(Debug, Clone, Storable)]
@@ -590,6 +810,13 @@ pub struct UserRewardInfo {
     pub reward_balance: U256,
 }
 
+/// A holder's accrual in a foreign reward token
+#[derive(Debug, Clone, Default, PartialEq, Eq, Storable)]
+pub struct TokenRewardInfo {
+    pub reward_per_token: U256,
+    pub reward_balance: U256,
+}
+
 #[derive(Debug, Clone, Storable)]
 pub struct RewardStream {
     funder: Address,
@@ -630,6 +857,15 @@ impl From<RewardStream> for ITIP20::RewardStream {
     }
 }
 
+impl From<TokenRewardInfo> for ITIP20::TokenRewardInfo {
+    fn from(value: TokenRewardInfo) -> Self {
+        Self {
+            rewardPerToken: value.reward_per_token,
+            rewardBalance: value.reward_balance,
+        }
+    }
+}
+
 impl From<UserRewardInfo> for ITIP20::UserRewardInfo {
     fn from(value: UserRewardInfo) -> Self {
         Self {
@@ -652,7 +888,7 @@ mod tests {
         tip20::{ISSUER_ROLE, tests::initialize_path_usd},
         tip403_registry::TIP403Registry,
     };
-    use alloy::primitives::{Address, U256};
+    use alloy::primitives::{Address, IntoLogData, U256};
     use tempo_chainspec::hardfork::TempoHardfork;
     use tempo_contracts::precompiles::ITIP403Registry;
 
@@ -1324,6 +1560,119 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_token_rewards() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        let admin = Address::random();
+        let alice = Address::random();
+        let bob = Address::random();
+        let carol = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("Test", "TST", admin)
+                .with_issuer(admin)
+                .with_mint(alice, U256::from(100e18))
+                .with_mint(bob, U256::from(300e18))
+                .apply()?;
+            let reward = TIP20Setup::create("Reward", "RWD", admin)
+                .with_issuer(admin)
+                .with_mint(admin, U256::from(1000e18))
+                .apply()?;
+            let reward_token = reward.address;
+
+            let start = |token: &mut TIP20Token, amount: U256, secs: u32| {
+                token.start_token_reward(
+                    admin,
+                    ITIP20::startTokenRewardCall {
+                        rewardToken: reward_token,
+                        amount,
+                        secs,
+                    },
+                )
+            };
+
+            assert_eq!(
+                start(&mut token, U256::from(40e18), 0),
+                Err(TIP20Error::no_opted_in_supply().into())
+            );
+
+            token
+                .set_reward_recipient(alice, ITIP20::setRewardRecipientCall { recipient: alice })?;
+            token.set_reward_recipient(bob, ITIP20::setRewardRecipientCall { recipient: carol })?;
+
+            assert_eq!(
+                start(&mut token, U256::from(40e18), 10),
+                Err(TIP20Error::scheduled_rewards_disabled().into())
+            );
+            assert_eq!(
+                token.start_token_reward(
+                    admin,
+                    ITIP20::startTokenRewardCall {
+                        rewardToken: Address::random(),
+                        amount: U256::from(40e18),
+                        secs: 0,
+                    },
+                ),
+                Err(TIP20Error::invalid_token().into())
+            );
+
+            assert_eq!(start(&mut token, U256::from(40e18), 0)?, 0);
+            assert_eq!(token.get_reward_tokens()?, vec![reward_token]);
+            assert_eq!(
+                token.emitted_events().last(),
+                Some(
+                    &TIP20Event::TokenRewardScheduled(ITIP20::TokenRewardScheduled {
+                        funder: admin,
+                        rewardToken: reward_token,
+                        amount: U256::from(40e18),
+                    })
+                    .into_log_data()
+                )
+            );
+
+            let reward = TIP20Token::from_address(reward_token)?;
+            assert_eq!(reward.get_balance(token.address)?, U256::from(40e18));
+
+            // Transfers settle accrued rewards before balances change
+            token.transfer(
+                bob,
+                ITIP20::transferCall {
+                    to: alice,
+                    amount: U256::from(100e18),
+                },
+            )?;
+            assert_eq!(
+                token.get_token_reward_info(carol, reward_token)?,
+                TokenRewardInfo {
+                    reward_per_token: U256::ZERO,
+                    reward_balance: U256::from(30e18),
+                }
+            );
+
+            assert_eq!(
+                token.claim_token_rewards(alice, reward_token)?,
+                U256::from(10e18)
+            );
+            assert_eq!(token.claim_token_rewards(bob, reward_token)?, U256::ZERO);
+            assert_eq!(
+                token.claim_token_rewards(carol, reward_token)?,
+                U256::from(30e18)
+            );
+            assert_eq!(reward.get_balance(alice)?, U256::from(10e18));
+            assert_eq!(reward.get_balance(carol)?, U256::from(30e18));
+            assert_eq!(reward.get_balance(token.address)?, U256::ZERO);
+
+            // Same-token rewards keep being tracked in `UserRewardInfo`
+            assert_eq!(
+                token.get_token_reward_info(alice, token.address)?,
+                TokenRewardInfo::default()
+            );
+            assert_eq!(token.claim_token_rewards(alice, token.address)?, U256::ZERO);
+
+            Ok(())
+        })
+    }
+
     #[test]
     fn test_cancel_reward_ensure_tip403_is_not_blacklisted() -> eyre::Result<()> {
         const STREAM_DURATION: u32 = 10;
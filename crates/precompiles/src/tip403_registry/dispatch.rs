@@ -74,6 +74,106 @@ impl Precompile for TIP403Registry {
                     |s, call| self.modify_policy_blacklist(s, call),
                 )
             }
+            ITIP403Registry::isAuthorizedAsCall::SELECTOR => {
+                if self.storage.spec().is_vivace() {
+                    view::<ITIP403Registry::isAuthorizedAsCall>(calldata, |call| {
+                        self.is_authorized_as(call)
+                    })
+                } else {
+                    unknown_selector(selector, self.storage.gas_used(), self.storage.spec())
+                }
+            }
+            ITIP403Registry::isTransferAuthorizedCall::SELECTOR => {
+                if self.storage.spec().is_vivace() {
+                    view::<ITIP403Registry::isTransferAuthorizedCall>(calldata, |call| {
+                        self.is_transfer_authorized(call)
+                    })
+                } else {
+                    unknown_selector(selector, self.storage.gas_used(), self.storage.spec())
+                }
+            }
+            ITIP403Registry::compoundPolicyDataCall::SELECTOR => {
+                if self.storage.spec().is_vivace() {
+                    view::<ITIP403Registry::compoundPolicyDataCall>(calldata, |call| {
+                        self.compound_policy_data(call)
+                    })
+                } else {
+                    unknown_selector(selector, self.storage.gas_used(), self.storage.spec())
+                }
+            }
+            ITIP403Registry::directionalPolicyDataCall::SELECTOR => {
+                if self.storage.spec().is_vivace() {
+                    view::<ITIP403Registry::directionalPolicyDataCall>(calldata, |call| {
+                        self.directional_policy_data(call)
+                    })
+                } else {
+                    unknown_selector(selector, self.storage.gas_used(), self.storage.spec())
+                }
+            }
+            ITIP403Registry::createCompoundPolicyCall::SELECTOR => {
+                if self.storage.spec().is_vivace() {
+                    mutate::<ITIP403Registry::createCompoundPolicyCall>(
+                        calldata,
+                        msg_sender,
+                        |s, call| self.create_compound_policy(s, call),
+                    )
+                } else {
+                    unknown_selector(selector, self.storage.gas_used(), self.storage.spec())
+                }
+            }
+            ITIP403Registry::setCompoundPolicyCall::SELECTOR => {
+                if self.storage.spec().is_vivace() {
+                    mutate_void::<ITIP403Registry::setCompoundPolicyCall>(
+                        calldata,
+                        msg_sender,
+                        |s, call| self.set_compound_policy(s, call),
+                    )
+                } else {
+                    unknown_selector(selector, self.storage.gas_used(), self.storage.spec())
+                }
+            }
+            ITIP403Registry::createDirectionalPolicyCall::SELECTOR => {
+                if self.storage.spec().is_vivace() {
+                    mutate::<ITIP403Registry::createDirectionalPolicyCall>(
+                        calldata,
+                        msg_sender,
+                        |s, call| self.create_directional_policy(s, call),
+                    )
+                } else {
+                    unknown_selector(selector, self.storage.gas_used(), self.storage.spec())
+                }
+            }
+            ITIP403Registry::setDirectionalPolicyCall::SELECTOR => {
+                if self.storage.spec().is_vivace() {
+                    mutate_void::<ITIP403Registry::setDirectionalPolicyCall>(
+                        calldata,
+                        msg_sender,
+                        |s, call| self.set_directional_policy(s, call),
+                    )
+                } else {
+                    unknown_selector(selector, self.storage.gas_used(), self.storage.spec())
+                }
+            }
+            ITIP403Registry::membershipExpiryCall::SELECTOR => {
+                if self.storage.spec().is_vivace() {
+                    view::<ITIP403Registry::membershipExpiryCall>(calldata, |call| {
+                        self.membership_expiry(call)
+                    })
+                } else {
+                    unknown_selector(selector, self.storage.gas_used(), self.storage.spec())
+                }
+            }
+            ITIP403Registry::modifyPolicyMembersCall::SELECTOR => {
+                if self.storage.spec().is_vivace() {
+                    mutate_void::<ITIP403Registry::modifyPolicyMembersCall>(
+                        calldata,
+                        msg_sender,
+                        |s, call| self.modify_policy_members(s, call),
+                    )
+                } else {
+                    unknown_selector(selector, self.storage.gas_used(), self.storage.spec())
+                }
+            }
             _ => unknown_selector(selector, self.storage.gas_used(), self.storage.spec()),
         };
 
@@ -532,6 +632,42 @@ mod tests {
                 ITIP403RegistryCalls::name_by_selector,
             );
 
+            // In pre-Vivace, post-Vivace functions should be unsupported
+            let mut unsupported: Vec<&str> = unsupported.iter().map(|(_, name)| *name).collect();
+            unsupported.sort();
+            assert_eq!(
+                unsupported,
+                vec![
+                    "compoundPolicyData",
+                    "createCompoundPolicy",
+                    "createDirectionalPolicy",
+                    "directionalPolicyData",
+                    "isAuthorizedAs",
+                    "isTransferAuthorized",
+                    "membershipExpiry",
+                    "modifyPolicyMembers",
+                    "setCompoundPolicy",
+                    "setDirectionalPolicy",
+                ]
+            );
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_selector_coverage_post_vivace() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        StorageCtx::enter(&mut storage, || {
+            let mut registry = TIP403Registry::new();
+
+            let unsupported = check_selector_coverage(
+                &mut registry,
+                ITIP403RegistryCalls::SELECTORS,
+                "ITIP403Registry",
+                ITIP403RegistryCalls::name_by_selector,
+            );
+
             assert_full_coverage([unsupported]);
 
             Ok(())
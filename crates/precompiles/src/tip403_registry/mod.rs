@@ -15,8 +15,18 @@ pub struct TIP403Registry {
     policy_id_counter: u64,
     policy_data: Mapping<u64, PolicyData>,
     policy_set: Mapping<u64, Mapping<Address, bool>>,
+    compound_operators: Mapping<u64, u8>,
+    compound_members: Mapping<u64, Vec<u64>>,
+    directional_policies: Mapping<u64, DirectionalPolicy>,
+    membership_expiries: Mapping<u64, Mapping<Address, u64>>,
 }
 
+/// Maximum number of policies a compound policy can combine.
+///
+/// Compound policies can only reference whitelist and blacklist policies, so evaluating one costs
+/// at most this many membership lookups.
+pub const MAX_COMPOUND_POLICY_MEMBERS: usize = 8;
+
 #[derive(Debug, Clone, Storable)]
 pub struct PolicyData {
     // NOTE: enums are defined as u8, and leverage the sol! macro's `TryInto<u8>` impl
@@ -24,6 +34,20 @@ pub struct PolicyData {
     pub admin: Address,
 }
 
+/// Per-direction rules of a directional policy.
+///
+/// Each slot references a whitelist, blacklist or compound policy (or one of the special
+/// policies 0 and 1).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Storable)]
+pub struct DirectionalPolicy {
+    /// Policy the sender of a transfer must satisfy
+    pub sender_policy_id: u64,
+    /// Policy the recipient of a transfer must satisfy
+    pub recipient_policy_id: u64,
+    /// Policy the recipient of a mint must satisfy
+    pub mint_recipient_policy_id: u64,
+}
+
 // NOTE(rusowsky): can be removed once revm uses precompiles rather than directly
 // interacting with storage slots.
 impl PolicyData {
@@ -93,12 +117,79 @@ impl TIP403Registry {
         self.is_authorized_internal(call.policyId, call.user)
     }
 
+    pub fn is_authorized_as(&self, call: ITIP403Registry::isAuthorizedAsCall) -> Result<bool> {
+        self.is_authorized_as_internal(call.policyId, call.user, call.role)
+    }
+
+    /// Checks whether a transfer from `from` to `to` is allowed by the policy.
+    ///
+    /// Both sides are always evaluated, so that simple policies cost the same as two
+    /// `isAuthorized` checks.
+    pub fn is_transfer_authorized(
+        &self,
+        call: ITIP403Registry::isTransferAuthorizedCall,
+    ) -> Result<bool> {
+        let from_authorized = self.is_authorized_as_internal(
+            call.policyId,
+            call.from,
+            ITIP403Registry::PolicyRole::SENDER,
+        )?;
+        let to_authorized = self.is_authorized_as_internal(
+            call.policyId,
+            call.to,
+            ITIP403Registry::PolicyRole::RECIPIENT,
+        )?;
+
+        Ok(from_authorized && to_authorized)
+    }
+
+    pub fn compound_policy_data(
+        &self,
+        call: ITIP403Registry::compoundPolicyDataCall,
+    ) -> Result<ITIP403Registry::compoundPolicyDataReturn> {
+        self.ensure_policy_type(call.policyId, ITIP403Registry::PolicyType::COMPOUND)?;
+
+        Ok(ITIP403Registry::compoundPolicyDataReturn {
+            operator: self
+                .compound_operators
+                .at(call.policyId)
+                .read()?
+                .try_into()
+                .map_err(|_| TempoPrecompileError::under_overflow())?,
+            policyIds: self.compound_members.at(call.policyId).read()?,
+        })
+    }
+
+    pub fn directional_policy_data(
+        &self,
+        call: ITIP403Registry::directionalPolicyDataCall,
+    ) -> Result<ITIP403Registry::directionalPolicyDataReturn> {
+        self.ensure_policy_type(call.policyId, ITIP403Registry::PolicyType::DIRECTIONAL)?;
+
+        let policy = self.directional_policies.at(call.policyId).read()?;
+        Ok(ITIP403Registry::directionalPolicyDataReturn {
+            senderPolicyId: policy.sender_policy_id,
+            recipientPolicyId: policy.recipient_policy_id,
+            mintRecipientPolicyId: policy.mint_recipient_policy_id,
+        })
+    }
+
+    /// Returns the timestamp at which the account's membership of the policy expires, or 0 if
+    /// it does not expire.
+    pub fn membership_expiry(&self, call: ITIP403Registry::membershipExpiryCall) -> Result<u64> {
+        self.membership_expiries
+            .at(call.policyId)
+            .at(call.account)
+            .read()
+    }
+
     // State-changing functions
     pub fn create_policy(
         &mut self,
         msg_sender: Address,
         call: ITIP403Registry::createPolicyCall,
     ) -> Result<u64> {
+        let policy_type = self.set_policy_type(call.policyType)?;
         let new_policy_id = self.policy_id_counter()?;
 
         // Increment counter
@@ -110,7 +201,7 @@ impl TIP403Registry {
 
         // Store policy data
         self.policy_data.at(new_policy_id).write(PolicyData {
-            policy_type: call.policyType as u8,
+            policy_type: policy_type as u8,
             admin: call.admin,
         })?;
 
@@ -119,7 +210,7 @@ impl TIP403Registry {
             ITIP403Registry::PolicyCreated {
                 policyId: new_policy_id,
                 updater: msg_sender,
-                policyType: call.policyType,
+                policyType: policy_type,
             },
         ))?;
 
@@ -139,7 +230,7 @@ impl TIP403Registry {
         msg_sender: Address,
         call: ITIP403Registry::createPolicyWithAccountsCall,
     ) -> Result<u64> {
-        let (admin, policy_type) = (call.admin, call.policyType);
+        let (admin, policy_type) = (call.admin, self.set_policy_type(call.policyType)?);
         let new_policy_id = self.policy_id_counter()?;
 
         // Increment counter
@@ -183,7 +274,9 @@ impl TIP403Registry {
                         },
                     ))?;
                 }
-                ITIP403Registry::PolicyType::__Invalid => {
+                ITIP403Registry::PolicyType::COMPOUND
+                | ITIP403Registry::PolicyType::DIRECTIONAL
+                | ITIP403Registry::PolicyType::__Invalid => {
                     return Err(TIP403RegistryError::incompatible_policy_type().into());
                 }
             }
@@ -194,7 +287,7 @@ impl TIP403Registry {
             ITIP403Registry::PolicyCreated {
                 policyId: new_policy_id,
                 updater: msg_sender,
-                policyType: call.policyType,
+                policyType: policy_type,
             },
         ))?;
 
@@ -257,6 +350,7 @@ impl TIP403Registry {
         }
 
         self.set_policy_set(call.policyId, call.account, call.allowed)?;
+        self.clear_membership_expiry(call.policyId, call.account)?;
 
         self.emit_event(TIP403RegistryEvent::WhitelistUpdated(
             ITIP403Registry::WhitelistUpdated {
@@ -286,6 +380,7 @@ impl TIP403Registry {
         }
 
         self.set_policy_set(call.policyId, call.account, call.restricted)?;
+        self.clear_membership_expiry(call.policyId, call.account)?;
 
         self.emit_event(TIP403RegistryEvent::BlacklistUpdated(
             ITIP403Registry::BlacklistUpdated {
@@ -297,6 +392,138 @@ impl TIP403Registry {
         ))
     }
 
+    /// Updates the membership of many accounts at once.
+    ///
+    /// `allowed` is whether the accounts may transfer, so it adds them to a whitelist or removes
+    /// them from a blacklist. A non-zero `expiry` bounds the membership of accounts that are added
+    /// to the set, after which the accounts are treated as non-members. It is ignored for
+    /// accounts that are removed.
+    pub fn modify_policy_members(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP403Registry::modifyPolicyMembersCall,
+    ) -> Result<()> {
+        let data = self.get_policy_data(call.policyId)?;
+
+        // Check authorization
+        if data.admin != msg_sender {
+            return Err(TIP403RegistryError::unauthorized().into());
+        }
+
+        // Check policy type
+        let is_member = match data
+            .policy_type
+            .try_into()
+            .map_err(|_| TempoPrecompileError::under_overflow())?
+        {
+            ITIP403Registry::PolicyType::WHITELIST => call.allowed,
+            ITIP403Registry::PolicyType::BLACKLIST => !call.allowed,
+            _ => return Err(TIP403RegistryError::incompatible_policy_type().into()),
+        };
+
+        let expiry = if is_member { call.expiry } else { 0 };
+        let current_timestamp = self.storage.timestamp().saturating_to::<u64>();
+        if expiry != 0 && expiry <= current_timestamp {
+            return Err(TIP403RegistryError::invalid_membership_expiry().into());
+        }
+
+        for account in call.accounts {
+            self.set_policy_set(call.policyId, account, is_member)?;
+            self.membership_expiries
+                .at(call.policyId)
+                .at(account)
+                .write(expiry)?;
+
+            self.emit_event(TIP403RegistryEvent::PolicyMembershipUpdated(
+                ITIP403Registry::PolicyMembershipUpdated {
+                    policyId: call.policyId,
+                    updater: msg_sender,
+                    account,
+                    isMember: is_member,
+                    expiry,
+                },
+            ))?;
+        }
+
+        Ok(())
+    }
+
+    pub fn create_compound_policy(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP403Registry::createCompoundPolicyCall,
+    ) -> Result<u64> {
+        self.validate_compound_members(&call.policyIds)?;
+        let new_policy_id = self.create_policy_id(
+            msg_sender,
+            call.admin,
+            ITIP403Registry::PolicyType::COMPOUND,
+        )?;
+        self.write_compound_policy(msg_sender, new_policy_id, call.operator, call.policyIds)?;
+
+        Ok(new_policy_id)
+    }
+
+    pub fn set_compound_policy(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP403Registry::setCompoundPolicyCall,
+    ) -> Result<()> {
+        let data = self.ensure_policy_type(call.policyId, ITIP403Registry::PolicyType::COMPOUND)?;
+
+        // Check authorization
+        if data.admin != msg_sender {
+            return Err(TIP403RegistryError::unauthorized().into());
+        }
+
+        self.validate_compound_members(&call.policyIds)?;
+        self.write_compound_policy(msg_sender, call.policyId, call.operator, call.policyIds)
+    }
+
+    pub fn create_directional_policy(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP403Registry::createDirectionalPolicyCall,
+    ) -> Result<u64> {
+        let policy = DirectionalPolicy {
+            sender_policy_id: call.senderPolicyId,
+            recipient_policy_id: call.recipientPolicyId,
+            mint_recipient_policy_id: call.mintRecipientPolicyId,
+        };
+        self.validate_directional_policy(&policy)?;
+
+        let new_policy_id = self.create_policy_id(
+            msg_sender,
+            call.admin,
+            ITIP403Registry::PolicyType::DIRECTIONAL,
+        )?;
+        self.write_directional_policy(msg_sender, new_policy_id, policy)?;
+
+        Ok(new_policy_id)
+    }
+
+    pub fn set_directional_policy(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP403Registry::setDirectionalPolicyCall,
+    ) -> Result<()> {
+        let data =
+            self.ensure_policy_type(call.policyId, ITIP403Registry::PolicyType::DIRECTIONAL)?;
+
+        // Check authorization
+        if data.admin != msg_sender {
+            return Err(TIP403RegistryError::unauthorized().into());
+        }
+
+        let policy = DirectionalPolicy {
+            sender_policy_id: call.senderPolicyId,
+            recipient_policy_id: call.recipientPolicyId,
+            mint_recipient_policy_id: call.mintRecipientPolicyId,
+        };
+        self.validate_directional_policy(&policy)?;
+        self.write_directional_policy(msg_sender, call.policyId, policy)
+    }
+
     // Internal helper functions
     fn get_policy_data(&self, policy_id: u64) -> Result<PolicyData> {
         self.policy_data.at(policy_id).read()
@@ -310,7 +537,235 @@ impl TIP403Registry {
         self.policy_set.at(policy_id).at(account).write(value)
     }
 
+    /// Returns the policy type to store for a `createPolicy` call.
+    ///
+    /// Compound and directional policies have dedicated constructors. Before Vivace these types
+    /// were not part of the ABI and decoded as `__Invalid`, so they are stored as such.
+    fn set_policy_type(
+        &self,
+        policy_type: ITIP403Registry::PolicyType,
+    ) -> Result<ITIP403Registry::PolicyType> {
+        match policy_type {
+            ITIP403Registry::PolicyType::COMPOUND | ITIP403Registry::PolicyType::DIRECTIONAL => {
+                if self.storage.spec().is_vivace() {
+                    Err(TIP403RegistryError::incompatible_policy_type().into())
+                } else {
+                    Ok(ITIP403Registry::PolicyType::__Invalid)
+                }
+            }
+            policy_type => Ok(policy_type),
+        }
+    }
+
+    /// Allocates a new policy ID and emits the creation events.
+    fn create_policy_id(
+        &mut self,
+        msg_sender: Address,
+        admin: Address,
+        policy_type: ITIP403Registry::PolicyType,
+    ) -> Result<u64> {
+        let new_policy_id = self.policy_id_counter()?;
+
+        self.policy_id_counter.write(
+            new_policy_id
+                .checked_add(1)
+                .ok_or(TempoPrecompileError::under_overflow())?,
+        )?;
+
+        self.set_policy_data(
+            new_policy_id,
+            PolicyData {
+                policy_type: policy_type as u8,
+                admin,
+            },
+        )?;
+
+        self.emit_event(TIP403RegistryEvent::PolicyCreated(
+            ITIP403Registry::PolicyCreated {
+                policyId: new_policy_id,
+                updater: msg_sender,
+                policyType: policy_type,
+            },
+        ))?;
+
+        self.emit_event(TIP403RegistryEvent::PolicyAdminUpdated(
+            ITIP403Registry::PolicyAdminUpdated {
+                policyId: new_policy_id,
+                updater: msg_sender,
+                admin,
+            },
+        ))?;
+
+        Ok(new_policy_id)
+    }
+
+    /// Returns the type of an existing, non-special policy.
+    fn policy_type_of(&self, policy_id: u64) -> Result<ITIP403Registry::PolicyType> {
+        if !self.policy_exists(ITIP403Registry::policyExistsCall {
+            policyId: policy_id,
+        })? {
+            return Err(TIP403RegistryError::policy_not_found().into());
+        }
+
+        self.get_policy_data(policy_id)?
+            .policy_type
+            .try_into()
+            .map_err(|_| TempoPrecompileError::under_overflow())
+    }
+
+    /// Reads the policy data, ensuring the policy has the given type.
+    fn ensure_policy_type(
+        &self,
+        policy_id: u64,
+        policy_type: ITIP403Registry::PolicyType,
+    ) -> Result<PolicyData> {
+        if policy_id < 2 {
+            return Err(TIP403RegistryError::incompatible_policy_type().into());
+        }
+
+        let data = self.get_policy_data(policy_id)?;
+        if data.policy_type != policy_type as u8 {
+            return Err(TIP403RegistryError::incompatible_policy_type().into());
+        }
+
+        Ok(data)
+    }
+
+    /// Ensures the members of a compound policy exist and are special, whitelist or blacklist
+    /// policies, which keeps evaluation to a single level of lookups.
+    fn validate_compound_members(&self, policy_ids: &[u64]) -> Result<()> {
+        if policy_ids.is_empty() || policy_ids.len() > MAX_COMPOUND_POLICY_MEMBERS {
+            return Err(TIP403RegistryError::invalid_compound_policy().into());
+        }
+
+        for &policy_id in policy_ids {
+            if policy_id < 2 {
+                continue;
+            }
+            match self.policy_type_of(policy_id)? {
+                ITIP403Registry::PolicyType::WHITELIST | ITIP403Registry::PolicyType::BLACKLIST => {
+                }
+                _ => return Err(TIP403RegistryError::invalid_compound_policy().into()),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Ensures every slot of a directional policy references an existing special, whitelist,
+    /// blacklist or compound policy.
+    fn validate_directional_policy(&self, policy: &DirectionalPolicy) -> Result<()> {
+        for policy_id in [
+            policy.sender_policy_id,
+            policy.recipient_policy_id,
+            policy.mint_recipient_policy_id,
+        ] {
+            if policy_id < 2 {
+                continue;
+            }
+            match self.policy_type_of(policy_id)? {
+                ITIP403Registry::PolicyType::WHITELIST
+                | ITIP403Registry::PolicyType::BLACKLIST
+                | ITIP403Registry::PolicyType::COMPOUND => {}
+                _ => return Err(TIP403RegistryError::incompatible_policy_type().into()),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_compound_policy(
+        &mut self,
+        msg_sender: Address,
+        policy_id: u64,
+        operator: ITIP403Registry::CompoundOperator,
+        policy_ids: Vec<u64>,
+    ) -> Result<()> {
+        if operator == ITIP403Registry::CompoundOperator::__Invalid {
+            return Err(TIP403RegistryError::invalid_compound_policy().into());
+        }
+
+        self.compound_operators
+            .at(policy_id)
+            .write(operator as u8)?;
+        self.compound_members
+            .at(policy_id)
+            .write(policy_ids.clone())?;
+
+        self.emit_event(TIP403RegistryEvent::CompoundPolicyUpdated(
+            ITIP403Registry::CompoundPolicyUpdated {
+                policyId: policy_id,
+                updater: msg_sender,
+                operator,
+                policyIds: policy_ids,
+            },
+        ))
+    }
+
+    fn write_directional_policy(
+        &mut self,
+        msg_sender: Address,
+        policy_id: u64,
+        policy: DirectionalPolicy,
+    ) -> Result<()> {
+        self.directional_policies
+            .at(policy_id)
+            .write(policy.clone())?;
+
+        self.emit_event(TIP403RegistryEvent::DirectionalPolicyUpdated(
+            ITIP403Registry::DirectionalPolicyUpdated {
+                policyId: policy_id,
+                updater: msg_sender,
+                senderPolicyId: policy.sender_policy_id,
+                recipientPolicyId: policy.recipient_policy_id,
+                mintRecipientPolicyId: policy.mint_recipient_policy_id,
+            },
+        ))
+    }
+
+    /// Makes a membership permanent after it was set through `modifyPolicyWhitelist` or
+    /// `modifyPolicyBlacklist`. Expiries only exist since Vivace.
+    fn clear_membership_expiry(&mut self, policy_id: u64, account: Address) -> Result<()> {
+        if !self.storage.spec().is_vivace() {
+            return Ok(());
+        }
+
+        if self.membership_expiries.at(policy_id).at(account).read()? != 0 {
+            self.membership_expiries
+                .at(policy_id)
+                .at(account)
+                .write(0)?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks `user` against the policy without a role.
+    ///
+    /// Directional policies require both the sender and the recipient rule to pass.
     fn is_authorized_internal(&self, policy_id: u64, user: Address) -> Result<bool> {
+        self.evaluate(policy_id, user, None)
+    }
+
+    /// Checks `user` against the policy when acting in `role`.
+    ///
+    /// The role only selects the rule of directional policies; any other policy applies to all
+    /// roles alike.
+    fn is_authorized_as_internal(
+        &self,
+        policy_id: u64,
+        user: Address,
+        role: ITIP403Registry::PolicyRole,
+    ) -> Result<bool> {
+        self.evaluate(policy_id, user, Some(role))
+    }
+
+    fn evaluate(
+        &self,
+        policy_id: u64,
+        user: Address,
+        role: Option<ITIP403Registry::PolicyRole>,
+    ) -> Result<bool> {
         // Special case for always-allow and always-reject policies
         if policy_id < 2 {
             // policyId == 0 is the "always-reject" policy
@@ -319,7 +774,36 @@ impl TIP403Registry {
         }
 
         let data = self.get_policy_data(policy_id)?;
-        let is_in_set = self.policy_set.at(policy_id).at(user).read()?;
+
+        // Compound and directional policies have no member set of their own
+        if data.policy_type == ITIP403Registry::PolicyType::COMPOUND as u8 {
+            return self.evaluate_compound(policy_id, user);
+        }
+        if data.policy_type == ITIP403Registry::PolicyType::DIRECTIONAL as u8 {
+            let policy = self.directional_policies.at(policy_id).read()?;
+            return match role {
+                Some(ITIP403Registry::PolicyRole::SENDER) => {
+                    self.evaluate(policy.sender_policy_id, user, None)
+                }
+                Some(ITIP403Registry::PolicyRole::RECIPIENT) => {
+                    self.evaluate(policy.recipient_policy_id, user, None)
+                }
+                Some(ITIP403Registry::PolicyRole::MINT_RECIPIENT) => {
+                    self.evaluate(policy.mint_recipient_policy_id, user, None)
+                }
+                Some(ITIP403Registry::PolicyRole::__Invalid) => Ok(false),
+                None => Ok(self.evaluate(policy.sender_policy_id, user, None)?
+                    && self.evaluate(policy.recipient_policy_id, user, None)?),
+            };
+        }
+
+        let mut is_in_set = self.policy_set.at(policy_id).at(user).read()?;
+
+        // Memberships with an expiry lapse once the block timestamp reaches it
+        if is_in_set && self.storage.spec().is_vivace() {
+            let expiry = self.membership_expiries.at(policy_id).at(user).read()?;
+            is_in_set = expiry == 0 || self.storage.timestamp() < U256::from(expiry);
+        }
 
         let auth = match data
             .policy_type
@@ -328,11 +812,27 @@ impl TIP403Registry {
         {
             ITIP403Registry::PolicyType::WHITELIST => is_in_set,
             ITIP403Registry::PolicyType::BLACKLIST => !is_in_set,
-            ITIP403Registry::PolicyType::__Invalid => false,
+            ITIP403Registry::PolicyType::COMPOUND
+            | ITIP403Registry::PolicyType::DIRECTIONAL
+            | ITIP403Registry::PolicyType::__Invalid => false,
         };
 
         Ok(auth)
     }
+
+    /// Evaluates a compound policy, short-circuiting on the first deciding member.
+    fn evaluate_compound(&self, policy_id: u64, user: Address) -> Result<bool> {
+        let operator = self.compound_operators.at(policy_id).read()?;
+        let is_and = operator == ITIP403Registry::CompoundOperator::AND as u8;
+
+        for member in self.compound_members.at(policy_id).read()? {
+            if self.evaluate(member, user, None)? != is_and {
+                return Ok(!is_and);
+            }
+        }
+
+        Ok(is_and)
+    }
 }
 
 #[cfg(test)]
@@ -340,6 +840,7 @@ mod tests {
     use super::*;
     use crate::storage::{StorageCtx, hashmap::HashMapStorageProvider};
     use alloy::primitives::Address;
+    use tempo_chainspec::hardfork::TempoHardfork;
 
     #[test]
     fn test_create_policy() -> eyre::Result<()> {
@@ -478,4 +979,373 @@ mod tests {
             Ok(())
         })
     }
+
+    #[test]
+    fn test_compound_policy() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        let admin = Address::random();
+        let kyc_user = Address::random();
+        let sanctioned_user = Address::random();
+        let other_user = Address::random();
+        StorageCtx::enter(&mut storage, || {
+            let mut registry = TIP403Registry::new();
+
+            let whitelist = registry.create_policy_with_accounts(
+                admin,
+                ITIP403Registry::createPolicyWithAccountsCall {
+                    admin,
+                    policyType: ITIP403Registry::PolicyType::WHITELIST,
+                    accounts: vec![kyc_user, sanctioned_user],
+                },
+            )?;
+            let blacklist = registry.create_policy_with_accounts(
+                admin,
+                ITIP403Registry::createPolicyWithAccountsCall {
+                    admin,
+                    policyType: ITIP403Registry::PolicyType::BLACKLIST,
+                    accounts: vec![sanctioned_user],
+                },
+            )?;
+
+            let and_policy = registry.create_compound_policy(
+                admin,
+                ITIP403Registry::createCompoundPolicyCall {
+                    admin,
+                    operator: ITIP403Registry::CompoundOperator::AND,
+                    policyIds: vec![whitelist, blacklist],
+                },
+            )?;
+            let data = registry.compound_policy_data(ITIP403Registry::compoundPolicyDataCall {
+                policyId: and_policy,
+            })?;
+            assert_eq!(data.operator, ITIP403Registry::CompoundOperator::AND);
+            assert_eq!(data.policyIds, vec![whitelist, blacklist]);
+
+            let is_authorized = |registry: &TIP403Registry, policy_id, user| {
+                registry.is_authorized(ITIP403Registry::isAuthorizedCall {
+                    policyId: policy_id,
+                    user,
+                })
+            };
+            assert!(is_authorized(&registry, and_policy, kyc_user)?);
+            assert!(!is_authorized(&registry, and_policy, sanctioned_user)?);
+            assert!(!is_authorized(&registry, and_policy, other_user)?);
+
+            // Switching to OR only requires one of the members to pass
+            registry.set_compound_policy(
+                admin,
+                ITIP403Registry::setCompoundPolicyCall {
+                    policyId: and_policy,
+                    operator: ITIP403Registry::CompoundOperator::OR,
+                    policyIds: vec![whitelist, blacklist],
+                },
+            )?;
+            assert!(is_authorized(&registry, and_policy, kyc_user)?);
+            assert!(is_authorized(&registry, and_policy, sanctioned_user)?);
+            assert!(is_authorized(&registry, and_policy, other_user)?);
+
+            // Only the policy admin can update it
+            let result = registry.set_compound_policy(
+                other_user,
+                ITIP403Registry::setCompoundPolicyCall {
+                    policyId: and_policy,
+                    operator: ITIP403Registry::CompoundOperator::AND,
+                    policyIds: vec![whitelist],
+                },
+            );
+            assert!(matches!(
+                result,
+                Err(TempoPrecompileError::TIP403RegistryError(
+                    TIP403RegistryError::Unauthorized(_)
+                ))
+            ));
+
+            // Compound policies cannot be nested, empty or reference unknown policies
+            for policy_ids in [vec![and_policy], vec![], vec![whitelist; 9]] {
+                let result = registry.create_compound_policy(
+                    admin,
+                    ITIP403Registry::createCompoundPolicyCall {
+                        admin,
+                        operator: ITIP403Registry::CompoundOperator::AND,
+                        policyIds: policy_ids,
+                    },
+                );
+                assert!(matches!(
+                    result,
+                    Err(TempoPrecompileError::TIP403RegistryError(
+                        TIP403RegistryError::InvalidCompoundPolicy(_)
+                    ))
+                ));
+            }
+            let result = registry.create_compound_policy(
+                admin,
+                ITIP403Registry::createCompoundPolicyCall {
+                    admin,
+                    operator: ITIP403Registry::CompoundOperator::AND,
+                    policyIds: vec![whitelist, 999],
+                },
+            );
+            assert!(matches!(
+                result,
+                Err(TempoPrecompileError::TIP403RegistryError(
+                    TIP403RegistryError::PolicyNotFound(_)
+                ))
+            ));
+
+            // Members of compound policies cannot be modified as whitelists
+            let result = registry.modify_policy_whitelist(
+                admin,
+                ITIP403Registry::modifyPolicyWhitelistCall {
+                    policyId: and_policy,
+                    account: other_user,
+                    allowed: true,
+                },
+            );
+            assert!(matches!(
+                result,
+                Err(TempoPrecompileError::TIP403RegistryError(
+                    TIP403RegistryError::IncompatiblePolicyType(_)
+                ))
+            ));
+
+            // Compound types can only be created through their own constructor
+            let result = registry.create_policy(
+                admin,
+                ITIP403Registry::createPolicyCall {
+                    admin,
+                    policyType: ITIP403Registry::PolicyType::COMPOUND,
+                },
+            );
+            assert!(matches!(
+                result,
+                Err(TempoPrecompileError::TIP403RegistryError(
+                    TIP403RegistryError::IncompatiblePolicyType(_)
+                ))
+            ));
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_directional_policy() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        let admin = Address::random();
+        let kyc_user = Address::random();
+        let sanctioned_user = Address::random();
+        let other_user = Address::random();
+        StorageCtx::enter(&mut storage, || {
+            let mut registry = TIP403Registry::new();
+
+            let kyc = registry.create_policy_with_accounts(
+                admin,
+                ITIP403Registry::createPolicyWithAccountsCall {
+                    admin,
+                    policyType: ITIP403Registry::PolicyType::WHITELIST,
+                    accounts: vec![kyc_user],
+                },
+            )?;
+            let sanctions = registry.create_policy_with_accounts(
+                admin,
+                ITIP403Registry::createPolicyWithAccountsCall {
+                    admin,
+                    policyType: ITIP403Registry::PolicyType::BLACKLIST,
+                    accounts: vec![sanctioned_user],
+                },
+            )?;
+
+            // Senders must be KYC'd, recipients must not be sanctioned, mints are unrestricted
+            let policy_id = registry.create_directional_policy(
+                admin,
+                ITIP403Registry::createDirectionalPolicyCall {
+                    admin,
+                    senderPolicyId: kyc,
+                    recipientPolicyId: sanctions,
+                    mintRecipientPolicyId: 1,
+                },
+            )?;
+            let data =
+                registry.directional_policy_data(ITIP403Registry::directionalPolicyDataCall {
+                    policyId: policy_id,
+                })?;
+            assert_eq!(data.senderPolicyId, kyc);
+            assert_eq!(data.recipientPolicyId, sanctions);
+            assert_eq!(data.mintRecipientPolicyId, 1);
+
+            let is_transfer_authorized = |registry: &TIP403Registry, from, to| {
+                registry.is_transfer_authorized(ITIP403Registry::isTransferAuthorizedCall {
+                    policyId: policy_id,
+                    from,
+                    to,
+                })
+            };
+            assert!(is_transfer_authorized(&registry, kyc_user, other_user)?);
+            assert!(!is_transfer_authorized(
+                &registry,
+                kyc_user,
+                sanctioned_user
+            )?);
+            assert!(!is_transfer_authorized(&registry, other_user, kyc_user)?);
+
+            assert!(
+                registry.is_authorized_as(ITIP403Registry::isAuthorizedAsCall {
+                    policyId: policy_id,
+                    user: sanctioned_user,
+                    role: ITIP403Registry::PolicyRole::MINT_RECIPIENT,
+                })?
+            );
+
+            // Without a role both the sender and recipient rules apply
+            assert!(registry.is_authorized(ITIP403Registry::isAuthorizedCall {
+                policyId: policy_id,
+                user: kyc_user,
+            })?);
+            assert!(!registry.is_authorized(ITIP403Registry::isAuthorizedCall {
+                policyId: policy_id,
+                user: other_user,
+            })?);
+
+            // Directional policies cannot reference other directional policies
+            let result = registry.set_directional_policy(
+                admin,
+                ITIP403Registry::setDirectionalPolicyCall {
+                    policyId: policy_id,
+                    senderPolicyId: policy_id,
+                    recipientPolicyId: sanctions,
+                    mintRecipientPolicyId: 1,
+                },
+            );
+            assert!(matches!(
+                result,
+                Err(TempoPrecompileError::TIP403RegistryError(
+                    TIP403RegistryError::IncompatiblePolicyType(_)
+                ))
+            ));
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_expiring_memberships() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        let admin = Address::random();
+        let (alice, bob) = (Address::random(), Address::random());
+        StorageCtx::enter(&mut storage, || {
+            StorageCtx.set_timestamp(U256::from(1000));
+            let mut registry = TIP403Registry::new();
+
+            let whitelist = registry.create_policy(
+                admin,
+                ITIP403Registry::createPolicyCall {
+                    admin,
+                    policyType: ITIP403Registry::PolicyType::WHITELIST,
+                },
+            )?;
+            let blacklist = registry.create_policy(
+                admin,
+                ITIP403Registry::createPolicyCall {
+                    admin,
+                    policyType: ITIP403Registry::PolicyType::BLACKLIST,
+                },
+            )?;
+            let is_authorized = |registry: &TIP403Registry, policy_id, user| {
+                registry.is_authorized(ITIP403Registry::isAuthorizedCall {
+                    policyId: policy_id,
+                    user,
+                })
+            };
+
+            // Whitelist both accounts until timestamp 2000
+            registry.modify_policy_members(
+                admin,
+                ITIP403Registry::modifyPolicyMembersCall {
+                    policyId: whitelist,
+                    accounts: vec![alice, bob],
+                    allowed: true,
+                    expiry: 2000,
+                },
+            )?;
+            // Blacklist alice until timestamp 1500
+            registry.modify_policy_members(
+                admin,
+                ITIP403Registry::modifyPolicyMembersCall {
+                    policyId: blacklist,
+                    accounts: vec![alice],
+                    allowed: false,
+                    expiry: 1500,
+                },
+            )?;
+            assert_eq!(
+                registry.membership_expiry(ITIP403Registry::membershipExpiryCall {
+                    policyId: whitelist,
+                    account: bob,
+                })?,
+                2000
+            );
+            assert!(is_authorized(&registry, whitelist, alice)?);
+            assert!(is_authorized(&registry, whitelist, bob)?);
+            assert!(!is_authorized(&registry, blacklist, alice)?);
+
+            // Re-whitelisting bob individually makes the membership permanent
+            registry.modify_policy_whitelist(
+                admin,
+                ITIP403Registry::modifyPolicyWhitelistCall {
+                    policyId: whitelist,
+                    account: bob,
+                    allowed: true,
+                },
+            )?;
+            assert_eq!(
+                registry.membership_expiry(ITIP403Registry::membershipExpiryCall {
+                    policyId: whitelist,
+                    account: bob,
+                })?,
+                0
+            );
+
+            StorageCtx.set_timestamp(U256::from(1500));
+            assert!(is_authorized(&registry, blacklist, alice)?);
+
+            StorageCtx.set_timestamp(U256::from(2000));
+            assert!(!is_authorized(&registry, whitelist, alice)?);
+            assert!(is_authorized(&registry, whitelist, bob)?);
+
+            // Expiries must be in the future
+            let result = registry.modify_policy_members(
+                admin,
+                ITIP403Registry::modifyPolicyMembersCall {
+                    policyId: whitelist,
+                    accounts: vec![alice],
+                    allowed: true,
+                    expiry: 2000,
+                },
+            );
+            assert!(matches!(
+                result,
+                Err(TempoPrecompileError::TIP403RegistryError(
+                    TIP403RegistryError::InvalidMembershipExpiry(_)
+                ))
+            ));
+
+            // Only the policy admin can modify members
+            let result = registry.modify_policy_members(
+                alice,
+                ITIP403Registry::modifyPolicyMembersCall {
+                    policyId: whitelist,
+                    accounts: vec![alice],
+                    allowed: true,
+                    expiry: 0,
+                },
+            );
+            assert!(matches!(
+                result,
+                Err(TempoPrecompileError::TIP403RegistryError(
+                    TIP403RegistryError::Unauthorized(_)
+                ))
+            ));
+
+            Ok(())
+        })
+    }
 }
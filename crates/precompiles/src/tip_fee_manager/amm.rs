@@ -3,6 +3,7 @@ use crate::{
     storage::Handler,
     tip_fee_manager::{ITIPFeeAMM, TIPFeeAMMError, TIPFeeAMMEvent, TipFeeManager},
     tip20::{ITIP20, TIP20Token, validate_usd_currency},
+    validator_config::ValidatorConfig,
 };
 use alloy::{
     primitives::{Address, B256, U256, keccak256, uint},
@@ -16,12 +17,14 @@ pub const N: U256 = uint!(9985_U256);
 pub const SCALE: U256 = uint!(10000_U256);
 pub const SQRT_SCALE: U256 = uint!(100000_U256);
 pub const MIN_LIQUIDITY: U256 = uint!(1000_U256);
+/// Lowest fee swap rate a pool can be configured with (1% fee)
+pub const MIN_FEE_SWAP_RATE: U256 = uint!(9900_U256);
 
-/// Compute amount out for a fee swap
+/// Compute amount out for a fee swap at rate `m` (scaled by [`SCALE`])
 #[inline]
-pub fn compute_amount_out(amount_in: U256) -> Result<U256> {
+pub fn compute_amount_out(amount_in: U256, m: U256) -> Result<U256> {
     amount_in
-        .checked_mul(M)
+        .checked_mul(m)
         .map(|product| product / SCALE)
         .ok_or(TempoPrecompileError::under_overflow())
 }
@@ -42,6 +45,65 @@ impl From<Pool> for ITIPFeeAMM::Pool {
     }
 }
 
+/// Per-pool swap rates scaled by [`SCALE`]. Unset rates fall back to [`M`] and [`N`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Storable)]
+pub struct PoolFees {
+    pub fee_swap_rate: u16,
+    pub rebalance_rate: u16,
+}
+
+impl PoolFees {
+    /// Creates pool fees from explicit rates
+    pub fn new(fee_swap_rate: u16, rebalance_rate: u16) -> Self {
+        Self {
+            fee_swap_rate,
+            rebalance_rate,
+        }
+    }
+
+    /// Rate applied to fee swaps from user token to validator token
+    pub fn m(&self) -> U256 {
+        if self.fee_swap_rate == 0 {
+            M
+        } else {
+            U256::from(self.fee_swap_rate)
+        }
+    }
+
+    /// Rate applied to rebalance swaps from validator token to user token
+    pub fn n(&self) -> U256 {
+        if self.rebalance_rate == 0 {
+            N
+        } else {
+            U256::from(self.rebalance_rate)
+        }
+    }
+
+    /// Returns true if `MIN_FEE_SWAP_RATE <= m <= n <= SCALE`, so that rebalancing never pays
+    /// out more than fee swaps took in and LPs always earn the spread
+    pub fn is_valid(&self) -> bool {
+        MIN_FEE_SWAP_RATE <= self.m() && self.m() <= self.n() && self.n() <= SCALE
+    }
+
+    pub fn decode_from_slot(slot_value: U256) -> Self {
+        use crate::storage::{LayoutCtx, Storable, packing::PackedSlot};
+
+        // NOTE: fine to expect, as `StorageOps` on `PackedSlot` are infallible
+        Self::load(&PackedSlot(slot_value), U256::ZERO, LayoutCtx::FULL)
+            .expect("unable to decode PoolFees from slot")
+    }
+}
+
+impl From<PoolFees> for ITIPFeeAMM::PoolFees {
+    fn from(value: PoolFees) -> Self {
+        // Report the effective rates, falling back to the defaults for unset ones
+        Self {
+            feeSwapRate: value.m().to(),
+            rebalanceRate: value.n().to(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Storable)]
 pub struct PoolKey {
     pub user_token: Address,
@@ -90,6 +152,57 @@ impl TipFeeManager {
         self.pools.at(pool_id).read()
     }
 
+    /// Retrieves the swap rates of a pool. Pre-Vivace, every pool uses [`M`] and [`N`].
+    pub fn get_pool_fees(&self, pool_id: B256) -> Result<PoolFees> {
+        if !self.storage.spec().is_vivace() {
+            return Ok(PoolFees::default());
+        }
+
+        self.pool_fees.at(pool_id).read()
+    }
+
+    /// Sets the swap rates of a pool within the protocol bounds
+    ///
+    /// Only governance (the validator config owner) can change the rates of a pool. Pending fee
+    /// swaps were reserved at the current rate, so the rates cannot change while any are pending.
+    pub fn set_pool_fees(
+        &mut self,
+        msg_sender: Address,
+        call: ITIPFeeAMM::setPoolFeesCall,
+    ) -> Result<()> {
+        if call.userToken == call.validatorToken {
+            return Err(TIPFeeAMMError::identical_addresses().into());
+        }
+
+        // Validate both tokens are USD currency
+        validate_usd_currency(call.userToken, self.storage)?;
+        validate_usd_currency(call.validatorToken, self.storage)?;
+
+        let fees = PoolFees::new(call.feeSwapRate, call.rebalanceRate);
+        if call.feeSwapRate == 0 || call.rebalanceRate == 0 || !fees.is_valid() {
+            return Err(TIPFeeAMMError::invalid_pool_fees().into());
+        }
+
+        if msg_sender != ValidatorConfig::new().owner()? {
+            return Err(TIPFeeAMMError::only_governance().into());
+        }
+
+        let pool_id = self.pool_id(call.userToken, call.validatorToken);
+        if self.get_pending_fee_swap_in(pool_id)? != 0 {
+            return Err(TIPFeeAMMError::cannot_support_pending_swaps().into());
+        }
+
+        self.pool_fees.at(pool_id).write(fees)?;
+
+        self.emit_event(TIPFeeAMMEvent::PoolFeesUpdate(ITIPFeeAMM::PoolFeesUpdate {
+            userToken: call.userToken,
+            validatorToken: call.validatorToken,
+            updater: msg_sender,
+            feeSwapRate: call.feeSwapRate,
+            rebalanceRate: call.rebalanceRate,
+        }))
+    }
+
     /// Ensures that pool has enough liquidity for a fee swap and reserve that liquidity in `pending_fee_swap_in`.
     pub fn reserve_liquidity(
         &mut self,
@@ -110,7 +223,8 @@ impl TipFeeManager {
             )
             .ok_or(TempoPrecompileError::under_overflow())?;
 
-        let total_out_needed = compute_amount_out(U256::from(new_total_pending))?;
+        let m = self.get_pool_fees(pool_id)?.m();
+        let total_out_needed = compute_amount_out(U256::from(new_total_pending), m)?;
 
         let pool = self.pools.at(pool_id).read()?;
         if total_out_needed > U256::from(pool.reserve_validator_token) {
@@ -130,7 +244,8 @@ impl TipFeeManager {
         max_amount: U256,
     ) -> Result<()> {
         let pool_id = PoolKey::new(user_token, validator_token).get_id();
-        let amount_out_needed = compute_amount_out(max_amount)?;
+        let m = self.get_pool_fees(pool_id)?.m();
+        let amount_out_needed = compute_amount_out(max_amount, m)?;
         let pool = self.pools.at(pool_id).read()?;
         if amount_out_needed > U256::from(pool.reserve_validator_token) {
             return Err(TIPFeeAMMError::insufficient_liquidity().into());
@@ -143,7 +258,8 @@ impl TipFeeManager {
     fn get_effective_validator_reserve(&self, pool_id: B256) -> Result<U256> {
         let pool = self.pools.at(pool_id).read()?;
         let pending_fee_swap_in = self.get_pending_fee_swap_in(pool_id)?;
-        let pending_out = compute_amount_out(U256::from(pending_fee_swap_in))?;
+        let m = self.get_pool_fees(pool_id)?.m();
+        let pending_out = compute_amount_out(U256::from(pending_fee_swap_in), m)?;
 
         U256::from(pool.reserve_validator_token)
             .checked_sub(pending_out)
@@ -201,8 +317,9 @@ impl TipFeeManager {
 
         // Rebalancing swaps are always from validatorToken to userToken
         // Calculate input and update reserves
+        let n = self.get_pool_fees(pool_id)?.n();
         let amount_in = amount_out
-            .checked_mul(N)
+            .checked_mul(n)
             .and_then(|product| product.checked_div(SCALE))
             .and_then(|result| result.checked_add(U256::ONE))
             .ok_or(TempoPrecompileError::under_overflow())?;
@@ -427,7 +544,9 @@ impl TipFeeManager {
         } else {
             // Subsequent deposits: mint as if user called rebalanceSwap then minted with both
             // liquidity = amountValidatorToken * _totalSupply / (V + n * U), with n = N / SCALE
-            let product = N
+            let product = self
+                .get_pool_fees(pool_id)?
+                .n()
                 .checked_mul(U256::from(pool.reserve_user_token))
                 .and_then(|product| product.checked_div(SCALE))
                 .ok_or(TIPFeeAMMError::invalid_swap_calculation())?;
@@ -507,6 +626,213 @@ impl TipFeeManager {
         validator_token: Address,
         liquidity: U256,
         to: Address,
+    ) -> Result<(U256, U256)> {
+        let (amount_user_token, amount_validator_token) =
+            self.burn_liquidity(msg_sender, user_token, validator_token, liquidity)?;
+
+        // Transfer tokens to user
+        let _ = TIP20Token::from_address(user_token)?.transfer(
+            self.address,
+            ITIP20::transferCall {
+                to,
+                amount: amount_user_token,
+            },
+        )?;
+
+        let _ = TIP20Token::from_address(validator_token)?.transfer(
+            self.address,
+            ITIP20::transferCall {
+                to,
+                amount: amount_validator_token,
+            },
+        )?;
+
+        // Emit Burn event
+        self.emit_event(TIPFeeAMMEvent::Burn(ITIPFeeAMM::Burn {
+            sender: msg_sender,
+            userToken: user_token,
+            validatorToken: validator_token,
+            amountUserToken: amount_user_token,
+            amountValidatorToken: amount_validator_token,
+            liquidity,
+            to,
+        }))?;
+
+        Ok((amount_user_token, amount_validator_token))
+    }
+
+    /// Mint LP tokens using only validator tokens, with slippage and deadline protection
+    pub fn mint_with_validator_token_bounded(
+        &mut self,
+        msg_sender: Address,
+        call: ITIPFeeAMM::mintWithValidatorTokenBoundedCall,
+    ) -> Result<U256> {
+        self.check_deadline(call.deadline)?;
+
+        let liquidity = self.mint_with_validator_token(
+            msg_sender,
+            call.userToken,
+            call.validatorToken,
+            call.amountValidatorToken,
+            call.to,
+        )?;
+        if liquidity < call.minLiquidity {
+            return Err(TIPFeeAMMError::slippage_exceeded().into());
+        }
+
+        Ok(liquidity)
+    }
+
+    /// Burn LP tokens for a given pool, with slippage and deadline protection
+    pub fn burn_bounded(
+        &mut self,
+        msg_sender: Address,
+        call: ITIPFeeAMM::burnBoundedCall,
+    ) -> Result<(U256, U256)> {
+        self.check_deadline(call.deadline)?;
+
+        let (amount_user_token, amount_validator_token) = self.burn(
+            msg_sender,
+            call.userToken,
+            call.validatorToken,
+            call.liquidity,
+            call.to,
+        )?;
+        if amount_user_token < call.minAmountUserToken
+            || amount_validator_token < call.minAmountValidatorToken
+        {
+            return Err(TIPFeeAMMError::slippage_exceeded().into());
+        }
+
+        Ok((amount_user_token, amount_validator_token))
+    }
+
+    /// Burn LP tokens and withdraw the whole amount in a single token of the pool
+    ///
+    /// The validator token share is swapped into user tokens at the rebalance rate `n`, as if the
+    /// LP had called `rebalanceSwap` with it. The user token share is swapped into validator
+    /// tokens at the fee swap rate `m`, leaving liquidity reserved for pending fee swaps untouched.
+    pub fn burn_single_sided(
+        &mut self,
+        msg_sender: Address,
+        call: ITIPFeeAMM::burnSingleSidedCall,
+    ) -> Result<U256> {
+        self.check_deadline(call.deadline)?;
+
+        if call.outToken != call.userToken && call.outToken != call.validatorToken {
+            return Err(TIPFeeAMMError::invalid_token().into());
+        }
+
+        let (amount_user_token, amount_validator_token) = self.burn_liquidity(
+            msg_sender,
+            call.userToken,
+            call.validatorToken,
+            call.liquidity,
+        )?;
+
+        let pool_id = self.pool_id(call.userToken, call.validatorToken);
+        let mut pool = self.pools.at(pool_id).read()?;
+        let (amount_user_token, amount_validator_token) = if call.outToken == call.userToken {
+            // Largest amount out whose rebalance swap input `out * n / SCALE + 1` fits in the
+            // validator token share. The whole share stays in the pool.
+            let n = self.get_pool_fees(pool_id)?.n();
+            let swap_out = amount_validator_token
+                .saturating_sub(U256::ONE)
+                .checked_mul(SCALE)
+                .and_then(|product| product.checked_div(n))
+                .ok_or(TempoPrecompileError::under_overflow())?;
+
+            let swap_in: u128 = amount_validator_token
+                .try_into()
+                .map_err(|_| TIPFeeAMMError::invalid_amount())?;
+            let swap_out_u128: u128 = swap_out
+                .try_into()
+                .map_err(|_| TIPFeeAMMError::invalid_amount())?;
+            pool.reserve_validator_token = pool
+                .reserve_validator_token
+                .checked_add(swap_in)
+                .ok_or(TIPFeeAMMError::invalid_amount())?;
+            pool.reserve_user_token = pool
+                .reserve_user_token
+                .checked_sub(swap_out_u128)
+                .ok_or(TIPFeeAMMError::insufficient_reserves())?;
+
+            let amount_out = amount_user_token
+                .checked_add(swap_out)
+                .ok_or(TempoPrecompileError::under_overflow())?;
+            (amount_out, U256::ZERO)
+        } else {
+            let m = self.get_pool_fees(pool_id)?.m();
+            let swap_out = compute_amount_out(amount_user_token, m)?;
+            if swap_out > self.get_effective_validator_reserve(pool_id)? {
+                return Err(TIPFeeAMMError::insufficient_liquidity().into());
+            }
+
+            let swap_in: u128 = amount_user_token
+                .try_into()
+                .map_err(|_| TIPFeeAMMError::invalid_amount())?;
+            let swap_out_u128: u128 = swap_out
+                .try_into()
+                .map_err(|_| TIPFeeAMMError::invalid_amount())?;
+            pool.reserve_user_token = pool
+                .reserve_user_token
+                .checked_add(swap_in)
+                .ok_or(TIPFeeAMMError::invalid_amount())?;
+            pool.reserve_validator_token = pool
+                .reserve_validator_token
+                .checked_sub(swap_out_u128)
+                .ok_or(TIPFeeAMMError::insufficient_reserves())?;
+
+            let amount_out = amount_validator_token
+                .checked_add(swap_out)
+                .ok_or(TempoPrecompileError::under_overflow())?;
+            (U256::ZERO, amount_out)
+        };
+        self.pools.at(pool_id).write(pool)?;
+
+        let amount_out = amount_user_token.max(amount_validator_token);
+        if amount_out < call.minOut {
+            return Err(TIPFeeAMMError::slippage_exceeded().into());
+        }
+
+        TIP20Token::from_address(call.outToken)?.transfer(
+            self.address,
+            ITIP20::transferCall {
+                to: call.to,
+                amount: amount_out,
+            },
+        )?;
+
+        self.emit_event(TIPFeeAMMEvent::Burn(ITIPFeeAMM::Burn {
+            sender: msg_sender,
+            userToken: call.userToken,
+            validatorToken: call.validatorToken,
+            amountUserToken: amount_user_token,
+            amountValidatorToken: amount_validator_token,
+            liquidity: call.liquidity,
+            to: call.to,
+        }))?;
+
+        Ok(amount_out)
+    }
+
+    /// Ensures a liquidity operation is executed no later than `deadline`
+    fn check_deadline(&self, deadline: U256) -> Result<()> {
+        if self.storage.timestamp() > deadline {
+            return Err(TIPFeeAMMError::deadline_expired().into());
+        }
+
+        Ok(())
+    }
+
+    /// Burn LP tokens of `msg_sender` and remove their share from the pool reserves, returning the
+    /// amounts of each token withdrawn
+    fn burn_liquidity(
+        &mut self,
+        msg_sender: Address,
+        user_token: Address,
+        validator_token: Address,
+        liquidity: U256,
     ) -> Result<(U256, U256)> {
         if user_token == validator_token {
             return Err(TIPFeeAMMError::identical_addresses().into());
@@ -562,34 +888,6 @@ impl TipFeeManager {
             .ok_or(TIPFeeAMMError::insufficient_reserves())?;
         self.pools.at(pool_id).write(pool)?;
 
-        // Transfer tokens to user
-        let _ = TIP20Token::from_address(user_token)?.transfer(
-            self.address,
-            ITIP20::transferCall {
-                to,
-                amount: amount_user_token,
-            },
-        )?;
-
-        let _ = TIP20Token::from_address(validator_token)?.transfer(
-            self.address,
-            ITIP20::transferCall {
-                to,
-                amount: amount_validator_token,
-            },
-        )?;
-
-        // Emit Burn event
-        self.emit_event(TIPFeeAMMEvent::Burn(ITIPFeeAMM::Burn {
-            sender: msg_sender,
-            userToken: user_token,
-            validatorToken: validator_token,
-            amountUserToken: amount_user_token,
-            amountValidatorToken: amount_validator_token,
-            liquidity,
-            to,
-        }))?;
-
         Ok((amount_user_token, amount_validator_token))
     }
 
@@ -630,7 +928,8 @@ impl TipFeeManager {
         Ok((amount_user_token, amount_validator_token))
     }
 
-    /// Executes a fee swap immediately, converting userToken to validatorToken at the fixed rate m = 0.9970.
+    /// Executes a fee swap immediately, converting userToken to validatorToken at the pool's rate m
+    /// (0.9970 unless configured otherwise).
     /// Called by FeeManager.collectFeePostTx during post-transaction fee collection.
     pub fn execute_fee_swap(
         &mut self,
@@ -641,8 +940,9 @@ impl TipFeeManager {
         let pool_id = self.pool_id(user_token, validator_token);
         let mut pool = self.pools.at(pool_id).read()?;
 
-        // Calculate output at fixed price m = 0.9970
-        let amount_out = compute_amount_out(amount_in)?;
+        // Calculate output at the pool's price m
+        let m = self.get_pool_fees(pool_id)?.m();
+        let amount_out = compute_amount_out(amount_in, m)?;
 
         // Check if there's enough validatorToken available
         if amount_out > U256::from(pool.reserve_validator_token) {
@@ -681,7 +981,8 @@ impl TipFeeManager {
         let mut pool = self.pools.at(pool_id).read()?;
 
         let amount_in = U256::from(self.get_pending_fee_swap_in(pool_id)?);
-        let pending_out = compute_amount_out(amount_in)?;
+        let m = self.get_pool_fees(pool_id)?.m();
+        let pending_out = compute_amount_out(amount_in, m)?;
 
         // Use checked math for these operations
         let new_user_reserve = U256::from(pool.reserve_user_token)
@@ -736,6 +1037,142 @@ impl TipFeeManager {
         self.liquidity_balances.at(pool_id).at(user).write(balance)
     }
 
+    /// Get the LP tokens of a pool `spender` may transfer on behalf of `owner`
+    pub fn get_liquidity_allowance(
+        &self,
+        pool_id: B256,
+        owner: Address,
+        spender: Address,
+    ) -> Result<U256> {
+        self.liquidity_allowances
+            .at(pool_id)
+            .at(owner)
+            .at(spender)
+            .read()
+    }
+
+    /// Transfer LP tokens of a pool from `msg_sender` to `to`
+    pub fn transfer_liquidity(
+        &mut self,
+        msg_sender: Address,
+        call: ITIPFeeAMM::transferLiquidityCall,
+    ) -> Result<bool> {
+        self._transfer_liquidity(
+            call.userToken,
+            call.validatorToken,
+            msg_sender,
+            call.to,
+            call.liquidity,
+        )?;
+        Ok(true)
+    }
+
+    /// Approve `spender` to transfer LP tokens of a pool on behalf of `msg_sender`
+    pub fn approve_liquidity(
+        &mut self,
+        msg_sender: Address,
+        call: ITIPFeeAMM::approveLiquidityCall,
+    ) -> Result<bool> {
+        let pool_id = self.pool_id(call.userToken, call.validatorToken);
+        self.liquidity_allowances
+            .at(pool_id)
+            .at(msg_sender)
+            .at(call.spender)
+            .write(call.liquidity)?;
+
+        self.emit_event(TIPFeeAMMEvent::LiquidityApproval(
+            ITIPFeeAMM::LiquidityApproval {
+                poolId: pool_id,
+                owner: msg_sender,
+                spender: call.spender,
+                liquidity: call.liquidity,
+            },
+        ))?;
+
+        Ok(true)
+    }
+
+    /// Transfer LP tokens of a pool from `from` to `to` using the allowance of `msg_sender`
+    pub fn transfer_liquidity_from(
+        &mut self,
+        msg_sender: Address,
+        call: ITIPFeeAMM::transferLiquidityFromCall,
+    ) -> Result<bool> {
+        let pool_id = self.pool_id(call.userToken, call.validatorToken);
+        let allowed = self.get_liquidity_allowance(pool_id, call.from, msg_sender)?;
+        if call.liquidity > allowed {
+            return Err(TIPFeeAMMError::insufficient_liquidity_allowance().into());
+        }
+
+        // An unlimited allowance is never spent
+        if allowed != U256::MAX {
+            self.liquidity_allowances
+                .at(pool_id)
+                .at(call.from)
+                .at(msg_sender)
+                .write(allowed - call.liquidity)?;
+        }
+
+        self._transfer_liquidity(
+            call.userToken,
+            call.validatorToken,
+            call.from,
+            call.to,
+            call.liquidity,
+        )?;
+        Ok(true)
+    }
+
+    /// Move LP tokens between accounts, subject to the TIP-403 policies of both pool tokens
+    fn _transfer_liquidity(
+        &mut self,
+        user_token: Address,
+        validator_token: Address,
+        from: Address,
+        to: Address,
+        liquidity: U256,
+    ) -> Result<()> {
+        if user_token == validator_token {
+            return Err(TIPFeeAMMError::identical_addresses().into());
+        }
+
+        if to.is_zero() {
+            return Err(TIPFeeAMMError::zero_address().into());
+        }
+
+        // LP tokens are a claim on both reserves, so both tokens must authorize the transfer
+        for token in [user_token, validator_token] {
+            if !TIP20Token::from_address(token)?.is_transfer_authorized(from, to)? {
+                return Err(TIPFeeAMMError::liquidity_policy_forbids().into());
+            }
+        }
+
+        let pool_id = self.pool_id(user_token, validator_token);
+        let from_balance = self.get_liquidity_balances(pool_id, from)?;
+        if liquidity > from_balance {
+            return Err(TIPFeeAMMError::insufficient_liquidity_balance().into());
+        }
+        self.set_liquidity_balances(pool_id, from, from_balance - liquidity)?;
+
+        let to_balance = self.get_liquidity_balances(pool_id, to)?;
+        self.set_liquidity_balances(
+            pool_id,
+            to,
+            to_balance
+                .checked_add(liquidity)
+                .ok_or(TempoPrecompileError::under_overflow())?,
+        )?;
+
+        self.emit_event(TIPFeeAMMEvent::LiquidityTransfer(
+            ITIPFeeAMM::LiquidityTransfer {
+                poolId: pool_id,
+                from,
+                to,
+                liquidity,
+            },
+        ))
+    }
+
     /// Get pending fee swap amount for a pool
     pub fn get_pending_fee_swap_in(&self, pool_id: B256) -> Result<u128> {
         self.pending_fee_swap_in.at(pool_id).read()
@@ -769,11 +1206,14 @@ mod tests {
     use super::*;
     use crate::{
         error::TempoPrecompileError,
-        storage::{ContractStorage, StorageCtx, hashmap::HashMapStorageProvider},
+        storage::{
+            ContractStorage, PrecompileStorageProvider, StorageCtx, hashmap::HashMapStorageProvider,
+        },
         test_util::TIP20Setup,
         tip_fee_manager::TIPFeeAMMError,
+        tip403_registry::{ITIP403Registry, TIP403Registry},
     };
-    use alloy::primitives::Address;
+    use alloy::primitives::{Address, IntoLogData};
 
     /// Sets up a pool with initial liquidity for testing
     fn setup_pool_with_liquidity(
@@ -1725,4 +2165,382 @@ mod tests {
             Ok(())
         })
     }
+
+    #[test]
+    fn test_pool_fees() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        let admin = Address::random();
+        let governance = Address::random();
+        let to = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            ValidatorConfig::new().initialize(governance)?;
+            let mut amm = TipFeeManager::new();
+            let balance = uint!(1000000_U256);
+            let user_token = TIP20Setup::create("UserToken", "UTK", admin)
+                .with_issuer(admin)
+                .with_mint(amm.address, balance)
+                .apply()?
+                .address();
+            let validator_token = TIP20Setup::create("ValidatorToken", "VTK", admin)
+                .with_issuer(admin)
+                .with_mint(admin, balance)
+                .apply()?
+                .address();
+
+            let pool_id = amm.pool_id(user_token, validator_token);
+            assert_eq!(amm.get_pool_fees(pool_id)?.m(), M);
+            assert_eq!(amm.get_pool_fees(pool_id)?.n(), N);
+
+            let set_fees = |fee_swap_rate, rebalance_rate| ITIPFeeAMM::setPoolFeesCall {
+                userToken: user_token,
+                validatorToken: validator_token,
+                feeSwapRate: fee_swap_rate,
+                rebalanceRate: rebalance_rate,
+            };
+
+            // Rates must satisfy MIN_FEE_SWAP_RATE <= m <= n <= SCALE
+            for (m, n) in [(9899, 9950), (9990, 9980), (9990, 10001), (0, 9985)] {
+                assert_eq!(
+                    amm.set_pool_fees(admin, set_fees(m, n)),
+                    Err(TIPFeeAMMError::invalid_pool_fees().into())
+                );
+            }
+
+            // Only governance can configure a pool, even before it has liquidity
+            assert_eq!(
+                amm.set_pool_fees(admin, set_fees(9990, 9995)),
+                Err(TIPFeeAMMError::only_governance().into())
+            );
+            amm.set_pool_fees(governance, set_fees(9990, 9995))?;
+            assert_eq!(amm.get_pool_fees(pool_id)?, PoolFees::new(9990, 9995));
+            assert_eq!(
+                amm.emitted_events().last(),
+                Some(
+                    &TIPFeeAMMEvent::PoolFeesUpdate(ITIPFeeAMM::PoolFeesUpdate {
+                        userToken: user_token,
+                        validatorToken: validator_token,
+                        updater: governance,
+                        feeSwapRate: 9990,
+                        rebalanceRate: 9995,
+                    })
+                    .into_log_data()
+                )
+            );
+
+            let liquidity = uint!(100000_U256) * uint!(10_U256).pow(U256::from(6));
+            setup_pool_with_liquidity(&mut amm, user_token, validator_token, liquidity, liquidity)?;
+
+            // Fee swaps and rebalance swaps use the pool's rates
+            let amount = uint!(10000_U256);
+            assert_eq!(
+                amm.execute_fee_swap(user_token, validator_token, amount)?,
+                amount * uint!(9990_U256) / SCALE
+            );
+            assert_eq!(
+                amm.rebalance_swap(admin, user_token, validator_token, amount, to)?,
+                amount * uint!(9995_U256) / SCALE + U256::ONE
+            );
+
+            // Rates cannot change while fee swaps reserved at the current rate are pending
+            amm.reserve_liquidity(user_token, validator_token, amount)?;
+            assert_eq!(
+                amm.set_pool_fees(governance, set_fees(9970, 9985)),
+                Err(TIPFeeAMMError::cannot_support_pending_swaps().into())
+            );
+            amm.execute_pending_fee_swaps(user_token, validator_token)?;
+
+            amm.set_pool_fees(governance, set_fees(9970, 9985))?;
+            assert_eq!(amm.get_pool_fees(pool_id)?, PoolFees::new(9970, 9985));
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_pool_fees_pre_vivace() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::AllegroModerato);
+        StorageCtx::enter(&mut storage, || {
+            let mut amm = TipFeeManager::new();
+            let pool_id = B256::random();
+
+            // Rates written to storage are ignored before Vivace
+            amm.pool_fees.at(pool_id).write(PoolFees::new(9990, 9995))?;
+            assert_eq!(amm.get_pool_fees(pool_id)?, PoolFees::default());
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_transfer_liquidity() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        let admin = Address::random();
+        let alice = Address::random();
+        let bob = Address::random();
+        let spender = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut amm = TipFeeManager::new();
+            let user_token = TIP20Setup::create("UserToken", "UTK", admin)
+                .apply()?
+                .address();
+            let validator_token = TIP20Setup::create("ValidatorToken", "VTK", admin)
+                .apply()?
+                .address();
+
+            let pool_id = amm.pool_id(user_token, validator_token);
+            amm.liquidity_balances
+                .at(pool_id)
+                .at(alice)
+                .write(uint!(1000_U256))?;
+
+            let transfer = |to, liquidity| ITIPFeeAMM::transferLiquidityCall {
+                userToken: user_token,
+                validatorToken: validator_token,
+                to,
+                liquidity,
+            };
+
+            assert!(amm.transfer_liquidity(alice, transfer(bob, uint!(400_U256)))?);
+            assert_eq!(amm.get_liquidity_balances(pool_id, alice)?, uint!(600_U256));
+            assert_eq!(amm.get_liquidity_balances(pool_id, bob)?, uint!(400_U256));
+            assert_eq!(
+                amm.emitted_events().last(),
+                Some(
+                    &TIPFeeAMMEvent::LiquidityTransfer(ITIPFeeAMM::LiquidityTransfer {
+                        poolId: pool_id,
+                        from: alice,
+                        to: bob,
+                        liquidity: uint!(400_U256),
+                    })
+                    .into_log_data()
+                )
+            );
+
+            assert_eq!(
+                amm.transfer_liquidity(alice, transfer(bob, uint!(601_U256))),
+                Err(TIPFeeAMMError::insufficient_liquidity_balance().into())
+            );
+            assert_eq!(
+                amm.transfer_liquidity(alice, transfer(Address::ZERO, uint!(1_U256))),
+                Err(TIPFeeAMMError::zero_address().into())
+            );
+
+            // Transfers on behalf of the owner spend the allowance
+            assert!(amm.approve_liquidity(
+                alice,
+                ITIPFeeAMM::approveLiquidityCall {
+                    userToken: user_token,
+                    validatorToken: validator_token,
+                    spender,
+                    liquidity: uint!(300_U256),
+                },
+            )?);
+            assert_eq!(
+                amm.get_liquidity_allowance(pool_id, alice, spender)?,
+                uint!(300_U256)
+            );
+
+            let transfer_from = |liquidity| ITIPFeeAMM::transferLiquidityFromCall {
+                userToken: user_token,
+                validatorToken: validator_token,
+                from: alice,
+                to: bob,
+                liquidity,
+            };
+            assert!(amm.transfer_liquidity_from(spender, transfer_from(uint!(200_U256)))?);
+            assert_eq!(
+                amm.get_liquidity_allowance(pool_id, alice, spender)?,
+                uint!(100_U256)
+            );
+            assert_eq!(amm.get_liquidity_balances(pool_id, alice)?, uint!(400_U256));
+            assert_eq!(amm.get_liquidity_balances(pool_id, bob)?, uint!(600_U256));
+            assert_eq!(
+                amm.transfer_liquidity_from(spender, transfer_from(uint!(101_U256))),
+                Err(TIPFeeAMMError::insufficient_liquidity_allowance().into())
+            );
+
+            // Blacklisting the recipient on either pool token forbids the transfer
+            let mut registry = TIP403Registry::new();
+            let policy_id = registry.create_policy(
+                admin,
+                ITIP403Registry::createPolicyCall {
+                    admin,
+                    policyType: ITIP403Registry::PolicyType::BLACKLIST,
+                },
+            )?;
+            registry.modify_policy_blacklist(
+                admin,
+                ITIP403Registry::modifyPolicyBlacklistCall {
+                    policyId: policy_id,
+                    account: bob,
+                    restricted: true,
+                },
+            )?;
+            TIP20Token::from_address(user_token)?.change_transfer_policy_id(
+                admin,
+                ITIP20::changeTransferPolicyIdCall {
+                    newPolicyId: policy_id,
+                },
+            )?;
+
+            assert_eq!(
+                amm.transfer_liquidity(alice, transfer(bob, uint!(1_U256))),
+                Err(TIPFeeAMMError::liquidity_policy_forbids().into())
+            );
+            assert_eq!(amm.get_liquidity_balances(pool_id, alice)?, uint!(400_U256));
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_bounded_liquidity_operations() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        let now = storage.timestamp();
+        let admin = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mint_amount = uint!(10000000_U256);
+            let user_token = TIP20Setup::create("UserToken", "UTK", admin)
+                .with_issuer(admin)
+                .with_mint(admin, mint_amount)
+                .apply()?
+                .address();
+            let validator_token = TIP20Setup::create("ValidatorToken", "VTK", admin)
+                .with_issuer(admin)
+                .with_mint(admin, mint_amount)
+                .apply()?
+                .address();
+
+            let mut amm = TipFeeManager::new();
+            let pool_id = amm.pool_id(user_token, validator_token);
+            let amount = uint!(1000000_U256);
+            amm.mint(admin, user_token, validator_token, amount, amount, admin)?;
+
+            // liquidity = amount * totalSupply / (V + n * U) = 10000 * 1e6 / 1998500
+            let liquidity = amm.mint_with_validator_token_bounded(
+                admin,
+                ITIPFeeAMM::mintWithValidatorTokenBoundedCall {
+                    userToken: user_token,
+                    validatorToken: validator_token,
+                    amountValidatorToken: uint!(10000_U256),
+                    minLiquidity: uint!(5003_U256),
+                    to: admin,
+                    deadline: now,
+                },
+            )?;
+            assert_eq!(liquidity, uint!(5003_U256));
+
+            let burn = |liquidity, deadline| ITIPFeeAMM::burnBoundedCall {
+                userToken: user_token,
+                validatorToken: validator_token,
+                liquidity,
+                minAmountUserToken: uint!(990_U256),
+                minAmountValidatorToken: uint!(1000_U256),
+                to: admin,
+                deadline,
+            };
+            let pool = amm.pools.at(pool_id).read()?;
+            let total_supply = amm.get_total_supply(pool_id)?;
+            let liquidity = uint!(1000_U256);
+            assert_eq!(
+                amm.burn_bounded(admin, burn(liquidity, now))?,
+                (
+                    liquidity * U256::from(pool.reserve_user_token) / total_supply,
+                    liquidity * U256::from(pool.reserve_validator_token) / total_supply,
+                )
+            );
+
+            // Withdrawing in user tokens swaps the validator token share at the rebalance rate
+            let burn_single_sided = |out_token, to, min_out| ITIPFeeAMM::burnSingleSidedCall {
+                userToken: user_token,
+                validatorToken: validator_token,
+                liquidity,
+                outToken: out_token,
+                minOut: min_out,
+                to,
+                deadline: now,
+            };
+            let to = Address::random();
+            let pool = amm.pools.at(pool_id).read()?;
+            let total_supply = amm.get_total_supply(pool_id)?;
+            let user_share = liquidity * U256::from(pool.reserve_user_token) / total_supply;
+            let validator_share =
+                liquidity * U256::from(pool.reserve_validator_token) / total_supply;
+            let expected_out = user_share + (validator_share - U256::ONE) * SCALE / N;
+
+            let amount_out =
+                amm.burn_single_sided(admin, burn_single_sided(user_token, to, expected_out))?;
+            assert_eq!(amount_out, expected_out);
+            assert_eq!(
+                TIP20Token::from_address(user_token)?
+                    .balance_of(ITIP20::balanceOfCall { account: to })?,
+                expected_out
+            );
+            let pool_after = amm.pools.at(pool_id).read()?;
+            assert_eq!(
+                U256::from(pool_after.reserve_user_token),
+                U256::from(pool.reserve_user_token) - expected_out
+            );
+            assert_eq!(
+                pool_after.reserve_validator_token,
+                pool.reserve_validator_token
+            );
+
+            // Withdrawing in validator tokens swaps the user token share at the fee swap rate
+            let to = Address::random();
+            let pool = pool_after;
+            let total_supply = amm.get_total_supply(pool_id)?;
+            let user_share = liquidity * U256::from(pool.reserve_user_token) / total_supply;
+            let validator_share =
+                liquidity * U256::from(pool.reserve_validator_token) / total_supply;
+            let expected_out = validator_share + compute_amount_out(user_share, M)?;
+
+            let amount_out =
+                amm.burn_single_sided(admin, burn_single_sided(validator_token, to, expected_out))?;
+            assert_eq!(amount_out, expected_out);
+            assert_eq!(
+                TIP20Token::from_address(validator_token)?
+                    .balance_of(ITIP20::balanceOfCall { account: to })?,
+                expected_out
+            );
+            let pool_after = amm.pools.at(pool_id).read()?;
+            assert_eq!(pool_after.reserve_user_token, pool.reserve_user_token);
+            assert_eq!(
+                U256::from(pool_after.reserve_validator_token),
+                U256::from(pool.reserve_validator_token) - expected_out
+            );
+
+            assert_eq!(
+                amm.burn_bounded(admin, burn(liquidity, now - U256::ONE)),
+                Err(TIPFeeAMMError::deadline_expired().into())
+            );
+            assert_eq!(
+                amm.burn_single_sided(admin, burn_single_sided(Address::random(), to, U256::ZERO)),
+                Err(TIPFeeAMMError::invalid_token().into())
+            );
+            assert_eq!(
+                amm.burn_single_sided(admin, burn_single_sided(user_token, to, U256::MAX)),
+                Err(TIPFeeAMMError::slippage_exceeded().into())
+            );
+            assert_eq!(
+                amm.mint_with_validator_token_bounded(
+                    admin,
+                    ITIPFeeAMM::mintWithValidatorTokenBoundedCall {
+                        userToken: user_token,
+                        validatorToken: validator_token,
+                        amountValidatorToken: uint!(10000_U256),
+                        minLiquidity: U256::MAX,
+                        to: admin,
+                        deadline: now,
+                    },
+                ),
+                Err(TIPFeeAMMError::slippage_exceeded().into())
+            );
+
+            Ok(())
+        })
+    }
 }
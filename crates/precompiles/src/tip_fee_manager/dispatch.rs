@@ -64,6 +64,16 @@ impl Precompile for TipFeeManager {
                     reserveValidatorToken: pool.reserve_validator_token,
                 })
             }),
+            ITIPFeeAMM::getPoolFeesCall::SELECTOR => {
+                if self.storage.spec().is_vivace() {
+                    view::<ITIPFeeAMM::getPoolFeesCall>(calldata, |call| {
+                        let pool_id = self.pool_id(call.userToken, call.validatorToken);
+                        self.get_pool_fees(pool_id).map(Into::into)
+                    })
+                } else {
+                    unknown_selector(selector, self.storage.gas_used(), self.storage.spec())
+                }
+            }
             ITIPFeeAMM::totalSupplyCall::SELECTOR => {
                 view::<ITIPFeeAMM::totalSupplyCall>(calldata, |call| {
                     self.total_supply.at(call.poolId).read()
@@ -74,6 +84,15 @@ impl Precompile for TipFeeManager {
                     self.liquidity_balances.at(call.poolId).at(call.user).read()
                 })
             }
+            ITIPFeeAMM::liquidityAllowanceCall::SELECTOR => {
+                if self.storage.spec().is_vivace() {
+                    view::<ITIPFeeAMM::liquidityAllowanceCall>(calldata, |call| {
+                        self.get_liquidity_allowance(call.poolId, call.owner, call.spender)
+                    })
+                } else {
+                    unknown_selector(selector, self.storage.gas_used(), self.storage.spec())
+                }
+            }
             ITIPFeeAMM::MCall::SELECTOR => view::<ITIPFeeAMM::MCall>(calldata, |_call| Ok(M)),
             ITIPFeeAMM::NCall::SELECTOR => view::<ITIPFeeAMM::NCall>(calldata, |_call| Ok(N)),
             ITIPFeeAMM::SCALECall::SELECTOR => {
@@ -172,6 +191,79 @@ impl Precompile for TipFeeManager {
                     })
                 })
             }
+            ITIPFeeAMM::mintWithValidatorTokenBoundedCall::SELECTOR => {
+                if self.storage.spec().is_vivace() {
+                    mutate::<ITIPFeeAMM::mintWithValidatorTokenBoundedCall>(
+                        calldata,
+                        msg_sender,
+                        |s, call| self.mint_with_validator_token_bounded(s, call),
+                    )
+                } else {
+                    unknown_selector(selector, self.storage.gas_used(), self.storage.spec())
+                }
+            }
+            ITIPFeeAMM::burnBoundedCall::SELECTOR => {
+                if self.storage.spec().is_vivace() {
+                    mutate::<ITIPFeeAMM::burnBoundedCall>(calldata, msg_sender, |s, call| {
+                        let (amount_user_token, amount_validator_token) =
+                            self.burn_bounded(s, call)?;
+
+                        Ok(ITIPFeeAMM::burnBoundedReturn {
+                            amountUserToken: amount_user_token,
+                            amountValidatorToken: amount_validator_token,
+                        })
+                    })
+                } else {
+                    unknown_selector(selector, self.storage.gas_used(), self.storage.spec())
+                }
+            }
+            ITIPFeeAMM::burnSingleSidedCall::SELECTOR => {
+                if self.storage.spec().is_vivace() {
+                    mutate::<ITIPFeeAMM::burnSingleSidedCall>(calldata, msg_sender, |s, call| {
+                        self.burn_single_sided(s, call)
+                    })
+                } else {
+                    unknown_selector(selector, self.storage.gas_used(), self.storage.spec())
+                }
+            }
+            ITIPFeeAMM::setPoolFeesCall::SELECTOR => {
+                if self.storage.spec().is_vivace() {
+                    mutate_void::<ITIPFeeAMM::setPoolFeesCall>(calldata, msg_sender, |s, call| {
+                        self.set_pool_fees(s, call)
+                    })
+                } else {
+                    unknown_selector(selector, self.storage.gas_used(), self.storage.spec())
+                }
+            }
+            ITIPFeeAMM::transferLiquidityCall::SELECTOR => {
+                if self.storage.spec().is_vivace() {
+                    mutate::<ITIPFeeAMM::transferLiquidityCall>(calldata, msg_sender, |s, call| {
+                        self.transfer_liquidity(s, call)
+                    })
+                } else {
+                    unknown_selector(selector, self.storage.gas_used(), self.storage.spec())
+                }
+            }
+            ITIPFeeAMM::approveLiquidityCall::SELECTOR => {
+                if self.storage.spec().is_vivace() {
+                    mutate::<ITIPFeeAMM::approveLiquidityCall>(calldata, msg_sender, |s, call| {
+                        self.approve_liquidity(s, call)
+                    })
+                } else {
+                    unknown_selector(selector, self.storage.gas_used(), self.storage.spec())
+                }
+            }
+            ITIPFeeAMM::transferLiquidityFromCall::SELECTOR => {
+                if self.storage.spec().is_vivace() {
+                    mutate::<ITIPFeeAMM::transferLiquidityFromCall>(
+                        calldata,
+                        msg_sender,
+                        |s, call| self.transfer_liquidity_from(s, call),
+                    )
+                } else {
+                    unknown_selector(selector, self.storage.gas_used(), self.storage.spec())
+                }
+            }
             ITIPFeeAMM::rebalanceSwapCall::SELECTOR => {
                 mutate::<ITIPFeeAMM::rebalanceSwapCall>(calldata, msg_sender, |s, call| {
                     self.rebalance_swap(
@@ -453,6 +545,54 @@ mod tests {
                 ITIPFeeAMMCalls::name_by_selector,
             );
 
+            assert_full_coverage([fee_manager_unsupported]);
+
+            // In pre-Vivace, post-Vivace AMM functions should be unsupported
+            let mut amm_unsupported: Vec<&str> =
+                amm_unsupported.iter().map(|(_, name)| *name).collect();
+            amm_unsupported.sort();
+            assert_eq!(
+                amm_unsupported,
+                vec![
+                    "approveLiquidity",
+                    "burnBounded",
+                    "burnSingleSided",
+                    "getPoolFees",
+                    "liquidityAllowance",
+                    "mintWithValidatorTokenBounded",
+                    "setPoolFees",
+                    "transferLiquidity",
+                    "transferLiquidityFrom",
+                ]
+            );
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_tip_fee_manager_selector_coverage_post_vivace() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1).with_spec(TempoHardfork::Vivace);
+        StorageCtx::enter(&mut storage, || {
+            let mut fee_manager = TipFeeManager::new();
+
+            let fee_manager_unsupported = check_selector_coverage(
+                &mut fee_manager,
+                IFeeManagerCalls::SELECTORS,
+                "IFeeManager",
+                IFeeManagerCalls::name_by_selector,
+            )
+            .into_iter()
+            .filter(|(selector, _)| *selector != IFeeManager::executeBlockCall::SELECTOR)
+            .collect();
+
+            let amm_unsupported = check_selector_coverage(
+                &mut fee_manager,
+                ITIPFeeAMMCalls::SELECTORS,
+                "ITIPFeeAMM",
+                ITIPFeeAMMCalls::name_by_selector,
+            );
+
             assert_full_coverage([fee_manager_unsupported, amm_unsupported]);
 
             Ok(())
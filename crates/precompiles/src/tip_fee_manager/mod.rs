@@ -11,7 +11,7 @@ use crate::{
     DEFAULT_FEE_TOKEN_POST_ALLEGRETTO, DEFAULT_FEE_TOKEN_PRE_ALLEGRETTO, PATH_USD_ADDRESS,
     error::{Result, TempoPrecompileError},
     storage::{Handler, Mapping, StorableType, StorageKey},
-    tip_fee_manager::amm::{Pool, compute_amount_out},
+    tip_fee_manager::amm::{Pool, PoolFees, compute_amount_out},
     tip20::{
         ITIP20, TIP20Token, address_to_token_id_unchecked, is_tip20_prefix, token_id_to_address,
         validate_usd_currency,
@@ -53,6 +53,8 @@ pub struct TipFeeManager {
     pool_in_fees_array: Mapping<TokenPair, bool>,
     validators_with_fees: Vec<Address>,
     validator_in_fees_array: Mapping<Address, bool>,
+    pool_fees: Mapping<B256, PoolFees>,
+    liquidity_allowances: Mapping<B256, Mapping<Address, Mapping<Address, U256>>>,
 }
 
 impl TipFeeManager {
@@ -262,7 +264,8 @@ impl TipFeeManager {
             let amount = if fee_token == validator_token {
                 actual_spending
             } else {
-                compute_amount_out(actual_spending)?
+                let pool_id = self.pool_id(fee_token, validator_token);
+                compute_amount_out(actual_spending, self.get_pool_fees(pool_id)?.m())?
             };
 
             self.increment_collected_fees(beneficiary, amount)?;
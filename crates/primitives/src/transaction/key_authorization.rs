@@ -1,7 +1,7 @@
 use super::SignatureType;
 use crate::transaction::PrimitiveSignature;
 use alloy_consensus::crypto::RecoveryError;
-use alloy_primitives::{Address, B256, U256, keccak256};
+use alloy_primitives::{Address, B256, FixedBytes, U256, keccak256};
 use alloy_rlp::Encodable;
 use core::mem;
 
@@ -9,18 +9,65 @@ use core::mem;
 ///
 /// Defines a per-token spending limit for an access key provisioned via key_authorization.
 /// This limit is enforced by the AccountKeychain precompile when the key is used.
+///
+/// RLP encoding: `[token, limit, period?]`
+/// - `period` is a trailing field, so limits encoded without it are still valid
 #[derive(Clone, Debug, PartialEq, Eq, Hash, alloy_rlp::RlpEncodable, alloy_rlp::RlpDecodable)]
+#[rlp(trailing)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
-#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "reth-codec", derive(reth_codecs::Compact))]
 #[cfg_attr(test, reth_codecs::add_arbitrary_tests(compact, rlp))]
 pub struct TokenLimit {
     /// TIP20 token address
     pub token: Address,
 
-    /// Maximum spending amount for this token (enforced over the key's lifetime)
+    /// Maximum spending amount for this token, over the key's lifetime or per period
     pub limit: U256,
+
+    /// Length of the spending period in seconds.
+    /// - `None` (omitted) = the limit is enforced over the key's lifetime
+    /// - `Some(seconds)` = the limit resets every `seconds`, starting when the key is authorized
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub period: Option<u64>,
+}
+
+impl TokenLimit {
+    /// Returns whether the limit resets periodically.
+    pub fn is_periodic(&self) -> bool {
+        self.period.is_some_and(|period| period > 0)
+    }
+}
+
+/// Call an access key is allowed to make
+///
+/// Restricts an access key provisioned via key_authorization to calling `selector` on `target`.
+/// Calls with less than 4 bytes of input match the zero selector.
+///
+/// RLP encoding: `[target, selector]`
+#[derive(Clone, Debug, PartialEq, Eq, Hash, alloy_rlp::RlpEncodable, alloy_rlp::RlpDecodable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[cfg_attr(test, reth_codecs::add_arbitrary_tests(rlp))]
+pub struct CallScope {
+    /// Contract the key is allowed to call
+    pub target: Address,
+
+    /// Function selector the key is allowed to call on `target`
+    pub selector: FixedBytes<4>,
+}
+
+/// Returns the function selector of a call input, or the zero selector if the input is shorter
+/// than 4 bytes.
+pub fn call_selector(input: &[u8]) -> FixedBytes<4> {
+    input
+        .get(..4)
+        .map(FixedBytes::from_slice)
+        .unwrap_or_default()
 }
 
 /// Key authorization for provisioning access keys
@@ -28,10 +75,11 @@ pub struct TokenLimit {
 /// Used in TempoTransaction to add a new key to the AccountKeychain precompile.
 /// The transaction must be signed by the root key to authorize adding this access key.
 ///
-/// RLP encoding: `[key_type, key_id, expiry?, limits?]`
+/// RLP encoding: `[key_type, key_id, expiry?, limits?, allowed_calls?]`
 /// - Non-optional fields come first, followed by optional (trailing) fields
 /// - `expiry`: `None` (omitted or 0x80) = key never expires, `Some(timestamp)` = expires at timestamp
 /// - `limits`: `None` (omitted or 0x80) = unlimited spending, `Some([])` = no spending, `Some([...])` = specific limits
+/// - `allowed_calls`: `None` (omitted) = any call, `Some([])` = no calls, `Some([...])` = specific calls
 #[derive(Clone, Debug, PartialEq, Eq, Hash, alloy_rlp::RlpEncodable, alloy_rlp::RlpDecodable)]
 #[rlp(trailing)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -57,6 +105,16 @@ pub struct KeyAuthorization {
     /// - `Some([])` = no spending allowed (enforce_limits=true but no tokens allowed)
     /// - `Some([TokenLimit{...}])` = specific limits enforced
     pub limits: Option<Vec<TokenLimit>>,
+
+    /// Calls this key is allowed to make.
+    /// - `None` (omitted) = the key can call any contract
+    /// - `Some([])` = the key cannot make any call
+    /// - `Some([CallScope{...}])` = the key can only make the listed calls
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub allowed_calls: Option<Vec<CallScope>>,
 }
 
 impl KeyAuthorization {
@@ -72,6 +130,11 @@ impl KeyAuthorization {
         self.limits.is_none()
     }
 
+    /// Returns whether this key can call any contract (allowed_calls is None)
+    pub fn has_unrestricted_calls(&self) -> bool {
+        self.allowed_calls.is_none()
+    }
+
     /// Returns whether this key never expires (expiry is None)
     pub fn never_expires(&self) -> bool {
         self.expiry.is_none()
@@ -93,8 +156,11 @@ impl KeyAuthorization {
         mem::size_of::<Option<u64>>() + // expiry
         self.limits.as_ref().map_or(0, |limits| {
             limits.iter().map(|_limit| {
-                mem::size_of::<Address>() + mem::size_of::<U256>()
+                mem::size_of::<Address>() + mem::size_of::<U256>() + mem::size_of::<Option<u64>>()
             }).sum::<usize>()
+        }) +
+        self.allowed_calls.as_ref().map_or(0, |calls| {
+            calls.len() * (mem::size_of::<Address>() + mem::size_of::<FixedBytes<4>>())
         })
     }
 }
@@ -156,6 +222,18 @@ impl reth_codecs::Compact for SignedKeyAuthorization {
     }
 }
 
+#[cfg(any(test, feature = "arbitrary"))]
+impl<'a> arbitrary::Arbitrary<'a> for TokenLimit {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            token: u.arbitrary()?,
+            limit: u.arbitrary()?,
+            // Ensure that Some(0) is not generated as it's becoming `None` after RLP roundtrip.
+            period: u.arbitrary::<Option<u64>>()?.filter(|v| *v != 0),
+        })
+    }
+}
+
 #[cfg(any(test, feature = "arbitrary"))]
 impl<'a> arbitrary::Arbitrary<'a> for KeyAuthorization {
     fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
@@ -166,6 +244,7 @@ impl<'a> arbitrary::Arbitrary<'a> for KeyAuthorization {
             // Ensure that Some(0) is not generated as it's becoming `None` after RLP roundtrip.
             expiry: u.arbitrary::<Option<u64>>()?.filter(|v| *v != 0),
             limits: u.arbitrary()?,
+            allowed_calls: u.arbitrary()?,
         })
     }
 }
@@ -9,13 +9,15 @@ pub mod tt_signed;
 pub use tt_authorization::{MAGIC, RecoveredTempoAuthorization, TempoSignedAuthorization};
 // Re-export Authorization from alloy for convenience
 pub use tt_signature::{
-    KeychainSignature, PrimitiveSignature, TempoSignature, derive_p256_address,
+    KeychainSignature, MultisigSignature, PrimitiveSignature, TempoSignature, derive_p256_address,
 };
 
 pub use alloy_eips::eip7702::Authorization;
 pub use envelope::{TempoTxEnvelope, TempoTxType, TempoTypedTransaction};
 pub use fee_token::{FEE_TOKEN_TX_TYPE_ID, TxFeeToken};
-pub use key_authorization::{KeyAuthorization, SignedKeyAuthorization, TokenLimit};
+pub use key_authorization::{
+    CallScope, KeyAuthorization, SignedKeyAuthorization, TokenLimit, call_selector,
+};
 pub use tempo_transaction::{
     Call, MAX_WEBAUTHN_SIGNATURE_LENGTH, P256_SIGNATURE_LENGTH, SECP256K1_SIGNATURE_LENGTH,
     SignatureType, TEMPO_TX_TYPE_ID, TempoTransaction,
@@ -1734,8 +1734,10 @@ mod tests {
             limits: Some(vec![crate::transaction::TokenLimit {
                 token: address!("0000000000000000000000000000000000000003"),
                 limit: U256::from(10000),
+                period: None,
             }]),
             key_id: address!("0000000000000000000000000000000000000004"),
+            allowed_calls: None,
         }
         .into_signed(PrimitiveSignature::Secp256k1(Signature::test_signature()));
 
@@ -1776,6 +1778,47 @@ mod tests {
         assert_eq!(decoded_old_format.key_authorization, None);
     }
 
+    #[test]
+    fn test_backwards_compatibility_token_limit_period() {
+        use crate::transaction::TokenLimit;
+
+        /// Token limit as encoded before periodic limits were introduced
+        #[derive(alloy_rlp::RlpEncodable)]
+        struct LegacyTokenLimit {
+            token: Address,
+            limit: U256,
+        }
+
+        let token = address!("0000000000000000000000000000000000000003");
+        let limit = U256::from(10000);
+
+        // Lifetime limits are encoded exactly like before
+        let mut legacy = Vec::new();
+        LegacyTokenLimit { token, limit }.encode(&mut legacy);
+        let lifetime = TokenLimit {
+            token,
+            limit,
+            period: None,
+        };
+        assert_eq!(alloy_rlp::encode(&lifetime), legacy);
+        assert_eq!(
+            TokenLimit::decode(&mut legacy.as_slice()).unwrap(),
+            lifetime
+        );
+        assert!(!lifetime.is_periodic());
+
+        let periodic = TokenLimit {
+            period: Some(86400),
+            ..lifetime
+        };
+        let encoded = alloy_rlp::encode(&periodic);
+        assert_eq!(
+            TokenLimit::decode(&mut encoded.as_slice()).unwrap(),
+            periodic
+        );
+        assert!(periodic.is_periodic());
+    }
+
     #[test]
     fn test_aa_signed_rlp_direct() {
         // Simple test for AASigned RLP encoding/decoding without key_authorization
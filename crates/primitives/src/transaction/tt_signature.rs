@@ -41,6 +41,10 @@ pub fn normalize_p256_s(s_bytes: &[u8]) -> B256 {
 pub const SIGNATURE_TYPE_P256: u8 = 0x01;
 pub const SIGNATURE_TYPE_WEBAUTHN: u8 = 0x02;
 pub const SIGNATURE_TYPE_KEYCHAIN: u8 = 0x03;
+pub const SIGNATURE_TYPE_MULTISIG: u8 = 0x04;
+
+/// Maximum number of signatures in a multisig signature
+pub const MAX_MULTISIG_SIGNATURES: usize = 16;
 
 // Minimum authenticatorData is 37 bytes (32 rpIdHash + 1 flags + 4 signCount)
 const MIN_AUTH_DATA_LEN: usize = 37;
@@ -466,6 +470,154 @@ impl<'a> arbitrary::Arbitrary<'a> for KeychainSignature {
     }
 }
 
+/// Multisig signature of an account by a threshold of its signers
+///
+/// Format: 0x04 || account (20 bytes) || rlp([inner_signature, ...])
+///
+/// The account is the one this transaction is being executed for.
+/// Each inner signature must be from a different signer of the account's signer set, and signs
+/// `keccak256(sig_hash || account)` so that it cannot be reused for another account sharing signers.
+/// The handler validates the signers against the account's signer set and threshold in the KeyChain precompile.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(test, reth_codecs::add_arbitrary_tests(compact))]
+pub struct MultisigSignature {
+    /// Account that this transaction is being executed for
+    pub account: Address,
+    /// Signatures from the signers of the account (Secp256k1, P256, or WebAuthn)
+    pub signatures: Vec<PrimitiveSignature>,
+    /// Cached signer addresses recovered from the signatures.
+    /// This is an implementation detail - use `signers()` to access.
+    /// Note: Excluded from PartialEq, Eq, Hash, and Compact as it's a cache.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "serialize_once_lock",
+            rename = "signers",
+            skip_deserializing,
+        )
+    )]
+    cached_signers: OnceLock<Vec<Address>>,
+}
+
+impl MultisigSignature {
+    /// Create a new MultisigSignature
+    pub fn new(account: Address, signatures: Vec<PrimitiveSignature>) -> Self {
+        Self {
+            account,
+            signatures,
+            cached_signers: OnceLock::new(),
+        }
+    }
+
+    /// Returns the hash signed by the inner signatures, committing to both the transaction and
+    /// the account.
+    pub fn signing_hash(sig_hash: &B256, account: Address) -> B256 {
+        keccak256([sig_hash.as_slice(), account.as_slice()].concat())
+    }
+
+    /// Get the signers of the multisig signature.
+    ///
+    /// The signers are recovered from the inner signatures over [`Self::signing_hash`] on first
+    /// access and cached for subsequent calls. Fails if any signature is invalid or if two
+    /// signatures are from the same signer.
+    pub fn signers(
+        &self,
+        sig_hash: &B256,
+    ) -> Result<&[Address], alloy_consensus::crypto::RecoveryError> {
+        if let Some(cached) = self.cached_signers.get() {
+            return Ok(cached.as_slice());
+        }
+
+        let signing_hash = Self::signing_hash(sig_hash, self.account);
+        let mut signers = Vec::with_capacity(self.signatures.len());
+        for signature in &self.signatures {
+            let signer = signature.recover_signer(&signing_hash)?;
+            if signers.contains(&signer) {
+                return Err(alloy_consensus::crypto::RecoveryError::new());
+            }
+            signers.push(signer);
+        }
+
+        Ok(self.cached_signers.get_or_init(|| signers).as_slice())
+    }
+
+    /// Decode the inner signatures from their RLP list encoding
+    fn decode_signatures(mut data: &[u8]) -> Result<Vec<PrimitiveSignature>, &'static str> {
+        let signatures = <Vec<PrimitiveSignature> as alloy_rlp::Decodable>::decode(&mut data)
+            .map_err(|_| "Invalid Multisig signature: failed to decode inner signatures")?;
+
+        if !data.is_empty() {
+            return Err("Invalid Multisig signature: trailing bytes after inner signatures");
+        }
+
+        if signatures.is_empty() || signatures.len() > MAX_MULTISIG_SIGNATURES {
+            return Err("Invalid Multisig signature: invalid number of inner signatures");
+        }
+
+        Ok(signatures)
+    }
+}
+
+// Manual implementations of PartialEq, Eq, and Hash that exclude cached_signers
+// since it's just a cache and doesn't affect the logical equality of signatures
+impl PartialEq for MultisigSignature {
+    fn eq(&self, other: &Self) -> bool {
+        self.account == other.account && self.signatures == other.signatures
+    }
+}
+
+impl Eq for MultisigSignature {}
+
+impl core::hash::Hash for MultisigSignature {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.account.hash(state);
+        self.signatures.hash(state);
+    }
+}
+
+// Manual Compact implementation that excludes cached_signers (cache field)
+#[cfg(feature = "reth-codec")]
+impl reth_codecs::Compact for MultisigSignature {
+    fn to_compact<B>(&self, buf: &mut B) -> usize
+    where
+        B: alloy_rlp::BufMut + AsMut<[u8]>,
+    {
+        // Only encode account and signatures, skip cached_signers
+        let mut written = 0;
+        written += self.account.to_compact(buf);
+        written += Bytes::from(alloy_rlp::encode(&self.signatures)).to_compact(buf);
+        written
+    }
+
+    fn from_compact(buf: &[u8], len: usize) -> (Self, &[u8]) {
+        // Decode account and signatures, initialize cached_signers as empty
+        let (account, rest) = Address::from_compact(buf, len);
+        let remaining_len = len - (buf.len() - rest.len());
+        let (signatures, rest) = Bytes::from_compact(rest, remaining_len);
+        let signatures = Self::decode_signatures(&signatures)
+            .expect("Failed to decode MultisigSignature from compact encoding");
+
+        (Self::new(account, signatures), rest)
+    }
+}
+
+// Manual Arbitrary implementation that excludes cached_signers (cache field)
+#[cfg(any(test, feature = "arbitrary"))]
+impl<'a> arbitrary::Arbitrary<'a> for MultisigSignature {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let num_signatures = u.int_in_range(1..=MAX_MULTISIG_SIGNATURES)?;
+        Ok(Self {
+            account: u.arbitrary()?,
+            signatures: (0..num_signatures)
+                .map(|_| u.arbitrary())
+                .collect::<arbitrary::Result<_>>()?,
+            cached_signers: OnceLock::new(), // Always start with empty cache
+        })
+    }
+}
+
 /// AA transaction signature supporting multiple signature schemes
 ///
 /// Note: Uses custom Compact implementation that delegates to `to_bytes()` / `from_bytes()`.
@@ -483,6 +635,10 @@ pub enum TempoSignature {
     /// IMP: The inner signature MUST NOT be another Keychain (validated at runtime)
     /// Note: Recursion is prevented by KeychainSignature's custom Arbitrary impl
     Keychain(KeychainSignature),
+
+    /// Multisig signature - signatures from a threshold of an account's signers
+    /// Format: account (20 bytes) + rlp list of inner signatures
+    Multisig(MultisigSignature),
 }
 
 impl TempoSignature {
@@ -523,6 +679,24 @@ impl TempoSignature {
             }));
         }
 
+        // Check if this is a Multisig signature (type identifier 0x04)
+        if data.len() > 1
+            && data.len() != SECP256K1_SIGNATURE_LENGTH
+            && data[0] == SIGNATURE_TYPE_MULTISIG
+        {
+            let sig_data = &data[1..];
+
+            // Multisig format: account (20 bytes) || rlp([inner_signature, ...])
+            if sig_data.len() < 20 {
+                return Err("Invalid Multisig signature: too short for account");
+            }
+
+            let account = Address::from_slice(&sig_data[0..20]);
+            let signatures = MultisigSignature::decode_signatures(&sig_data[20..])?;
+
+            return Ok(Self::Multisig(MultisigSignature::new(account, signatures)));
+        }
+
         // For all non-Keychain signatures, delegate to PrimitiveSignature
         let primitive = PrimitiveSignature::from_bytes(data)?;
         Ok(Self::Primitive(primitive))
@@ -545,6 +719,15 @@ impl TempoSignature {
                 bytes.extend_from_slice(&inner_bytes);
                 Bytes::from(bytes)
             }
+            Self::Multisig(multisig_sig) => {
+                // Format: 0x04 | account (20 bytes) | rlp([inner_signature, ...])
+                let inner_bytes = alloy_rlp::encode(&multisig_sig.signatures);
+                let mut bytes = Vec::with_capacity(1 + 20 + inner_bytes.len());
+                bytes.push(SIGNATURE_TYPE_MULTISIG);
+                bytes.extend_from_slice(multisig_sig.account.as_slice());
+                bytes.extend_from_slice(&inner_bytes);
+                Bytes::from(bytes)
+            }
         }
     }
 
@@ -557,14 +740,23 @@ impl TempoSignature {
         match self {
             Self::Primitive(primitive_sig) => primitive_sig.encoded_length(),
             Self::Keychain(keychain_sig) => 1 + 20 + keychain_sig.signature.encoded_length(),
+            Self::Multisig(multisig_sig) => {
+                1 + 20 + alloy_rlp::list_length(&multisig_sig.signatures)
+            }
         }
     }
 
     /// Get signature type
+    ///
+    /// For Multisig signatures, this is the type of the first inner signature.
     pub fn signature_type(&self) -> SignatureType {
         match self {
             Self::Primitive(primitive_sig) => primitive_sig.signature_type(),
             Self::Keychain(keychain_sig) => keychain_sig.signature.signature_type(),
+            Self::Multisig(multisig_sig) => multisig_sig
+                .signatures
+                .first()
+                .map_or(SignatureType::Secp256k1, |sig| sig.signature_type()),
         }
     }
 
@@ -573,6 +765,14 @@ impl TempoSignature {
         match self {
             Self::Primitive(primitive_sig) => primitive_sig.size(),
             Self::Keychain(keychain_sig) => 1 + 20 + keychain_sig.signature.size(),
+            Self::Multisig(multisig_sig) => {
+                1 + 20
+                    + multisig_sig
+                        .signatures
+                        .iter()
+                        .map(|sig| sig.size())
+                        .sum::<usize>()
+            }
         }
     }
 
@@ -583,9 +783,11 @@ impl TempoSignature {
     /// - P256: Verifies P256 signature then derives address from public key
     /// - WebAuthn: Parses WebAuthn data, verifies P256 signature, derives address
     /// - Keychain: Validates inner signature and returns user_address
+    /// - Multisig: Validates inner signatures and returns account
     ///
     /// For Keychain signatures, this performs full validation of the inner signature.
     /// The access key address is cached in the KeychainSignature for later use.
+    /// For Multisig signatures, the signers are cached in the MultisigSignature.
     pub fn recover_signer(
         &self,
         sig_hash: &B256,
@@ -599,6 +801,13 @@ impl TempoSignature {
                 // Return the user_address - the root account this transaction is for
                 Ok(keychain_sig.user_address)
             }
+            Self::Multisig(multisig_sig) => {
+                // Ensure validity of the inner signatures and cache the signers
+                multisig_sig.signers(sig_hash)?;
+
+                // Return the account this transaction is for
+                Ok(multisig_sig.account)
+            }
         }
     }
 
@@ -614,6 +823,19 @@ impl TempoSignature {
             _ => None,
         }
     }
+
+    /// Check if this is a Multisig signature
+    pub fn is_multisig(&self) -> bool {
+        matches!(self, Self::Multisig(_))
+    }
+
+    /// Get the Multisig signature if this is a Multisig signature
+    pub fn as_multisig(&self) -> Option<&MultisigSignature> {
+        match self {
+            Self::Multisig(multisig_sig) => Some(multisig_sig),
+            _ => None,
+        }
+    }
 }
 
 impl Default for TempoSignature {
@@ -799,8 +1021,9 @@ fn verify_webauthn_data_internal(
 
 #[cfg(feature = "serde")]
 /// Helper function to serialize a [`OnceLock`] as an [`Option`] if it's initialized.
-fn serialize_once_lock<S>(value: &OnceLock<Address>, serializer: S) -> Result<S::Ok, S::Error>
+fn serialize_once_lock<T, S>(value: &OnceLock<T>, serializer: S) -> Result<S::Ok, S::Error>
 where
+    T: serde::Serialize,
     S: serde::Serializer,
 {
     serde::Serialize::serialize(&value.get(), serializer)
@@ -1222,6 +1445,91 @@ mod tests {
         assert_eq!(sig3, decoded3);
     }
 
+    #[test]
+    fn test_multisig_signature_roundtrip() {
+        let account = Address::repeat_byte(0x11);
+        let signatures = vec![
+            PrimitiveSignature::Secp256k1(Signature::test_signature()),
+            PrimitiveSignature::P256(P256SignatureWithPreHash {
+                r: B256::from([1u8; 32]),
+                s: B256::from([2u8; 32]),
+                pub_key_x: B256::from([3u8; 32]),
+                pub_key_y: B256::from([4u8; 32]),
+                pre_hash: false,
+            }),
+            PrimitiveSignature::WebAuthn(WebAuthnSignature {
+                r: B256::from([5u8; 32]),
+                s: B256::from([6u8; 32]),
+                pub_key_x: B256::from([7u8; 32]),
+                pub_key_y: B256::from([8u8; 32]),
+                webauthn_data: Bytes::from(vec![9u8; 50]),
+            }),
+        ];
+        let sig = TempoSignature::Multisig(MultisigSignature::new(account, signatures));
+
+        let encoded = sig.to_bytes();
+        assert_eq!(encoded[0], SIGNATURE_TYPE_MULTISIG);
+        assert_eq!(encoded.len(), sig.encoded_length());
+
+        let decoded = TempoSignature::from_bytes(&encoded).unwrap();
+        assert_eq!(sig, decoded);
+
+        // Multisig signatures need at least one inner signature
+        let empty = TempoSignature::Multisig(MultisigSignature::new(account, vec![]));
+        assert!(TempoSignature::from_bytes(&empty.to_bytes()).is_err());
+
+        // Trailing bytes are rejected
+        let mut trailing = encoded.to_vec();
+        trailing.push(0);
+        assert!(TempoSignature::from_bytes(&trailing).is_err());
+    }
+
+    #[test]
+    fn test_multisig_signature_signers() {
+        use p256::{
+            ecdsa::{SigningKey, signature::hazmat::PrehashSigner},
+            elliptic_curve::rand_core::OsRng,
+        };
+
+        let sig_hash = B256::repeat_byte(0x22);
+        let account = Address::repeat_byte(0x11);
+        let signing_hash = MultisigSignature::signing_hash(&sig_hash, account);
+        let sign = |signing_key: &SigningKey| {
+            let signature: p256::ecdsa::Signature =
+                signing_key.sign_prehash(signing_hash.as_slice()).unwrap();
+            let sig_bytes = signature.to_bytes();
+            let encoded_point = signing_key.verifying_key().to_encoded_point(false);
+            PrimitiveSignature::P256(P256SignatureWithPreHash {
+                r: B256::from_slice(&sig_bytes[0..32]),
+                s: normalize_p256_s(&sig_bytes[32..64]),
+                pub_key_x: B256::from_slice(encoded_point.x().unwrap()),
+                pub_key_y: B256::from_slice(encoded_point.y().unwrap()),
+                pre_hash: false,
+            })
+        };
+
+        let key1 = SigningKey::random(&mut OsRng);
+        let key2 = SigningKey::random(&mut OsRng);
+        let sig1 = sign(&key1);
+        let sig2 = sign(&key2);
+        let signer1 = sig1.recover_signer(&signing_hash).unwrap();
+        let signer2 = sig2.recover_signer(&signing_hash).unwrap();
+
+        let multisig = MultisigSignature::new(account, vec![sig1.clone(), sig2.clone()]);
+        assert_eq!(multisig.signers(&sig_hash).unwrap(), &[signer1, signer2]);
+
+        let sig = TempoSignature::Multisig(multisig);
+        assert_eq!(sig.recover_signer(&sig_hash).unwrap(), account);
+
+        // The same signer cannot sign twice
+        let duplicate = MultisigSignature::new(account, vec![sig1.clone(), sig1.clone()]);
+        assert!(duplicate.signers(&sig_hash).is_err());
+
+        // The signatures commit to the account, so they do not verify for another account
+        let reused = MultisigSignature::new(Address::repeat_byte(0x33), vec![sig1, sig2]);
+        assert!(reused.signers(&sig_hash).is_err());
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn test_tempo_signature_serde_roundtrip() {
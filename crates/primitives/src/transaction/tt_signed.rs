@@ -384,9 +384,16 @@ mod serde_impl {
         where
             S: Serializer,
         {
-            if let TempoSignature::Keychain(keychain_sig) = &self.signature {
-                // Initialize the `key_id` field for keychain signatures so that it's serialized.
-                let _ = keychain_sig.key_id(&self.signature_hash());
+            match &self.signature {
+                TempoSignature::Keychain(keychain_sig) => {
+                    // Initialize the `key_id` field for keychain signatures so that it's serialized.
+                    let _ = keychain_sig.key_id(&self.signature_hash());
+                }
+                TempoSignature::Multisig(multisig_sig) => {
+                    // Initialize the `signers` field for multisig signatures so that it's serialized.
+                    let _ = multisig_sig.signers(&self.signature_hash());
+                }
+                TempoSignature::Primitive(_) => {}
             }
             AASignedHelper {
                 tx: Cow::Borrowed(&self.tx),
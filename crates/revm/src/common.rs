@@ -238,7 +238,7 @@ pub trait TempoStateAccess<M = ()> {
         })
     }
 
-    /// Checks if the fee payer can transfer a given token (is not blacklisted).
+    /// Checks if the fee payer can transfer a given token (is neither blacklisted nor frozen).
     fn can_fee_payer_transfer(
         &mut self,
         fee_token: Address,
@@ -249,13 +249,19 @@ pub trait TempoStateAccess<M = ()> {
         Self: Sized,
     {
         self.with_read_only_storage_ctx(spec, || {
+            let token = TIP20Token::from_address(fee_token)?;
+
+            // Ensure the fee payer is not frozen
+            if spec.is_vivace() && token.is_frozen(ITIP20::isFrozenCall { account: fee_payer })? {
+                return Ok(false);
+            }
+
             // Ensure the fee payer is not blacklisted
-            let transfer_policy_id = TIP20Token::from_address(fee_token)?
-                .transfer_policy_id
-                .read()?;
-            TIP403Registry::new().is_authorized(ITIP403Registry::isAuthorizedCall {
+            let transfer_policy_id = token.transfer_policy_id.read()?;
+            TIP403Registry::new().is_authorized_as(ITIP403Registry::isAuthorizedAsCall {
                 policyId: transfer_policy_id,
                 user: fee_payer,
+                role: ITIP403Registry::PolicyRole::SENDER,
             })
         })
     }
@@ -113,6 +113,18 @@ pub enum TempoInvalidTransaction {
         reason: String,
     },
 
+    /// Call is outside the call scope of the access key.
+    ///
+    /// Access keys with a call scope can only call the (target, selector) pairs they were
+    /// authorized for, and cannot create contracts.
+    #[error("call {index} is not allowed for access key {key_id}")]
+    AccessKeyCallNotAllowed {
+        /// The access key used to sign the transaction.
+        key_id: Address,
+        /// Index of the call in the transaction.
+        index: usize,
+    },
+
     /// Keychain operations are only supported after Allegretto.
     #[error("keychain operations are only supported after Allegretto")]
     KeychainOpBeforeAllegretto,
@@ -130,6 +142,20 @@ pub enum TempoInvalidTransaction {
     #[error("keychain operations are not supported in subblock transactions")]
     KeychainOpInSubblockTransaction,
 
+    /// Multisig signatures are only supported after Vivace.
+    #[error("multisig signatures are only supported after Vivace")]
+    MultisigBeforeVivace,
+
+    /// Multisig signature validation failed.
+    ///
+    /// This error occurs when the signers of a multisig signature are not in the signer set of
+    /// the account, or do not meet its threshold.
+    #[error("multisig validation failed: {reason}")]
+    MultisigValidationFailed {
+        /// Specific reason for failure.
+        reason: String,
+    },
+
     /// Fee payment error.
     #[error(transparent)]
     CollectFeePreTx(#[from] FeePaymentError),
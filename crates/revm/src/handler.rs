@@ -34,7 +34,10 @@ use tempo_contracts::{
     precompiles::{IAccountKeychain::SignatureType as PrecompileSignatureType, TIPFeeAMMError},
 };
 use tempo_precompiles::{
-    account_keychain::{AccountKeychain, TokenLimit, authorizeKeyCall},
+    account_keychain::{
+        AccountKeychain, CallScope, PeriodicTokenLimit, authorizeKeyWithPeriodicLimitsCall,
+        setAllowedCallsCall,
+    },
     error::TempoPrecompileError,
     nonce::{INonce::getNonceCall, NonceManager},
     storage::StorageCtx,
@@ -62,12 +65,21 @@ const ECRECOVER_GAS: u64 = 3_000;
 /// Additional gas for Keychain signatures (key validation overhead: COLD_SLOAD_COST + 900 processing)
 const KEYCHAIN_VALIDATION_GAS: u64 = COLD_SLOAD_COST + 900;
 
+/// Additional gas for Multisig signatures (threshold and signer set length: 2 * COLD_SLOAD_COST + 900 processing)
+const MULTISIG_VALIDATION_GAS: u64 = 2 * COLD_SLOAD_COST + 900;
+
 /// Base gas for KeyAuthorization (22k storage + 5k buffer), signature gas added at runtime
 const KEY_AUTH_BASE_GAS: u64 = 27_000;
 
 /// Gas per spending limit in KeyAuthorization
 const KEY_AUTH_PER_LIMIT_GAS: u64 = 22_000;
 
+/// Additional gas per periodic spending limit in KeyAuthorization (limit and period slots)
+const KEY_AUTH_PER_PERIOD_GAS: u64 = 2 * SSTORE_SET;
+
+/// Gas per allowed call in KeyAuthorization
+const KEY_AUTH_PER_CALL_SCOPE_GAS: u64 = SSTORE_SET;
+
 /// Gas cost for using an existing 2D nonce key (cold SLOAD + warm SSTORE reset)
 const EXISTING_NONCE_KEY_GAS: u64 = COLD_SLOAD_COST + WARM_SSTORE_RESET;
 
@@ -100,6 +112,7 @@ fn primitive_signature_verification_gas(signature: &PrimitiveSignature) -> u64 {
 ///
 /// For Keychain signatures, adds key validation overhead to the inner signature cost
 /// (only post-AllegroModerato hardfork).
+/// For Multisig signatures, every inner signature is verified and checked against the signer set.
 /// Returns the additional gas required beyond the base transaction cost.
 #[inline]
 fn tempo_signature_verification_gas(
@@ -118,6 +131,18 @@ fn tempo_signature_verification_gas(
                 base_gas
             }
         }
+        TempoSignature::Multisig(multisig_sig) => {
+            // Multisig = inner signatures + signer set validation (SLOAD per signer + threshold)
+            // The first ecrecover is already included in base 21k
+            let signatures_gas: u64 = multisig_sig
+                .signatures
+                .iter()
+                .map(|sig| {
+                    ECRECOVER_GAS + primitive_signature_verification_gas(sig) + COLD_SLOAD_COST
+                })
+                .sum();
+            signatures_gas.saturating_sub(ECRECOVER_GAS) + MULTISIG_VALIDATION_GAS
+        }
     }
 }
 
@@ -125,6 +150,7 @@ fn tempo_signature_verification_gas(
 ///
 /// This is charged before execution as part of transaction validation.
 /// Gas = BASE (27k) + signature verification + (22k per spending limit)
+///       + (40k per periodic spending limit) + (20k per allowed call)
 #[inline]
 fn calculate_key_authorization_gas(
     key_auth: &tempo_primitives::transaction::SignedKeyAuthorization,
@@ -139,11 +165,30 @@ fn calculate_key_authorization_gas(
         .authorization
         .limits
         .as_ref()
-        .map(|limits| limits.len() as u64 * KEY_AUTH_PER_LIMIT_GAS)
+        .map(|limits| {
+            limits
+                .iter()
+                .map(|limit| {
+                    if limit.is_periodic() {
+                        KEY_AUTH_PER_LIMIT_GAS + KEY_AUTH_PER_PERIOD_GAS
+                    } else {
+                        KEY_AUTH_PER_LIMIT_GAS
+                    }
+                })
+                .sum()
+        })
+        .unwrap_or(0);
+
+    // Per-call storage gas
+    let calls_gas = key_auth
+        .authorization
+        .allowed_calls
+        .as_ref()
+        .map(|calls| calls.len() as u64 * KEY_AUTH_PER_CALL_SCOPE_GAS)
         .unwrap_or(0);
 
-    // Total: base (27k) + sig verification + limits
-    KEY_AUTH_BASE_GAS + sig_gas + limits_gas
+    // Total: base (27k) + sig verification + limits + call scopes
+    KEY_AUTH_BASE_GAS + sig_gas + limits_gas + calls_gas
 }
 
 /// Calculates the gas cost for 2D nonce usage.
@@ -834,22 +879,37 @@ where
                 // Some([]) means no spending allowed (enforce_limits=true)
                 // Some([...]) means specific limits (enforce_limits=true)
                 let enforce_limits = key_auth.limits.is_some();
-                let precompile_limits: Vec<TokenLimit> = key_auth
-                    .limits
-                    .as_ref()
-                    .map(|limits| {
-                        limits
-                            .iter()
-                            .map(|limit| TokenLimit {
-                                token: limit.token,
-                                amount: limit.limit,
-                            })
-                            .collect()
+                let limits = key_auth.limits.as_deref().unwrap_or_default();
+
+                // Periodic limits are only supported after the Vivace hardfork
+                if !cfg.spec.is_vivace() && limits.iter().any(|limit| limit.is_periodic()) {
+                    return Err(EVMError::Transaction(
+                        TempoInvalidTransaction::AccessKeyAuthorizationFailed {
+                            reason: "Periodic spending limits are not supported yet".to_string(),
+                        },
+                    ));
+                }
+
+                // Call scopes are only supported after the Vivace hardfork
+                if !cfg.spec.is_vivace() && key_auth.allowed_calls.is_some() {
+                    return Err(EVMError::Transaction(
+                        TempoInvalidTransaction::AccessKeyAuthorizationFailed {
+                            reason: "Call scopes are not supported yet".to_string(),
+                        },
+                    ));
+                }
+
+                let precompile_limits: Vec<PeriodicTokenLimit> = limits
+                    .iter()
+                    .map(|limit| PeriodicTokenLimit {
+                        token: limit.token,
+                        amount: limit.limit,
+                        period: limit.period.unwrap_or_default(),
                     })
-                    .unwrap_or_default();
+                    .collect();
 
                 // Create the authorize key call
-                let authorize_call = authorizeKeyCall {
+                let authorize_call = authorizeKeyWithPeriodicLimitsCall {
                     keyId: access_key_addr,
                     signatureType: signature_type,
                     expiry,
@@ -859,7 +919,34 @@ where
 
                 // Call precompile to authorize the key (same phase as nonce increment)
                 keychain
-                    .authorize_key(*root_account, authorize_call)
+                    .authorize_key_with_periodic_limits(*root_account, authorize_call)
+                    .map_err(|err| match err {
+                        TempoPrecompileError::Fatal(err) => EVMError::Custom(err),
+                        err => TempoInvalidTransaction::AccessKeyAuthorizationFailed {
+                            reason: err.to_string(),
+                        }
+                        .into(),
+                    })?;
+
+                // Handle call scopes: None means the key can call any contract
+                // Some([]) means no calls allowed, Some([...]) means specific calls
+                let Some(allowed_calls) = &key_auth.allowed_calls else {
+                    return Ok(());
+                };
+
+                let set_allowed_calls = setAllowedCallsCall {
+                    keyId: access_key_addr,
+                    calls: allowed_calls
+                        .iter()
+                        .map(|scope| CallScope {
+                            target: scope.target,
+                            selector: scope.selector,
+                        })
+                        .collect(),
+                };
+
+                keychain
+                    .set_allowed_calls(*root_account, set_allowed_calls)
                     .map_err(|err| match err {
                         TempoPrecompileError::Fatal(err) => EVMError::Custom(err),
                         err => TempoInvalidTransaction::AccessKeyAuthorizationFailed {
@@ -937,6 +1024,28 @@ where
                         })?;
                 }
 
+                // Validate that every call is within the call scope of the access key
+                if cfg.spec.is_vivace() {
+                    let user_address = &keychain_sig.user_address;
+                    for (index, call) in tempo_tx_env.aa_calls.iter().enumerate() {
+                        keychain
+                            .validate_call_scope(
+                                *user_address,
+                                access_key_addr,
+                                call.to,
+                                &call.input,
+                            )
+                            .map_err(|err| match err {
+                                TempoPrecompileError::Fatal(err) => EVMError::Custom(err),
+                                _ => TempoInvalidTransaction::AccessKeyCallNotAllowed {
+                                    key_id: access_key_addr,
+                                    index,
+                                }
+                                .into(),
+                            })?;
+                    }
+                }
+
                 // Set the transaction key in the keychain precompile
                 // This marks that the current transaction is using an access key
                 // The TIP20 precompile will read this during execution to enforce spending limits
@@ -946,6 +1055,44 @@ where
             })?;
         }
 
+        // For Multisig signatures, validate the signers against the signer set of the account
+        if let Some(tempo_tx_env) = tx.tempo_tx_env.as_ref()
+            && let Some(multisig_sig) = tempo_tx_env.signature.as_multisig()
+        {
+            // Sanity check: account should match tx.caller
+            if multisig_sig.account != tx.caller {
+                return Err(EVMError::Transaction(
+                    TempoInvalidTransaction::MultisigValidationFailed {
+                        reason: format!(
+                            "Multisig account {} does not match transaction caller {}",
+                            multisig_sig.account, tx.caller
+                        ),
+                    },
+                ));
+            }
+
+            // Get the signers (recovered during Tx->TxEnv conversion and cached)
+            let signers = multisig_sig
+                .signers(&tempo_tx_env.signature_hash)
+                .map_err(|_| {
+                    EVMError::Transaction(TempoInvalidTransaction::MultisigValidationFailed {
+                        reason: "Failed to recover signers from Multisig signature".to_string(),
+                    })
+                })?;
+
+            StorageCtx::enter_precompile(journal, block, cfg, |keychain: AccountKeychain| {
+                keychain
+                    .validate_multisig(multisig_sig.account, signers)
+                    .map_err(|err| match err {
+                        TempoPrecompileError::Fatal(err) => EVMError::Custom(err),
+                        err => TempoInvalidTransaction::MultisigValidationFailed {
+                            reason: err.to_string(),
+                        }
+                        .into(),
+                    })
+            })?;
+        }
+
         if gas_balance_spending.is_zero() {
             return Ok(());
         }
@@ -1091,14 +1238,20 @@ where
         let tx = evm.ctx_ref().tx();
 
         if let Some(aa_env) = tx.tempo_tx_env.as_ref() {
-            let has_keychain_fields =
-                aa_env.key_authorization.is_some() || aa_env.signature.is_keychain();
+            let has_keychain_fields = aa_env.key_authorization.is_some()
+                || aa_env.signature.is_keychain()
+                || aa_env.signature.is_multisig();
 
             // Validate that keychain operations are only supported after Allegretto
             if has_keychain_fields && !cfg.spec.is_allegretto() {
                 return Err(TempoInvalidTransaction::KeychainOpBeforeAllegretto.into());
             }
 
+            // Validate that multisig signatures are only supported after Vivace
+            if aa_env.signature.is_multisig() && !cfg.spec.is_vivace() {
+                return Err(TempoInvalidTransaction::MultisigBeforeVivace.into());
+            }
+
             if aa_env.subblock_transaction {
                 if !cfg.spec.is_allegretto() {
                     if tx.max_fee_per_gas() > 0 {
@@ -1889,10 +2042,12 @@ mod tests {
             TokenLimit {
                 token: Address::random(),
                 limit: U256::from(100),
+                period: None,
             },
             TokenLimit {
                 token: Address::random(),
                 limit: U256::from(200),
+                period: None,
             },
         ];
 
@@ -1903,6 +2058,7 @@ mod tests {
             key_id,
             expiry: Some(expiry),
             limits: Some(limits.clone()),
+            allowed_calls: None,
         }
         .signature_hash();
 
@@ -1913,6 +2069,7 @@ mod tests {
             key_id,
             expiry: Some(expiry),
             limits: Some(limits.clone()),
+            allowed_calls: None,
         }
         .signature_hash();
 
@@ -1925,6 +2082,7 @@ mod tests {
             key_id,
             expiry: Some(expiry),
             limits: Some(limits),
+            allowed_calls: None,
         }
         .signature_hash();
         assert_ne!(
@@ -2009,10 +2167,225 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_access_key_call_scope() {
+        use alloy_primitives::{FixedBytes, TxKind};
+        use tempo_primitives::transaction::{Call, KeychainSignature};
+
+        let caller = Address::random();
+        let access_key = Address::random();
+        let target = Address::random();
+        let selector = FixedBytes::new([0xaa, 0xbb, 0xcc, 0xdd]);
+
+        // Validates a transaction of an access key that may only call `selector` on `target`
+        let validate = |calls: Vec<Call>| {
+            let mut cfg = CfgEnv::<TempoHardfork>::default();
+            cfg.spec = TempoHardfork::Vivace;
+            let ctx = Context::mainnet()
+                .with_db(CacheDB::new(EmptyDB::default()))
+                .with_block(TempoBlockEnv::default())
+                .with_cfg(cfg)
+                .with_tx(TempoTxEnv::default());
+            let mut evm = TempoEvm::new(ctx, ());
+
+            let (block, _, cfg, journal, _, _) = evm.ctx.all_mut();
+            StorageCtx::enter_precompile(journal, block, cfg, |mut keychain: AccountKeychain| {
+                keychain.authorize_key_with_periodic_limits(
+                    caller,
+                    authorizeKeyWithPeriodicLimitsCall {
+                        keyId: access_key,
+                        signatureType: PrecompileSignatureType::Secp256k1,
+                        expiry: u64::MAX,
+                        enforceLimits: false,
+                        limits: vec![],
+                    },
+                )?;
+                keychain.set_allowed_calls(
+                    caller,
+                    setAllowedCallsCall {
+                        keyId: access_key,
+                        calls: vec![CallScope { target, selector }],
+                    },
+                )
+            })
+            .unwrap();
+
+            evm.ctx.tx.inner.caller = caller;
+            evm.ctx.tx.tempo_tx_env = Some(Box::new(TempoBatchCallEnv {
+                signature: TempoSignature::Keychain(KeychainSignature::new(
+                    caller,
+                    PrimitiveSignature::Secp256k1(alloy_primitives::Signature::new(
+                        U256::ONE,
+                        U256::ONE,
+                        false,
+                    )),
+                )),
+                aa_calls: calls,
+                // Skips recovering the access key from the signature
+                override_key_id: Some(access_key),
+                ..Default::default()
+            }));
+
+            TempoEvmHandler::<_, ()>::new().validate_against_state_and_deduct_caller(&mut evm)
+        };
+
+        let allowed_call = Call {
+            to: TxKind::Call(target),
+            value: U256::ZERO,
+            input: [selector.as_slice(), &[0u8; 32]].concat().into(),
+        };
+        let other_call = Call {
+            to: TxKind::Call(Address::random()),
+            ..allowed_call.clone()
+        };
+        let create = Call {
+            to: TxKind::Create,
+            ..allowed_call.clone()
+        };
+
+        let is_call_not_allowed =
+            |result: Result<(), EVMError<Infallible, TempoInvalidTransaction>>, index: usize| {
+                matches!(
+                    result,
+                    Err(EVMError::Transaction(TempoInvalidTransaction::AccessKeyCallNotAllowed {
+                        key_id,
+                        index: i,
+                    })) if key_id == access_key && i == index
+                )
+            };
+
+        assert!(!matches!(
+            validate(vec![allowed_call.clone()]),
+            Err(EVMError::Transaction(
+                TempoInvalidTransaction::AccessKeyCallNotAllowed { .. }
+            ))
+        ));
+        assert!(is_call_not_allowed(
+            validate(vec![allowed_call.clone(), other_call]),
+            1
+        ));
+        // Restricted keys cannot deploy contracts
+        assert!(is_call_not_allowed(validate(vec![create, allowed_call]), 0));
+    }
+
+    #[test]
+    fn test_multisig_signature_reused_for_other_account() {
+        use alloy_signer::SignerSync;
+        use alloy_signer_local::PrivateKeySigner;
+        use tempo_precompiles::account_keychain::setMultisigCall;
+        use tempo_primitives::transaction::MultisigSignature;
+
+        // Two accounts sharing the same signer set
+        let signers = [PrivateKeySigner::random(), PrivateKeySigner::random()];
+        let signer_set = signers.iter().map(|s| s.address()).collect::<Vec<_>>();
+        let account = Address::random();
+        let other_account = Address::random();
+        let sig_hash = B256::random();
+
+        // The signers sign the transaction for `account` only
+        let signing_hash = MultisigSignature::signing_hash(&sig_hash, account);
+        let signatures = signers
+            .iter()
+            .map(|signer| {
+                PrimitiveSignature::Secp256k1(signer.sign_hash_sync(&signing_hash).unwrap())
+            })
+            .collect::<Vec<_>>();
+
+        let validate = |caller: Address| {
+            let mut cfg = CfgEnv::<TempoHardfork>::default();
+            cfg.spec = TempoHardfork::Vivace;
+            let ctx = Context::mainnet()
+                .with_db(CacheDB::new(EmptyDB::default()))
+                .with_block(TempoBlockEnv::default())
+                .with_cfg(cfg)
+                .with_tx(TempoTxEnv::default());
+            let mut evm = TempoEvm::new(ctx, ());
+
+            let (block, _, cfg, journal, _, _) = evm.ctx.all_mut();
+            StorageCtx::enter_precompile(journal, block, cfg, |mut keychain: AccountKeychain| {
+                for account in [account, other_account] {
+                    keychain.set_multisig(
+                        account,
+                        setMultisigCall {
+                            signers: signer_set.clone(),
+                            threshold: 2,
+                        },
+                    )?;
+                }
+                Ok::<_, TempoPrecompileError>(())
+            })
+            .unwrap();
+
+            evm.ctx.tx.inner.caller = caller;
+            evm.ctx.tx.tempo_tx_env = Some(Box::new(TempoBatchCallEnv {
+                signature: TempoSignature::Multisig(MultisigSignature::new(
+                    caller,
+                    signatures.clone(),
+                )),
+                signature_hash: sig_hash,
+                ..Default::default()
+            }));
+
+            TempoEvmHandler::<_, ()>::new().validate_against_state_and_deduct_caller(&mut evm)
+        };
+
+        assert!(!matches!(
+            validate(account),
+            Err(EVMError::Transaction(
+                TempoInvalidTransaction::MultisigValidationFailed { .. }
+            ))
+        ));
+        // The same signatures do not authorize a transaction of the other account
+        assert!(matches!(
+            validate(other_account),
+            Err(EVMError::Transaction(
+                TempoInvalidTransaction::MultisigValidationFailed { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_multisig_signature_gas() {
+        use tempo_primitives::transaction::{
+            MultisigSignature, tt_signature::P256SignatureWithPreHash,
+        };
+
+        let secp256k1 =
+            PrimitiveSignature::Secp256k1(alloy_primitives::Signature::test_signature());
+        let p256 = PrimitiveSignature::P256(P256SignatureWithPreHash {
+            r: B256::ZERO,
+            s: B256::ZERO,
+            pub_key_x: B256::ZERO,
+            pub_key_y: B256::ZERO,
+            pre_hash: false,
+        });
+
+        // 2 secp256k1 signatures: 1 additional ecrecover + 2 signer SLOADs + validation
+        let multisig = TempoSignature::Multisig(MultisigSignature::new(
+            Address::random(),
+            vec![secp256k1.clone(), secp256k1.clone()],
+        ));
+        assert_eq!(
+            tempo_signature_verification_gas(&multisig, TempoHardfork::Vivace),
+            ECRECOVER_GAS + 2 * COLD_SLOAD_COST + MULTISIG_VALIDATION_GAS
+        );
+
+        // secp256k1 + P256 signatures: P256 verification is added
+        let multisig = TempoSignature::Multisig(MultisigSignature::new(
+            Address::random(),
+            vec![secp256k1, p256],
+        ));
+        assert_eq!(
+            tempo_signature_verification_gas(&multisig, TempoHardfork::Vivace),
+            ECRECOVER_GAS + P256_VERIFY_GAS + 2 * COLD_SLOAD_COST + MULTISIG_VALIDATION_GAS
+        );
+    }
+
     #[test]
     fn test_key_authorization_gas_with_limits() {
+        use alloy_primitives::FixedBytes;
         use tempo_primitives::transaction::{
-            KeyAuthorization, SignatureType, SignedKeyAuthorization, TokenLimit,
+            CallScope, KeyAuthorization, SignatureType, SignedKeyAuthorization, TokenLimit,
         };
 
         // Helper to create key auth with N limits
@@ -2025,6 +2398,7 @@ mod tests {
                         .map(|_| TokenLimit {
                             token: Address::random(),
                             limit: U256::from(1000),
+                            period: None,
                         })
                         .collect(),
                 )
@@ -2037,6 +2411,7 @@ mod tests {
                     key_id: Address::random(),
                     expiry: None,
                     limits,
+                    allowed_calls: None,
                 },
                 signature: PrimitiveSignature::Secp256k1(
                     alloy_primitives::Signature::test_signature(),
@@ -2075,6 +2450,35 @@ mod tests {
             KEY_AUTH_BASE_GAS + ECRECOVER_GAS + 3 * KEY_AUTH_PER_LIMIT_GAS,
             "3 limits should be 96,000"
         );
+
+        // Test 1 periodic limit: 30,000 + 22,000 + 40,000 = 92,000
+        let mut key_auth = create_key_auth(1);
+        key_auth.authorization.limits.as_mut().unwrap()[0].period = Some(86400);
+        let gas_periodic = calculate_key_authorization_gas(&key_auth);
+        assert_eq!(
+            gas_periodic,
+            KEY_AUTH_BASE_GAS + ECRECOVER_GAS + KEY_AUTH_PER_LIMIT_GAS + KEY_AUTH_PER_PERIOD_GAS,
+            "1 periodic limit should be 92,000"
+        );
+
+        // Test 2 allowed calls: 30,000 + 2 * 20,000 = 70,000
+        let mut key_auth = create_key_auth(0);
+        key_auth.authorization.allowed_calls = Some(vec![
+            CallScope {
+                target: Address::random(),
+                selector: FixedBytes::new([0x01, 0x02, 0x03, 0x04]),
+            },
+            CallScope {
+                target: Address::random(),
+                selector: FixedBytes::ZERO,
+            },
+        ]);
+        let gas_scoped = calculate_key_authorization_gas(&key_auth);
+        assert_eq!(
+            gas_scoped,
+            KEY_AUTH_BASE_GAS + ECRECOVER_GAS + 2 * KEY_AUTH_PER_CALL_SCOPE_GAS,
+            "2 allowed calls should be 70,000"
+        );
     }
 
     #[test]
@@ -2106,12 +2510,15 @@ mod tests {
                     TokenLimit {
                         token: Address::random(),
                         limit: U256::from(1000),
+                        period: None,
                     },
                     TokenLimit {
                         token: Address::random(),
                         limit: U256::from(2000),
+                        period: None,
                     },
                 ]),
+                allowed_calls: None,
             },
             signature: PrimitiveSignature::Secp256k1(alloy_primitives::Signature::test_signature()),
         };
@@ -318,6 +318,11 @@ impl FromRecoveredTx<AASigned> for TempoTxEnv {
             let _ = keychain_sig.key_id(&aa_signed.signature_hash());
         }
 
+        // Same for the signers of Multisig signatures
+        if let Some(multisig_sig) = signature.as_multisig() {
+            let _ = multisig_sig.signers(&aa_signed.signature_hash());
+        }
+
         let TempoTransaction {
             chain_id,
             fee_token,
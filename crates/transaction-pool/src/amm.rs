@@ -15,7 +15,7 @@ use tempo_precompiles::{
     DEFAULT_FEE_TOKEN_POST_ALLEGRETTO, DEFAULT_FEE_TOKEN_PRE_ALLEGRETTO, TIP_FEE_MANAGER_ADDRESS,
     tip_fee_manager::{
         TipFeeManager,
-        amm::{Pool, PoolKey, compute_amount_out},
+        amm::{Pool, PoolFees, PoolKey, compute_amount_out},
     },
     tip20::{address_to_token_id_unchecked, token_id_to_address},
 };
@@ -60,7 +60,6 @@ impl AmmLiquidityCache {
         state_provider: &impl StateProvider,
     ) -> Result<bool, ProviderError> {
         let user_id = address_to_token_id_unchecked(user_token);
-        let amount_out = compute_amount_out(fee).map_err(ProviderError::other)?;
 
         let mut missing_in_cache = Vec::new();
 
@@ -77,8 +76,8 @@ impl AmmLiquidityCache {
 
                 let validator_id = address_to_token_id_unchecked(*token);
 
-                if let Some(validator_reserve) = inner.cache.get(&(user_id, validator_id)) {
-                    if *validator_reserve >= amount_out {
+                if let Some(pool) = inner.cache.get(&(user_id, validator_id)) {
+                    if pool.can_swap(fee)? {
                         return Ok(true);
                     }
                 } else {
@@ -97,18 +96,29 @@ impl AmmLiquidityCache {
             // This might race other fetches but we're OK with it.
             let pool_key =
                 PoolKey::new(token_id_to_address(user_id), token_id_to_address(token)).get_id();
-            let slot = TipFeeManager::new().pools.at(pool_key).base_slot();
+            let fee_manager = TipFeeManager::new();
+            let slot = fee_manager.pools.at(pool_key).base_slot();
+            let fees_slot = fee_manager.pool_fees.at(pool_key).base_slot();
             let pool = state_provider
                 .storage(TIP_FEE_MANAGER_ADDRESS, slot.into())?
                 .unwrap_or_default();
-            let reserve = U256::from(Pool::decode_from_slot(pool).reserve_validator_token);
+            let fees = state_provider
+                .storage(TIP_FEE_MANAGER_ADDRESS, fees_slot.into())?
+                .unwrap_or_default();
+            let pool = CachedPool {
+                reserve_validator_token: U256::from(
+                    Pool::decode_from_slot(pool).reserve_validator_token,
+                ),
+                fee_swap_rate: PoolFees::decode_from_slot(fees).m(),
+            };
 
             let mut inner = self.inner.write();
-            inner.cache.insert((user_id, token), reserve);
+            inner.cache.insert((user_id, token), pool);
             inner.slot_to_pool.insert(slot, (user_id, token));
+            inner.slot_to_pool_fees.insert(fees_slot, (user_id, token));
 
             // If the pool has enough liquidity, short circuit and return true
-            if reserve >= amount_out {
+            if pool.can_swap(fee)? {
                 return Ok(true);
             }
         }
@@ -134,7 +144,15 @@ impl AmmLiquidityCache {
                 // Update AMM pools
                 let validator_reserve =
                     U256::from(Pool::decode_from_slot(value.present_value).reserve_validator_token);
-                inner.cache.insert(pool, validator_reserve);
+                if let Some(cached) = inner.cache.get_mut(&pool) {
+                    cached.reserve_validator_token = validator_reserve;
+                }
+            } else if let Some(pool) = inner.slot_to_pool_fees.get(slot).copied() {
+                // Update AMM pool swap rates
+                let fee_swap_rate = PoolFees::decode_from_slot(value.present_value).m();
+                if let Some(cached) = inner.cache.get_mut(&pool) {
+                    cached.fee_swap_rate = fee_swap_rate;
+                }
             } else if let Some(validator) = inner.slot_to_validator.get(slot).copied() {
                 // Update validator fee token preferences
                 inner
@@ -209,14 +227,35 @@ impl AmmLiquidityCache {
     }
 }
 
+/// Cached state of an AMM pool needed to check fee swap liquidity.
+#[derive(Debug, Clone, Copy)]
+struct CachedPool {
+    /// Reserve of the validator token.
+    reserve_validator_token: U256,
+    /// Rate applied to fee swaps into the validator token.
+    fee_swap_rate: U256,
+}
+
+impl CachedPool {
+    /// Returns true if the pool can pay out a fee swap of `fee`.
+    fn can_swap(&self, fee: U256) -> Result<bool, ProviderError> {
+        let amount_out =
+            compute_amount_out(fee, self.fee_swap_rate).map_err(ProviderError::other)?;
+        Ok(self.reserve_validator_token >= amount_out)
+    }
+}
+
 #[derive(Debug, Default)]
 struct AmmLiquidityCacheInner {
-    /// Cache for (user_token, validator_token) -> liquidity
-    cache: HashMap<(u64, u64), U256>,
+    /// Cache for (user_token, validator_token) -> pool liquidity and swap rate
+    cache: HashMap<(u64, u64), CachedPool>,
 
     /// Reverse index for mapping AMM slot to a pool.
     slot_to_pool: HashMap<U256, (u64, u64)>,
 
+    /// Reverse index for mapping AMM pool swap rates slot to a pool.
+    slot_to_pool_fees: HashMap<U256, (u64, u64)>,
+
     /// Latest observed validator tokens.
     last_seen_tokens: VecDeque<Address>,
 
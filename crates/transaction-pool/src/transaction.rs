@@ -183,8 +183,8 @@ pub enum TempoPoolTransactionError {
     #[error("Tempo Transaction with subblock nonce key prefix aren't supported in the pool")]
     SubblockNonceKey,
 
-    /// Thrown if the fee payer of a transaction cannot transfer (is blacklisted) the fee token, thus making the payment impossible.
-    #[error("Fee payer {fee_payer} is blacklisted by fee token: {fee_token}")]
+    /// Thrown if the fee payer of a transaction cannot transfer (is blacklisted or frozen) the fee token, thus making the payment impossible.
+    #[error("Fee payer {fee_payer} is blacklisted or frozen by fee token: {fee_token}")]
     BlackListedFeePayer {
         fee_token: Address,
         fee_payer: Address,
@@ -18,6 +18,7 @@ use tempo_chainspec::{TempoChainSpec, hardfork::TempoHardforks};
 use tempo_precompiles::{
     ACCOUNT_KEYCHAIN_ADDRESS, NONCE_PRECOMPILE_ADDRESS,
     account_keychain::{AccountKeychain, AuthorizedKey},
+    error::TempoPrecompileError,
 };
 use tempo_primitives::{subblock::has_sub_block_nonce_key_prefix, transaction::TempoTransaction};
 use tempo_revm::TempoStateAccess;
@@ -71,7 +72,7 @@ where
     fn validate_against_keychain(
         &self,
         transaction: &TempoPooledTransaction,
-        state_provider: &impl StateProvider,
+        state_provider: &mut impl StateProvider,
     ) -> Result<Result<(), &'static str>, ProviderError> {
         let Some(tx) = transaction.inner().as_aa() else {
             return Ok(Ok(()));
@@ -109,6 +110,37 @@ where
             }
         }
 
+        if let Some(sig) = tx.signature().as_multisig() {
+            let spec = self
+                .inner
+                .chain_spec()
+                .tempo_hardfork_at(self.inner.fork_tracker().tip_timestamp());
+
+            if !spec.is_vivace() {
+                return Ok(Err("multisig signatures are only supported after Vivace"));
+            }
+
+            // This should never fail because we set sender based on the sig.
+            if sig.account != transaction.sender() {
+                return Ok(Err("Multisig signature account does not match sender"));
+            }
+
+            // This should never fail because we validate the signature validity in `recover_signer`.
+            let Ok(signers) = sig.signers(&tx.signature_hash()) else {
+                return Ok(Err("Failed to recover signers from Multisig signature"));
+            };
+
+            // Ensure that the signers meet the threshold of the account's signer set
+            let result = state_provider.with_read_only_storage_ctx(spec, || {
+                AccountKeychain::new().validate_multisig(transaction.sender(), signers)
+            });
+            return match result {
+                Ok(()) => Ok(Ok(())),
+                Err(err @ TempoPrecompileError::Fatal(_)) => Err(ProviderError::other(err)),
+                Err(_) => Ok(Err("multisig signers do not meet the account's threshold")),
+            };
+        }
+
         let Some(sig) = tx.signature().as_keychain() else {
             return Ok(Ok(()));
         };
@@ -218,7 +250,7 @@ where
         }
 
         // Validate transactions that involve keychain keys
-        match self.validate_against_keychain(&transaction, &state_provider) {
+        match self.validate_against_keychain(&transaction, &mut state_provider) {
             Ok(Ok(())) => {}
             Ok(Err(reason)) => {
                 return TransactionValidationOutcome::Invalid(
@@ -289,7 +321,7 @@ where
             }
         }
 
-        // Ensure that the fee payer is not blacklisted
+        // Ensure that the fee payer is neither blacklisted nor frozen
         match state_provider.can_fee_payer_transfer(fee_token, fee_payer, spec) {
             Ok(valid) => {
                 if !valid {
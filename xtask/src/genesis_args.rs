@@ -95,6 +95,10 @@ pub(crate) struct GenesisArgs {
     #[arg(long, default_value_t = 0)]
     pub allegro_moderato_time: u64,
 
+    /// Vivace hardfork activation timestamp (defaults to 0 = active at genesis)
+    #[arg(long, default_value_t = 0)]
+    pub vivace_time: u64,
+
     /// The hard-coded length of an epoch in blocks.
     #[arg(long, default_value_t = 302_400)]
     epoch_length: u64,
@@ -400,6 +404,11 @@ impl GenesisArgs {
             serde_json::json!(self.allegro_moderato_time),
         );
 
+        chain_config.extra_fields.insert(
+            "vivaceTime".to_string(),
+            serde_json::json!(self.vivace_time),
+        );
+
         chain_config
             .extra_fields
             .insert_value("epochLength".to_string(), self.epoch_length)?;